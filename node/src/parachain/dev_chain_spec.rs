@@ -146,6 +146,17 @@ fn configure_genesis(
 				subsidization_pool_percent: Perbill::from_percent(5),
 				depin_staking_percent: Perbill::from_percent(5),
 				depin_incentivization_percent: Perbill::from_percent(15),
+				burn_percent: Perbill::from_percent(0),
+			},
+			// Make sure sum is 100
+			fee_config: pallet_block_reward::RewardDistributionConfig {
+				treasury_percent: Perbill::from_percent(25),
+				collators_delegators_percent: Perbill::from_percent(40),
+				coretime_percent: Perbill::from_percent(10),
+				subsidization_pool_percent: Perbill::from_percent(5),
+				depin_staking_percent: Perbill::from_percent(5),
+				depin_incentivization_percent: Perbill::from_percent(15),
+				burn_percent: Perbill::from_percent(0),
 			},
 			_phantom: Default::default(),
 		},
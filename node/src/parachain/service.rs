@@ -302,6 +302,8 @@ where
 		+ substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>
 		+ pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>
 		+ peaq_pallet_did_rpc::PeaqDIDRuntimeApi<Block, AccountId, BlockNumber, Moment>
+		+ parachain_staking_rpc::ParachainStakingRuntimeApi<Block, AccountId, Balance, BlockNumber>
+		+ pallet_block_reward_rpc::BlockRewardRuntimeApi<Block, AccountId, Balance, Moment>
 		+ peaq_pallet_rbac_rpc::PeaqRBACRuntimeApi<Block, AccountId, RbacEntityId>
 		+ sp_consensus_aura::AuraApi<Block, AuraId>
 		+ fp_rpc::EthereumRuntimeRPCApi<Block>
@@ -724,6 +726,8 @@ where
 		+ substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>
 		+ pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>
 		+ peaq_pallet_did_rpc::PeaqDIDRuntimeApi<Block, AccountId, BlockNumber, Moment>
+		+ parachain_staking_rpc::ParachainStakingRuntimeApi<Block, AccountId, Balance, BlockNumber>
+		+ pallet_block_reward_rpc::BlockRewardRuntimeApi<Block, AccountId, Balance, Moment>
 		+ peaq_pallet_rbac_rpc::PeaqRBACRuntimeApi<Block, AccountId, RbacEntityId>
 		+ fp_rpc::EthereumRuntimeRPCApi<Block>
 		+ fp_rpc::ConvertTransactionRuntimeApi<Block>
@@ -115,6 +115,9 @@ where
 	C::Api: peaq_rpc_primitives_txpool::TxPoolRuntimeApi<Block>,
 	C::Api: peaq_pallet_storage_rpc::PeaqStorageRuntimeApi<Block, AccountId>,
 	C::Api: zenlink_protocol_runtime_api::ZenlinkProtocolApi<Block, AccountId, ZenlinkAssetId>,
+	C::Api:
+		parachain_staking_rpc::ParachainStakingRuntimeApi<Block, AccountId, Balance, BlockNumber>,
+	C::Api: pallet_block_reward_rpc::BlockRewardRuntimeApi<Block, AccountId, Balance, Moment>,
 	P: TransactionPool<Block = Block> + 'static,
 	A: ChainApi<Block = Block> + 'static,
 
@@ -124,7 +127,9 @@ where
 		Eth, EthApiServer, EthFilter, EthFilterApiServer, EthPubSub, EthPubSubApiServer, Net,
 		NetApiServer, Web3, Web3ApiServer,
 	};
+	use pallet_block_reward_rpc::{BlockReward, BlockRewardApiServer};
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
+	use parachain_staking_rpc::{ParachainStaking, ParachainStakingApiServer};
 	use peaq_pallet_did_rpc::{PeaqDID, PeaqDIDApiServer};
 	use peaq_pallet_rbac_rpc::{PeaqRBAC, PeaqRBACApiServer};
 	use peaq_pallet_storage_rpc::{PeaqStorage, PeaqStorageApiServer};
@@ -225,6 +230,8 @@ where
 	io.merge(PeaqDID::new(Arc::clone(&client)).into_rpc())?;
 	io.merge(PeaqRBAC::new(Arc::clone(&client)).into_rpc())?;
 	io.merge(ZenlinkProtocol::new(Arc::clone(&client)).into_rpc())?;
+	io.merge(ParachainStaking::new(Arc::clone(&client)).into_rpc())?;
+	io.merge(BlockReward::new(Arc::clone(&client)).into_rpc())?;
 	io.merge(Web3::new(Arc::clone(&client)).into_rpc())?;
 	io.merge(
 		EthPubSub::new(
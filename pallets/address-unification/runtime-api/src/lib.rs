@@ -0,0 +1,70 @@
+//! Runtime API definition of the address-unification pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+// These clippy lints are disabled because the macro-generated code triggers them.
+#![allow(clippy::too_many_arguments)]
+
+use parity_scale_codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+use sp_core::{H160, H256};
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+/// The EIP-712 domain claim signatures are bound to.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct Eip712Domain {
+	/// The name of the signing domain.
+	pub name: Vec<u8>,
+	/// The version of the signing domain.
+	pub version: Vec<u8>,
+	/// The EVM chain id.
+	pub chain_id: u64,
+	/// The address of the address-unification precompile.
+	pub verifying_contract: H160,
+	/// The genesis block hash.
+	pub salt: H256,
+}
+
+/// Everything needed to sign an account claim with `eth_signTypedData`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct ClaimTypedData<BlockNumber> {
+	/// The domain of the typed data.
+	pub domain: Eip712Domain,
+	/// The EIP-712 type of the message, e.g.
+	/// `Claim(bytes substrateAddress,uint256 nonce,uint256 deadline)`.
+	pub primary_type: Vec<u8>,
+	/// The SCALE encoded account which claims the EVM address.
+	pub substrate_address: Vec<u8>,
+	/// The current claim nonce of the EVM address.
+	pub nonce: u64,
+	/// The last block in which the signature is valid.
+	pub deadline: BlockNumber,
+	/// The `0x1901 ‖ domainSeparator ‖ hashStruct(message)` payload.
+	pub signable_message: Vec<u8>,
+	/// The keccak256 hash of the payload which has to be signed.
+	pub digest: H256,
+}
+
+sp_api::decl_runtime_apis! {
+	/// The API to build account claims of the address-unification pallet.
+	pub trait AddressUnificationApi<AccountId, BlockNumber>
+	where
+		AccountId: Codec,
+		BlockNumber: Codec,
+	{
+		/// Return the typed data `evm_address` has to sign so that `account_id`
+		/// can claim it until block `deadline`.
+		fn claim_typed_data(
+			account_id: AccountId,
+			evm_address: H160,
+			deadline: BlockNumber,
+		) -> ClaimTypedData<BlockNumber>;
+	}
+}
@@ -0,0 +1,40 @@
+//! Helper functions for the implementation of the `AddressUnificationApi` runtime API.
+
+use crate::{ClaimNonces, Config, Pallet, CLAIM_DOMAIN_NAME, CLAIM_DOMAIN_VERSION, CLAIM_TYPE};
+use address_unification_runtime_api::{ClaimTypedData, Eip712Domain};
+use frame_support::traits::Get;
+use parity_scale_codec::Encode;
+use peaq_primitives_xcm::evm::EvmAddress;
+use sp_core::H256;
+use sp_io::hashing::keccak_256;
+use sp_runtime::traits::Zero;
+
+impl<T: Config> Pallet<T> {
+	/// Return the typed data `evm_address` has to sign so that `who` can claim it with
+	/// `claim_account_with_deadline` until block `deadline`.
+	pub fn claim_typed_data(
+		who: &T::AccountId,
+		evm_address: EvmAddress,
+		deadline: T::BlockNumber,
+	) -> ClaimTypedData<T::BlockNumber> {
+		let nonce = ClaimNonces::<T>::get(evm_address);
+		let signable_message = Self::eip712_claim_signable_message(who, nonce, deadline);
+		let genesis_hash = frame_system::Pallet::<T>::block_hash(T::BlockNumber::zero());
+
+		ClaimTypedData {
+			domain: Eip712Domain {
+				name: CLAIM_DOMAIN_NAME.to_vec(),
+				version: CLAIM_DOMAIN_VERSION.to_vec(),
+				chain_id: T::ChainId::get(),
+				verifying_contract: T::VerifyingContract::get(),
+				salt: H256::from_slice(genesis_hash.as_ref()),
+			},
+			primary_type: CLAIM_TYPE.to_vec(),
+			substrate_address: who.encode(),
+			nonce,
+			deadline,
+			digest: H256(keccak_256(&signable_message)),
+			signable_message,
+		}
+	}
+}
@@ -56,4 +56,132 @@ mod benchmarks {
 
 		assert_last_event::<T>(Event::<T>::ClaimAccount { account_id: caller, evm_address }.into());
 	}
+
+	#[benchmark]
+	fn unclaim_account() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		Pallet::<T>::claim_default_account(RawOrigin::Signed(caller.clone()).into())?;
+		let evm_address = Pallet::<T>::get_detault_evm_address(&caller);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()));
+
+		assert_last_event::<T>(
+			Event::<T>::UnclaimAccount { account_id: caller, evm_address }.into(),
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn request_relink() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		Pallet::<T>::claim_default_account(RawOrigin::Signed(caller.clone()).into())?;
+		let old_evm_address = Pallet::<T>::get_detault_evm_address(&caller);
+		let eth_secret_key = libsecp256k1::SecretKey::parse(&keccak_256(b"Alice")).unwrap();
+		let evm_address = Pallet::<T>::evm_address(&eth_secret_key);
+		let deadline = frame_system::Pallet::<T>::block_number();
+		let signature =
+			Pallet::<T>::eth_sign_relink(&eth_secret_key, &caller, &old_evm_address, deadline);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), evm_address, signature, deadline);
+
+		assert!(PendingRelinks::<T>::contains_key(&caller));
+		assert_eq!(ClaimNonces::<T>::get(evm_address), 1);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn execute_relink() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		Pallet::<T>::claim_default_account(RawOrigin::Signed(caller.clone()).into())?;
+		let old_evm_address = Pallet::<T>::get_detault_evm_address(&caller);
+		let eth_secret_key = libsecp256k1::SecretKey::parse(&keccak_256(b"Alice")).unwrap();
+		let new_evm_address = Pallet::<T>::evm_address(&eth_secret_key);
+		PendingRelinks::<T>::insert(
+			&caller,
+			(new_evm_address, frame_system::Pallet::<T>::block_number()),
+		);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()));
+
+		assert_last_event::<T>(
+			Event::<T>::AccountRelinked { account_id: caller, old_evm_address, new_evm_address }
+				.into(),
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn cancel_relink() {
+		let caller: T::AccountId = whitelisted_caller();
+		let eth_secret_key = libsecp256k1::SecretKey::parse(&keccak_256(b"Alice")).unwrap();
+		let evm_address = Pallet::<T>::evm_address(&eth_secret_key);
+		PendingRelinks::<T>::insert(
+			&caller,
+			(evm_address, frame_system::Pallet::<T>::block_number()),
+		);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()));
+
+		assert_last_event::<T>(
+			Event::<T>::RelinkCancelled { account_id: caller, evm_address }.into(),
+		);
+	}
+
+	#[benchmark]
+	fn claim_account_with_sweep(a: Linear<0, { T::MaxSweptAssets::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let eth_secret_key = libsecp256k1::SecretKey::parse(&keccak_256(b"Alice")).unwrap();
+		let evm_address = Pallet::<T>::evm_address(&eth_secret_key);
+		let deadline = frame_system::Pallet::<T>::block_number();
+		let signature = Pallet::<T>::eth_sign_with_deadline(&eth_secret_key, &caller, deadline);
+
+		let default_account_id = T::OriginAddressMapping::into_account_id(evm_address);
+		T::Currency::make_free_balance_be(
+			&default_account_id,
+			<T::Currency as Currency<_>>::minimum_balance().saturating_mul(100u32.into()),
+		);
+		// asset ids start at 1, 0 is reserved for the native currency
+		let assets: Vec<AssetIdOf<T>> = (1..=a)
+			.map(|index| T::BenchmarkHelper::create_funded_asset(index, &default_account_id))
+			.collect();
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller.clone()),
+			evm_address,
+			signature,
+			deadline,
+			assets.try_into().unwrap(),
+		);
+
+		assert!(!frame_system::Pallet::<T>::account_exists(&default_account_id));
+		assert_last_event::<T>(Event::<T>::ClaimAccount { account_id: caller, evm_address }.into());
+	}
+
+	#[benchmark]
+	fn claim_account_with_deadline() {
+		let caller: T::AccountId = whitelisted_caller();
+		let eth_secret_key = libsecp256k1::SecretKey::parse(&keccak_256(b"Alice")).unwrap();
+		let evm_address = Pallet::<T>::evm_address(&eth_secret_key);
+		let deadline = frame_system::Pallet::<T>::block_number();
+		let signature = Pallet::<T>::eth_sign_with_deadline(&eth_secret_key, &caller, deadline);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), evm_address, signature, deadline);
+
+		assert_eq!(ClaimNonces::<T>::get(evm_address), 1);
+		assert_last_event::<T>(Event::<T>::ClaimAccount { account_id: caller, evm_address }.into());
+	}
+
+	#[benchmark]
+	fn set_legacy_claim_signatures() {
+		#[extrinsic_call]
+		_(RawOrigin::Root, false);
+
+		assert!(!LegacyClaimSignatures::<T>::get());
+	}
 }
@@ -29,10 +29,14 @@
 use frame_support::{
 	ensure,
 	pallet_prelude::*,
-	traits::{fungible, Currency, IsType, OnKilledAccount},
+	traits::{
+		fungible,
+		tokens::{Fortitude, Preservation},
+		Currency, ExistenceRequirement, IsType, OnKilledAccount,
+	},
 	transactional,
 };
-use frame_system::{ensure_signed, pallet_prelude::*};
+use frame_system::{ensure_root, ensure_signed, pallet_prelude::*};
 use pallet_evm::AddressMapping as PalletEVMAddressMapping;
 use parity_scale_codec::Encode;
 use precompile_utils::prelude::keccak256;
@@ -41,11 +45,12 @@ use peaq_primitives_xcm::{evm::EvmAddress, to_bytes, AccountIndex};
 use sp_core::{H160, H256};
 use sp_io::{crypto::secp256k1_ecdsa_recover, hashing::keccak_256};
 use sp_runtime::{
-	traits::{LookupError, StaticLookup, Zero},
+	traits::{LookupError, SaturatedConversion, Saturating, StaticLookup, Zero},
 	MultiAddress,
 };
 use sp_std::{marker::PhantomData, vec::Vec};
 
+pub mod api;
 mod convert_impl;
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -62,12 +67,25 @@ mod traits;
 
 use convert_impl::*;
 pub use module::*;
-pub use traits::EVMAddressMapping;
+#[cfg(feature = "runtime-benchmarks")]
+pub use traits::BenchmarkHelper;
+pub use traits::{EVMAddressMapping, FungiblesSweeper, SweepAssets};
 
 /// A signature (a 512-bit value, plus 8 bits for recovery ID).
 pub type Eip712Signature = [u8; 65];
+
+/// The name of the EIP-712 domain of claims.
+pub const CLAIM_DOMAIN_NAME: &[u8] = b"Peaq EVM claim";
+/// The version of the EIP-712 domain of claims with nonce and deadline.
+pub const CLAIM_DOMAIN_VERSION: &[u8] = b"2";
+/// The EIP-712 type of claims with nonce and deadline.
+pub const CLAIM_TYPE: &[u8] = b"Claim(bytes substrateAddress,uint256 nonce,uint256 deadline)";
+/// The EIP-712 type of relinks, signed in the domain of claims with nonce and deadline.
+pub const RELINK_TYPE: &[u8] =
+	b"Relink(bytes substrateAddress,address oldEvmAddress,uint256 nonce,uint256 deadline)";
 type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 type BalanceOf<T> = <<T as Config>::Currency as Currency<AccountIdOf<T>>>::Balance;
+type AssetIdOf<T> = <<T as Config>::AssetsSweeper as SweepAssets<AccountIdOf<T>>>::AssetId;
 
 #[frame_support::pallet]
 pub mod module {
@@ -91,6 +109,25 @@ pub mod module {
 		#[pallet::constant]
 		type ChainId: Get<u64>;
 
+		/// The verifying contract of the EIP-712 domain, the address-unification precompile.
+		#[pallet::constant]
+		type VerifyingContract: Get<H160>;
+
+		/// Number of blocks a requested relink has to wait before it can be executed.
+		#[pallet::constant]
+		type RelinkDelay: Get<Self::BlockNumber>;
+
+		/// Moves asset balances from the default account into the claiming account.
+		type AssetsSweeper: SweepAssets<Self::AccountId>;
+
+		/// Maximum number of assets which can be swept while claiming an account.
+		#[pallet::constant]
+		type MaxSweptAssets: Get<u32>;
+
+		/// Provides funded assets for the benchmarks.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<AssetIdOf<Self>, Self::AccountId>;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -101,6 +138,33 @@ pub mod module {
 		/// Mapping between Substrate accounts and EVM accounts
 		/// claim account.
 		ClaimAccount { account_id: T::AccountId, evm_address: EvmAddress },
+		/// Mapping between Substrate accounts and EVM accounts
+		/// removed.
+		UnclaimAccount { account_id: T::AccountId, evm_address: EvmAddress },
+		/// Relink of a Substrate account to a new EVM account requested.
+		RelinkRequested {
+			account_id: T::AccountId,
+			evm_address: EvmAddress,
+			execute_at: T::BlockNumber,
+		},
+		/// Requested relink cancelled.
+		RelinkCancelled { account_id: T::AccountId, evm_address: EvmAddress },
+		/// Substrate account mapped to a new EVM account.
+		AccountRelinked {
+			account_id: T::AccountId,
+			old_evm_address: EvmAddress,
+			new_evm_address: EvmAddress,
+		},
+		/// Balances of the default account of an EVM address moved into
+		/// the claiming account.
+		DefaultAccountSwept {
+			account_id: T::AccountId,
+			default_account_id: T::AccountId,
+			amount: BalanceOf<T>,
+			assets: u32,
+		},
+		/// Claims with signatures without nonce and deadline were allowed or disallowed.
+		LegacyClaimSignaturesSet { allowed: bool },
 	}
 
 	/// Error for evm accounts module.
@@ -118,6 +182,18 @@ pub mod module {
 		NonZeroRefCount,
 		/// Non Empty Accounts
 		NonEmptyAccounts,
+		/// AccountId has not mapped
+		AccountIdNotMapped,
+		/// A relink has already been requested
+		RelinkAlreadyRequested,
+		/// No relink has been requested
+		NoRelinkRequested,
+		/// The delay of the relink has not passed yet
+		RelinkDelayNotPassed,
+		/// The deadline of the signature has passed
+		ClaimExpired,
+		/// Signatures without nonce and deadline are not accepted anymore
+		LegacyClaimSignaturesDisabled,
 	}
 
 	/// The Substrate Account for EvmAddresses
@@ -136,6 +212,32 @@ pub mod module {
 	pub type EvmAddresses<T: Config> =
 		StorageMap<_, Twox64Concat, T::AccountId, EvmAddress, OptionQuery>;
 
+	/// The requested relinks of Substrate Accounts
+	///
+	/// PendingRelinks: map AccountId => Option<(EvmAddress, BlockNumber)>
+	#[pallet::storage]
+	#[pallet::getter(fn pending_relinks)]
+	pub type PendingRelinks<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, (EvmAddress, T::BlockNumber), OptionQuery>;
+
+	/// The number of claims signed by EvmAddresses
+	///
+	/// ClaimNonces: map EvmAddress => u64
+	#[pallet::storage]
+	#[pallet::getter(fn claim_nonces)]
+	pub type ClaimNonces<T: Config> = StorageMap<_, Twox64Concat, EvmAddress, u64, ValueQuery>;
+
+	#[pallet::type_value]
+	pub fn DefaultLegacyClaimSignatures() -> bool {
+		true
+	}
+
+	/// Whether claims signed without nonce and deadline are accepted
+	#[pallet::storage]
+	#[pallet::getter(fn legacy_claim_signatures)]
+	pub type LegacyClaimSignatures<T: Config> =
+		StorageValue<_, bool, ValueQuery, DefaultLegacyClaimSignatures>;
+
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
@@ -149,6 +251,9 @@ pub mod module {
 		///
 		/// - `evm_address`: The address to bind to the caller's account
 		/// - `eth_signature`: A signature generated by the address to prove ownership
+		///
+		/// The signature neither covers a nonce nor a deadline, it is only accepted as long
+		/// as `LegacyClaimSignatures` is set.
 		// Link
 		#[pallet::call_index(0)]
 		#[pallet::weight(T::WeightInfo::claim_account())]
@@ -159,15 +264,7 @@ pub mod module {
 			eth_signature: Eip712Signature,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-
-			// ensure account_id and evm_address has not been mapped
-			ensure!(!EvmAddresses::<T>::contains_key(&who), Error::<T>::AccountIdHasMapped);
-			ensure!(!Accounts::<T>::contains_key(evm_address), Error::<T>::EthAddressHasMapped);
-
-			// recover evm address from signature
-			let address = Self::verify_eip712_signature(&who, &eth_signature)
-				.ok_or(Error::<T>::BadSignature)?;
-			ensure!(evm_address == address, Error::<T>::InvalidSignature);
+			Self::ensure_claimable(&who, evm_address, &eth_signature, None)?;
 
 			// check if the evm padded address already exists
 			let ori_account_id = T::OriginAddressMapping::into_account_id(evm_address);
@@ -175,19 +272,7 @@ pub mod module {
 				!frame_system::Pallet::<T>::account_exists(&ori_account_id),
 				Error::<T>::NonEmptyAccounts
 			);
-			/*
-			 * TODO, we don't allow users to use the non-empty account to do the linking.
-			 * if frame_system::Pallet::<T>::account_exists(&ori_account_id) {
-			 *     // merge balance from `evm padded address` to `origin`
-			 *     // Sould transfer all...
-			 *     let amount = T::Currency::reducible_balance(
-			 *         &ori_account_id,
-			 *         Preservation::Expendable,
-			 *         Fortitude::Polite,
-			 *     );
-			 *     T::Currency::transfer(&ori_account_id, &who, amount,
-			 * ExistenceRequirement::AllowDeath)?; }
-			 */
+			// non-empty default accounts can be merged with `claim_account_with_sweep`
 
 			Accounts::<T>::insert(evm_address, &who);
 			EvmAddresses::<T>::insert(&who, evm_address);
@@ -226,6 +311,211 @@ pub mod module {
 			Self::deposit_event(Event::ClaimAccount { account_id: who, evm_address });
 			Ok(())
 		}
+
+		/// Remove the account mapping of the caller, the EVM address falls back to its
+		/// default account afterwards.
+		/// Ensure the caller has no references left and the default account of the EVM
+		/// address is empty.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::unclaim_account())]
+		pub fn unclaim_account(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let evm_address = EvmAddresses::<T>::get(&who).ok_or(Error::<T>::AccountIdNotMapped)?;
+			Self::ensure_unlinkable(&who, evm_address)?;
+
+			Accounts::<T>::remove(evm_address);
+			EvmAddresses::<T>::remove(&who);
+			PendingRelinks::<T>::remove(&who);
+
+			Self::deposit_event(Event::UnclaimAccount { account_id: who, evm_address });
+			Ok(())
+		}
+
+		/// Request to map the caller to a new EVM address, it can be executed after
+		/// `RelinkDelay` blocks.
+		///
+		/// - `evm_address`: The address to bind to the caller's account
+		/// - `eth_signature`: A relink signature generated by the address to prove ownership
+		/// - `deadline`: The last block in which the signature is valid
+		///
+		/// The signature covers the current mapping, the claim nonce of the new EVM
+		/// address and the deadline, the nonce is used up by the request.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::request_relink())]
+		pub fn request_relink(
+			origin: OriginFor<T>,
+			evm_address: EvmAddress,
+			eth_signature: Eip712Signature,
+			deadline: T::BlockNumber,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let old_evm_address =
+				EvmAddresses::<T>::get(&who).ok_or(Error::<T>::AccountIdNotMapped)?;
+			ensure!(!PendingRelinks::<T>::contains_key(&who), Error::<T>::RelinkAlreadyRequested);
+			ensure!(!Accounts::<T>::contains_key(evm_address), Error::<T>::EthAddressHasMapped);
+			ensure!(
+				frame_system::Pallet::<T>::block_number() <= deadline,
+				Error::<T>::ClaimExpired
+			);
+
+			let nonce = ClaimNonces::<T>::get(evm_address);
+			let address = Self::verify_relink_signature(
+				&who,
+				&old_evm_address,
+				nonce,
+				deadline,
+				&eth_signature,
+			)
+			.ok_or(Error::<T>::BadSignature)?;
+			ensure!(evm_address == address, Error::<T>::InvalidSignature);
+			ClaimNonces::<T>::mutate(evm_address, |nonce| *nonce = nonce.saturating_add(1));
+
+			let execute_at =
+				frame_system::Pallet::<T>::block_number().saturating_add(T::RelinkDelay::get());
+			PendingRelinks::<T>::insert(&who, (evm_address, execute_at));
+
+			Self::deposit_event(Event::RelinkRequested {
+				account_id: who,
+				evm_address,
+				execute_at,
+			});
+			Ok(())
+		}
+
+		/// Map the caller to the EVM address of its relink request, once the delay has
+		/// passed. The old EVM address falls back to its default account.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::execute_relink())]
+		#[transactional]
+		pub fn execute_relink(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let (new_evm_address, execute_at) =
+				PendingRelinks::<T>::get(&who).ok_or(Error::<T>::NoRelinkRequested)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() >= execute_at,
+				Error::<T>::RelinkDelayNotPassed
+			);
+			let old_evm_address =
+				EvmAddresses::<T>::get(&who).ok_or(Error::<T>::AccountIdNotMapped)?;
+			ensure!(!Accounts::<T>::contains_key(new_evm_address), Error::<T>::EthAddressHasMapped);
+			Self::ensure_unlinkable(&who, old_evm_address)?;
+
+			let ori_account_id = T::OriginAddressMapping::into_account_id(new_evm_address);
+			ensure!(
+				!frame_system::Pallet::<T>::account_exists(&ori_account_id),
+				Error::<T>::NonEmptyAccounts
+			);
+
+			PendingRelinks::<T>::remove(&who);
+			Accounts::<T>::remove(old_evm_address);
+			Accounts::<T>::insert(new_evm_address, &who);
+			EvmAddresses::<T>::insert(&who, new_evm_address);
+
+			Self::deposit_event(Event::AccountRelinked {
+				account_id: who,
+				old_evm_address,
+				new_evm_address,
+			});
+			Ok(())
+		}
+
+		/// Claim account mapping between Substrate accounts and EVM accounts like
+		/// `claim_account`, but instead of rejecting a non-empty default account of the
+		/// EVM address, its native balance and the given assets are moved into the caller's
+		/// account first.
+		///
+		/// - `evm_address`: The address to bind to the caller's account
+		/// - `eth_signature`: A signature generated by the address to prove ownership
+		/// - `deadline`: The last block in which the signature is valid
+		/// - `assets`: The assets to sweep from the default account
+		///
+		/// The signature has to cover the claim nonce and the deadline, see
+		/// `claim_account_with_deadline`.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::claim_account_with_sweep(assets.len() as u32))]
+		#[transactional]
+		pub fn claim_account_with_sweep(
+			origin: OriginFor<T>,
+			evm_address: EvmAddress,
+			eth_signature: Eip712Signature,
+			deadline: T::BlockNumber,
+			assets: BoundedVec<AssetIdOf<T>, T::MaxSweptAssets>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_claimable(&who, evm_address, &eth_signature, Some(deadline))?;
+
+			let ori_account_id = T::OriginAddressMapping::into_account_id(evm_address);
+			if frame_system::Pallet::<T>::account_exists(&ori_account_id) {
+				Self::sweep_default_account(&who, &ori_account_id, assets)?;
+			}
+			// balances which cannot be moved, e.g. locked ones, keep the account alive
+			ensure!(
+				!frame_system::Pallet::<T>::account_exists(&ori_account_id),
+				Error::<T>::NonEmptyAccounts
+			);
+
+			Accounts::<T>::insert(evm_address, &who);
+			EvmAddresses::<T>::insert(&who, evm_address);
+
+			Self::deposit_event(Event::ClaimAccount { account_id: who, evm_address });
+			Ok(())
+		}
+
+		/// Cancel the relink request of the caller.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::cancel_relink())]
+		pub fn cancel_relink(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let (evm_address, _) =
+				PendingRelinks::<T>::take(&who).ok_or(Error::<T>::NoRelinkRequested)?;
+
+			Self::deposit_event(Event::RelinkCancelled { account_id: who, evm_address });
+			Ok(())
+		}
+
+		/// Claim account mapping between Substrate accounts and EVM accounts with a
+		/// signature over the current claim nonce of the EVM address and a deadline,
+		/// so that it cannot be replayed.
+		///
+		/// - `evm_address`: The address to bind to the caller's account
+		/// - `eth_signature`: A signature generated by the address to prove ownership
+		/// - `deadline`: The last block in which the signature is valid
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::claim_account_with_deadline())]
+		#[transactional]
+		pub fn claim_account_with_deadline(
+			origin: OriginFor<T>,
+			evm_address: EvmAddress,
+			eth_signature: Eip712Signature,
+			deadline: T::BlockNumber,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_claimable(&who, evm_address, &eth_signature, Some(deadline))?;
+
+			let ori_account_id = T::OriginAddressMapping::into_account_id(evm_address);
+			ensure!(
+				!frame_system::Pallet::<T>::account_exists(&ori_account_id),
+				Error::<T>::NonEmptyAccounts
+			);
+
+			Accounts::<T>::insert(evm_address, &who);
+			EvmAddresses::<T>::insert(&who, evm_address);
+
+			Self::deposit_event(Event::ClaimAccount { account_id: who, evm_address });
+			Ok(())
+		}
+
+		/// Allow or disallow `claim_account` with signatures which neither cover a
+		/// nonce nor a deadline.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::set_legacy_claim_signatures())]
+		pub fn set_legacy_claim_signatures(origin: OriginFor<T>, allowed: bool) -> DispatchResult {
+			ensure_root(origin)?;
+			LegacyClaimSignatures::<T>::put(allowed);
+
+			Self::deposit_event(Event::LegacyClaimSignaturesSet { allowed });
+			Ok(())
+		}
 	}
 }
 
@@ -254,6 +544,177 @@ impl<T: Config> Pallet<T> {
 		r
 	}
 
+	#[cfg(any(feature = "runtime-benchmarks", feature = "std"))]
+	// Constructs a claim message covering the current nonce of the address and signs it.
+	pub fn eth_sign_with_deadline(
+		secret: &libsecp256k1::SecretKey,
+		who: &T::AccountId,
+		deadline: T::BlockNumber,
+	) -> Eip712Signature {
+		let nonce = ClaimNonces::<T>::get(Self::evm_address(secret));
+		let msg = keccak_256(&Self::eip712_claim_signable_message(who, nonce, deadline));
+		let (sig, recovery_id) = libsecp256k1::sign(&libsecp256k1::Message::parse(&msg), secret);
+		let mut r = [0u8; 65];
+		r[0..64].copy_from_slice(&sig.serialize()[..]);
+		r[64] = recovery_id.serialize();
+		r
+	}
+
+	#[cfg(any(feature = "runtime-benchmarks", feature = "std"))]
+	// Constructs a relink message covering the current nonce of the address and signs it.
+	pub fn eth_sign_relink(
+		secret: &libsecp256k1::SecretKey,
+		who: &T::AccountId,
+		old_evm_address: &EvmAddress,
+		deadline: T::BlockNumber,
+	) -> Eip712Signature {
+		let nonce = ClaimNonces::<T>::get(Self::evm_address(secret));
+		let msg = keccak_256(&Self::eip712_relink_signable_message(
+			who,
+			old_evm_address,
+			nonce,
+			deadline,
+		));
+		let (sig, recovery_id) = libsecp256k1::sign(&libsecp256k1::Message::parse(&msg), secret);
+		let mut r = [0u8; 65];
+		r[0..64].copy_from_slice(&sig.serialize()[..]);
+		r[64] = recovery_id.serialize();
+		r
+	}
+
+	// Ensure neither the account nor the evm address has been mapped and the signature
+	// was generated by the evm address. Signatures with a deadline cover the claim nonce
+	// of the evm address, which is used up, signatures without one are legacy signatures.
+	fn ensure_claimable(
+		who: &T::AccountId,
+		evm_address: EvmAddress,
+		eth_signature: &Eip712Signature,
+		deadline: Option<T::BlockNumber>,
+	) -> DispatchResult {
+		ensure!(!EvmAddresses::<T>::contains_key(who), Error::<T>::AccountIdHasMapped);
+		ensure!(!Accounts::<T>::contains_key(evm_address), Error::<T>::EthAddressHasMapped);
+
+		// recover evm address from signature
+		let address = match deadline {
+			Some(deadline) => {
+				ensure!(
+					frame_system::Pallet::<T>::block_number() <= deadline,
+					Error::<T>::ClaimExpired
+				);
+				let nonce = ClaimNonces::<T>::get(evm_address);
+				let msg = Self::eip712_claim_signable_message(who, nonce, deadline);
+				recover_signer(eth_signature, &keccak_256(msg.as_slice()))
+			},
+			None => {
+				ensure!(
+					LegacyClaimSignatures::<T>::get(),
+					Error::<T>::LegacyClaimSignaturesDisabled
+				);
+				Self::verify_eip712_signature(who, eth_signature)
+			},
+		}
+		.ok_or(Error::<T>::BadSignature)?;
+		ensure!(evm_address == address, Error::<T>::InvalidSignature);
+
+		if deadline.is_some() {
+			ClaimNonces::<T>::mutate(evm_address, |nonce| *nonce = nonce.saturating_add(1));
+		}
+		Ok(())
+	}
+
+	// Moves the given assets and then the native balance of the default account to `who`.
+	fn sweep_default_account(
+		who: &T::AccountId,
+		default_account_id: &T::AccountId,
+		assets: BoundedVec<AssetIdOf<T>, T::MaxSweptAssets>,
+	) -> DispatchResult {
+		let mut swept_assets = 0u32;
+		for asset in assets {
+			if T::AssetsSweeper::sweep(asset, default_account_id, who)? {
+				swept_assets = swept_assets.saturating_add(1);
+			}
+		}
+
+		// assets go first, they may keep the native balance from being transferred
+		let amount = <T::Currency as fungible::Inspect<T::AccountId>>::reducible_balance(
+			default_account_id,
+			Preservation::Expendable,
+			Fortitude::Polite,
+		);
+		if !amount.is_zero() {
+			<T::Currency as Currency<T::AccountId>>::transfer(
+				default_account_id,
+				who,
+				amount,
+				ExistenceRequirement::AllowDeath,
+			)?;
+		}
+
+		Self::deposit_event(Event::DefaultAccountSwept {
+			account_id: who.clone(),
+			default_account_id: default_account_id.clone(),
+			amount,
+			assets: swept_assets,
+		});
+		Ok(())
+	}
+
+	// The account must not be referenced and the default account of the EVM address,
+	// which the address maps to afterwards, must be empty.
+	fn ensure_unlinkable(who: &T::AccountId, evm_address: EvmAddress) -> DispatchResult {
+		ensure!(frame_system::Pallet::<T>::consumers(who).is_zero(), Error::<T>::NonZeroRefCount);
+		let ori_account_id = T::OriginAddressMapping::into_account_id(evm_address);
+		ensure!(
+			!frame_system::Pallet::<T>::account_exists(&ori_account_id),
+			Error::<T>::NonEmptyAccounts
+		);
+		Ok(())
+	}
+
+	fn verify_relink_signature(
+		who: &T::AccountId,
+		old_evm_address: &EvmAddress,
+		nonce: u64,
+		deadline: T::BlockNumber,
+		sig: &[u8; 65],
+	) -> Option<H160> {
+		let msg = Self::eip712_relink_signable_message(who, old_evm_address, nonce, deadline);
+		let msg_hash = keccak_256(msg.as_slice());
+
+		recover_signer(sig, &msg_hash)
+	}
+
+	// Eip-712 message to be signed for a relink, in the domain of claims with nonce and deadline
+	fn eip712_relink_signable_message(
+		who: &T::AccountId,
+		old_evm_address: &EvmAddress,
+		nonce: u64,
+		deadline: T::BlockNumber,
+	) -> Vec<u8> {
+		let domain_separator = Self::evm_claim_domain_separator();
+		let payload_hash = Self::evm_relink_payload_hash(who, old_evm_address, nonce, deadline);
+
+		let mut msg = b"\x19\x01".to_vec();
+		msg.extend_from_slice(&domain_separator);
+		msg.extend_from_slice(&payload_hash);
+		msg
+	}
+
+	fn evm_relink_payload_hash(
+		who: &T::AccountId,
+		old_evm_address: &EvmAddress,
+		nonce: u64,
+		deadline: T::BlockNumber,
+	) -> [u8; 32] {
+		let tx_type_hash = keccak_256(RELINK_TYPE);
+		let mut tx_msg = tx_type_hash.to_vec();
+		tx_msg.extend_from_slice(&keccak_256(&who.encode()));
+		tx_msg.extend_from_slice(H256::from(*old_evm_address).as_bytes());
+		tx_msg.extend_from_slice(&to_bytes(nonce));
+		tx_msg.extend_from_slice(&to_bytes(deadline.saturated_into::<u64>()));
+		keccak_256(tx_msg.as_slice())
+	}
+
 	fn verify_eip712_signature(who: &T::AccountId, sig: &[u8; 65]) -> Option<H160> {
 		let msg = Self::eip712_signable_message(who);
 		let msg_hash = keccak_256(msg.as_slice());
@@ -279,6 +740,51 @@ impl<T: Config> Pallet<T> {
 		keccak_256(tx_msg.as_slice())
 	}
 
+	// Eip-712 message to be signed for a claim with nonce and deadline
+	fn eip712_claim_signable_message(
+		who: &T::AccountId,
+		nonce: u64,
+		deadline: T::BlockNumber,
+	) -> Vec<u8> {
+		let domain_separator = Self::evm_claim_domain_separator();
+		let payload_hash = Self::evm_claim_payload_hash(who, nonce, deadline);
+
+		let mut msg = b"\x19\x01".to_vec();
+		msg.extend_from_slice(&domain_separator);
+		msg.extend_from_slice(&payload_hash);
+		msg
+	}
+
+	fn evm_claim_payload_hash(
+		who: &T::AccountId,
+		nonce: u64,
+		deadline: T::BlockNumber,
+	) -> [u8; 32] {
+		let tx_type_hash = keccak_256(CLAIM_TYPE);
+		let mut tx_msg = tx_type_hash.to_vec();
+		tx_msg.extend_from_slice(&keccak_256(&who.encode()));
+		tx_msg.extend_from_slice(&to_bytes(nonce));
+		tx_msg.extend_from_slice(&to_bytes(deadline.saturated_into::<u64>()));
+		keccak_256(tx_msg.as_slice())
+	}
+
+	// Version 2 of the domain, which also binds signatures to the verifying contract
+	fn evm_claim_domain_separator() -> [u8; 32] {
+		let domain_hash = keccak256!(
+			"EIP712Domain(string name,string version,uint256 chainId,address verifyingContract,bytes32 salt)"
+		);
+		let mut domain_seperator_msg = domain_hash.to_vec();
+		domain_seperator_msg.extend_from_slice(&keccak_256(CLAIM_DOMAIN_NAME)); // name
+		domain_seperator_msg.extend_from_slice(&keccak_256(CLAIM_DOMAIN_VERSION)); // version
+		domain_seperator_msg.extend_from_slice(&to_bytes(T::ChainId::get())); // chain id
+		let verifying_contract = H256::from(T::VerifyingContract::get());
+		domain_seperator_msg.extend_from_slice(verifying_contract.as_bytes()); // verifying contract
+		domain_seperator_msg.extend_from_slice(
+			frame_system::Pallet::<T>::block_hash(T::BlockNumber::zero()).as_ref(),
+		); // genesis block hash
+		keccak_256(domain_seperator_msg.as_slice())
+	}
+
 	fn evm_account_domain_separator() -> [u8; 32] {
 		let domain_hash =
 			keccak256!("EIP712Domain(string name,string version,uint256 chainId,bytes32 salt)");
@@ -367,6 +873,7 @@ impl<T: Config> OnKilledAccount<T::AccountId> for CallKillEVMLinkAccount<T> {
 			Accounts::<T>::remove(evm_addr);
 			EvmAddresses::<T>::remove(who);
 		}
+		PendingRelinks::<T>::remove(who);
 	}
 }
 
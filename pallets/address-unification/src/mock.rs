@@ -22,9 +22,10 @@
 
 use super::*;
 use frame_support::{
-	construct_runtime,
-	traits::{ConstU128, ConstU64, Everything},
+	construct_runtime, parameter_types,
+	traits::{AsEnsureOriginWithArg, ConstU128, ConstU64, Everything},
 };
+use frame_system::{EnsureRoot, EnsureSigned};
 
 use pallet_evm::HashedAddressMapping;
 use peaq_primitives_xcm::Balance;
@@ -88,11 +89,43 @@ impl pallet_balances::Config for Runtime {
 	type MaxFreezes = ();
 }
 
+impl pallet_assets::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type AssetId = u32;
+	type Currency = Balances;
+	type ForceOrigin = EnsureRoot<AccountId>;
+	type AssetDeposit = ConstU128<0>;
+	type AssetAccountDeposit = ConstU128<0>;
+	type MetadataDepositBase = ConstU128<0>;
+	type MetadataDepositPerByte = ConstU128<0>;
+	type ApprovalDeposit = ConstU128<0>;
+	type StringLimit = ConstU32<50>;
+	type Freezer = ();
+	type Extra = ();
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId>>;
+	type WeightInfo = ();
+	type RemoveItemsLimit = ConstU32<0>;
+	type AssetIdParameter = u32;
+	type CallbackHandle = ();
+}
+
+parameter_types! {
+	pub const RelinkDelay: BlockNumber = 10;
+	pub VerifyingContract: H160 = H160::from_low_u64_be(2058);
+}
+
 impl Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
 	type ChainId = ();
+	type VerifyingContract = VerifyingContract;
+	type RelinkDelay = RelinkDelay;
 	type OriginAddressMapping = HashedAddressMapping<BlakeTwo256>;
+	type AssetsSweeper = FungiblesSweeper<Assets>;
+	type MaxSweptAssets = ConstU32<4>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = FungiblesSweeper<Assets>;
 	type WeightInfo = weights::WeightInfo<Runtime>;
 }
 
@@ -108,6 +141,7 @@ construct_runtime!(
 		System: frame_system::{Pallet, Call, Storage, Config, Event<T>},
 		AddressUnificationModule: address_unification::{Pallet, Call, Storage, Event<T>},
 		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		Assets: pallet_assets::{Pallet, Call, Storage, Event<T>},
 	}
 );
 
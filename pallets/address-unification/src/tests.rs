@@ -21,13 +21,16 @@
 #![cfg(test)]
 
 use super::*;
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{LockableCurrency, WithdrawReasons},
+};
 use mock::{
-	alice, bob, AddressUnificationModule, ExtBuilder, Runtime, RuntimeEvent, RuntimeOrigin, System,
-	ALICE, BOB,
+	alice, bob, AddressUnificationModule, Assets, Balances, ExtBuilder, RelinkDelay, Runtime,
+	RuntimeEvent, RuntimeOrigin, System, ALICE, BOB,
 };
 use pallet_evm::HashedAddressMapping;
-use sp_runtime::traits::BlakeTwo256;
+use sp_runtime::{traits::BlakeTwo256, DispatchError};
 
 #[test]
 fn claim_account_work() {
@@ -150,3 +153,557 @@ fn claim_default_account_no_work() {
 		);
 	});
 }
+
+#[test]
+fn unclaim_account_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let evm_address = AddressUnificationModule::evm_address(&alice());
+		assert_ok!(AddressUnificationModule::claim_account(
+			RuntimeOrigin::signed(ALICE),
+			evm_address,
+			AddressUnificationModule::eth_sign(&alice(), &ALICE)
+		));
+
+		assert_ok!(AddressUnificationModule::unclaim_account(RuntimeOrigin::signed(ALICE)));
+		System::assert_last_event(RuntimeEvent::AddressUnificationModule(
+			crate::Event::UnclaimAccount { account_id: ALICE, evm_address },
+		));
+		assert!(
+			!Accounts::<Runtime>::contains_key(evm_address) &&
+				!EvmAddresses::<Runtime>::contains_key(ALICE)
+		);
+		assert_eq!(
+			AddressUnificationModule::get_account_id_or_default(&evm_address),
+			HashedAddressMapping::<BlakeTwo256>::into_account_id(evm_address)
+		);
+
+		// the account can be claimed again
+		assert_ok!(AddressUnificationModule::claim_default_account(RuntimeOrigin::signed(ALICE)));
+	});
+}
+
+#[test]
+fn unclaim_account_should_not_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			AddressUnificationModule::unclaim_account(RuntimeOrigin::signed(ALICE)),
+			Error::<Runtime>::AccountIdNotMapped
+		);
+
+		let evm_address = AddressUnificationModule::evm_address(&alice());
+		assert_ok!(AddressUnificationModule::claim_account(
+			RuntimeOrigin::signed(ALICE),
+			evm_address,
+			AddressUnificationModule::eth_sign(&alice(), &ALICE)
+		));
+
+		// the account is still referenced
+		System::inc_providers(&ALICE);
+		assert_ok!(System::inc_consumers(&ALICE));
+		assert_noop!(
+			AddressUnificationModule::unclaim_account(RuntimeOrigin::signed(ALICE)),
+			Error::<Runtime>::NonZeroRefCount
+		);
+		System::dec_consumers(&ALICE);
+
+		// the default account of the evm address has been funded in the meantime
+		let default_account = HashedAddressMapping::<BlakeTwo256>::into_account_id(evm_address);
+		let _ = Balances::deposit_creating(&default_account, 100);
+		assert_noop!(
+			AddressUnificationModule::unclaim_account(RuntimeOrigin::signed(ALICE)),
+			Error::<Runtime>::NonEmptyAccounts
+		);
+	});
+}
+
+#[test]
+fn relink_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let old_evm_address = AddressUnificationModule::evm_address(&alice());
+		let new_evm_address = AddressUnificationModule::evm_address(&bob());
+		assert_ok!(AddressUnificationModule::claim_account(
+			RuntimeOrigin::signed(ALICE),
+			old_evm_address,
+			AddressUnificationModule::eth_sign(&alice(), &ALICE)
+		));
+
+		assert_ok!(AddressUnificationModule::request_relink(
+			RuntimeOrigin::signed(ALICE),
+			new_evm_address,
+			AddressUnificationModule::eth_sign_relink(&bob(), &ALICE, &old_evm_address, 100),
+			100
+		));
+		let execute_at = 1 + RelinkDelay::get();
+		System::assert_last_event(RuntimeEvent::AddressUnificationModule(
+			crate::Event::RelinkRequested {
+				account_id: ALICE,
+				evm_address: new_evm_address,
+				execute_at,
+			},
+		));
+		assert_eq!(
+			AddressUnificationModule::pending_relinks(ALICE),
+			Some((new_evm_address, execute_at))
+		);
+
+		// the old mapping stays in place until the delay has passed
+		assert_noop!(
+			AddressUnificationModule::execute_relink(RuntimeOrigin::signed(ALICE)),
+			Error::<Runtime>::RelinkDelayNotPassed
+		);
+		assert!(AddressUnificationModule::is_linked(&ALICE, &old_evm_address));
+
+		System::set_block_number(execute_at);
+		assert_ok!(AddressUnificationModule::execute_relink(RuntimeOrigin::signed(ALICE)));
+		System::assert_last_event(RuntimeEvent::AddressUnificationModule(
+			crate::Event::AccountRelinked { account_id: ALICE, old_evm_address, new_evm_address },
+		));
+		assert!(AddressUnificationModule::is_linked(&ALICE, &new_evm_address));
+		assert_eq!(AddressUnificationModule::accounts(new_evm_address), Some(ALICE));
+		assert!(!Accounts::<Runtime>::contains_key(old_evm_address));
+		assert!(!PendingRelinks::<Runtime>::contains_key(ALICE));
+	});
+}
+
+#[test]
+fn relink_should_not_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let old_evm_address = AddressUnificationModule::evm_address(&alice());
+		let new_evm_address = AddressUnificationModule::evm_address(&bob());
+		assert_noop!(
+			AddressUnificationModule::request_relink(
+				RuntimeOrigin::signed(ALICE),
+				new_evm_address,
+				AddressUnificationModule::eth_sign_relink(&bob(), &ALICE, &old_evm_address, 100),
+				100
+			),
+			Error::<Runtime>::AccountIdNotMapped
+		);
+
+		assert_ok!(AddressUnificationModule::claim_account(
+			RuntimeOrigin::signed(ALICE),
+			old_evm_address,
+			AddressUnificationModule::eth_sign(&alice(), &ALICE)
+		));
+
+		// a claim signature does not authorize a relink
+		assert_noop!(
+			AddressUnificationModule::request_relink(
+				RuntimeOrigin::signed(ALICE),
+				new_evm_address,
+				AddressUnificationModule::eth_sign(&bob(), &ALICE),
+				100
+			),
+			Error::<Runtime>::InvalidSignature
+		);
+		// the signature has to cover the current mapping
+		assert_noop!(
+			AddressUnificationModule::request_relink(
+				RuntimeOrigin::signed(ALICE),
+				new_evm_address,
+				AddressUnificationModule::eth_sign_relink(&bob(), &ALICE, &new_evm_address, 100),
+				100
+			),
+			Error::<Runtime>::InvalidSignature
+		);
+		// the new address must not be mapped
+		assert_noop!(
+			AddressUnificationModule::request_relink(
+				RuntimeOrigin::signed(ALICE),
+				old_evm_address,
+				AddressUnificationModule::eth_sign_relink(&alice(), &ALICE, &old_evm_address, 100),
+				100
+			),
+			Error::<Runtime>::EthAddressHasMapped
+		);
+		// the deadline of the signature has passed
+		assert_noop!(
+			AddressUnificationModule::request_relink(
+				RuntimeOrigin::signed(ALICE),
+				new_evm_address,
+				AddressUnificationModule::eth_sign_relink(&bob(), &ALICE, &old_evm_address, 0),
+				0
+			),
+			Error::<Runtime>::ClaimExpired
+		);
+
+		assert_ok!(AddressUnificationModule::request_relink(
+			RuntimeOrigin::signed(ALICE),
+			new_evm_address,
+			AddressUnificationModule::eth_sign_relink(&bob(), &ALICE, &old_evm_address, 100),
+			100
+		));
+		assert_noop!(
+			AddressUnificationModule::request_relink(
+				RuntimeOrigin::signed(ALICE),
+				new_evm_address,
+				AddressUnificationModule::eth_sign_relink(&bob(), &ALICE, &old_evm_address, 100),
+				100
+			),
+			Error::<Runtime>::RelinkAlreadyRequested
+		);
+
+		// the new address got mapped in the meantime
+		assert_ok!(AddressUnificationModule::claim_account(
+			RuntimeOrigin::signed(BOB),
+			new_evm_address,
+			AddressUnificationModule::eth_sign(&bob(), &BOB)
+		));
+		System::set_block_number(1 + RelinkDelay::get());
+		assert_noop!(
+			AddressUnificationModule::execute_relink(RuntimeOrigin::signed(ALICE)),
+			Error::<Runtime>::EthAddressHasMapped
+		);
+	});
+}
+
+#[test]
+fn relink_signature_cannot_be_replayed() {
+	ExtBuilder::default().build().execute_with(|| {
+		let first_evm_address = AddressUnificationModule::evm_address(&alice());
+		let second_evm_address = AddressUnificationModule::evm_address(&bob());
+		assert_ok!(AddressUnificationModule::claim_account(
+			RuntimeOrigin::signed(ALICE),
+			first_evm_address,
+			AddressUnificationModule::eth_sign(&alice(), &ALICE)
+		));
+
+		// relink to the second address and back again
+		let signature =
+			AddressUnificationModule::eth_sign_relink(&bob(), &ALICE, &first_evm_address, 100);
+		assert_ok!(AddressUnificationModule::request_relink(
+			RuntimeOrigin::signed(ALICE),
+			second_evm_address,
+			signature,
+			100
+		));
+		assert_eq!(AddressUnificationModule::claim_nonces(second_evm_address), 1);
+		System::set_block_number(1 + RelinkDelay::get());
+		assert_ok!(AddressUnificationModule::execute_relink(RuntimeOrigin::signed(ALICE)));
+
+		assert_ok!(AddressUnificationModule::request_relink(
+			RuntimeOrigin::signed(ALICE),
+			first_evm_address,
+			AddressUnificationModule::eth_sign_relink(&alice(), &ALICE, &second_evm_address, 100),
+			100
+		));
+		System::set_block_number(1 + 2 * RelinkDelay::get());
+		assert_ok!(AddressUnificationModule::execute_relink(RuntimeOrigin::signed(ALICE)));
+		assert!(AddressUnificationModule::is_linked(&ALICE, &first_evm_address));
+
+		// the mapping is the same as before, but the nonce of the second address is used up
+		assert_noop!(
+			AddressUnificationModule::request_relink(
+				RuntimeOrigin::signed(ALICE),
+				second_evm_address,
+				signature,
+				100
+			),
+			Error::<Runtime>::InvalidSignature
+		);
+	});
+}
+
+#[test]
+fn cancel_relink_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			AddressUnificationModule::cancel_relink(RuntimeOrigin::signed(ALICE)),
+			Error::<Runtime>::NoRelinkRequested
+		);
+
+		let old_evm_address = AddressUnificationModule::evm_address(&alice());
+		let new_evm_address = AddressUnificationModule::evm_address(&bob());
+		assert_ok!(AddressUnificationModule::claim_account(
+			RuntimeOrigin::signed(ALICE),
+			old_evm_address,
+			AddressUnificationModule::eth_sign(&alice(), &ALICE)
+		));
+		assert_ok!(AddressUnificationModule::request_relink(
+			RuntimeOrigin::signed(ALICE),
+			new_evm_address,
+			AddressUnificationModule::eth_sign_relink(&bob(), &ALICE, &old_evm_address, 100),
+			100
+		));
+
+		assert_ok!(AddressUnificationModule::cancel_relink(RuntimeOrigin::signed(ALICE)));
+		System::assert_last_event(RuntimeEvent::AddressUnificationModule(
+			crate::Event::RelinkCancelled { account_id: ALICE, evm_address: new_evm_address },
+		));
+		assert!(!PendingRelinks::<Runtime>::contains_key(ALICE));
+
+		System::set_block_number(1 + RelinkDelay::get());
+		assert_noop!(
+			AddressUnificationModule::execute_relink(RuntimeOrigin::signed(ALICE)),
+			Error::<Runtime>::NoRelinkRequested
+		);
+		assert!(AddressUnificationModule::is_linked(&ALICE, &old_evm_address));
+	});
+}
+
+fn fund_default_account(evm_address: EvmAddress, asset: u32) -> AccountIdOf<Runtime> {
+	let default_account = HashedAddressMapping::<BlakeTwo256>::into_account_id(evm_address);
+	let _ = Balances::deposit_creating(&default_account, 100);
+	assert_ok!(Assets::force_create(RuntimeOrigin::root(), asset, BOB, true, 1));
+	assert_ok!(Assets::mint(RuntimeOrigin::signed(BOB), asset, default_account.clone(), 500));
+	default_account
+}
+
+#[test]
+fn claim_account_with_sweep_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let evm_address = AddressUnificationModule::evm_address(&alice());
+		let default_account = fund_default_account(evm_address, 1);
+
+		// the plain claim still rejects a non-empty default account
+		assert_noop!(
+			AddressUnificationModule::claim_account(
+				RuntimeOrigin::signed(ALICE),
+				evm_address,
+				AddressUnificationModule::eth_sign(&alice(), &ALICE)
+			),
+			Error::<Runtime>::NonEmptyAccounts
+		);
+
+		assert_ok!(AddressUnificationModule::claim_account_with_sweep(
+			RuntimeOrigin::signed(ALICE),
+			evm_address,
+			AddressUnificationModule::eth_sign_with_deadline(&alice(), &ALICE, 10),
+			10,
+			vec![1].try_into().unwrap()
+		));
+		System::assert_has_event(RuntimeEvent::AddressUnificationModule(
+			crate::Event::DefaultAccountSwept {
+				account_id: ALICE,
+				default_account_id: default_account.clone(),
+				amount: 100,
+				assets: 1,
+			},
+		));
+		System::assert_last_event(RuntimeEvent::AddressUnificationModule(
+			crate::Event::ClaimAccount { account_id: ALICE, evm_address },
+		));
+
+		assert_eq!(Balances::free_balance(ALICE), 100);
+		assert_eq!(Assets::balance(1, ALICE), 500);
+		assert!(!System::account_exists(&default_account));
+		assert_eq!(AddressUnificationModule::accounts(evm_address), Some(ALICE));
+		assert_eq!(AddressUnificationModule::evm_addresses(ALICE), Some(evm_address));
+	});
+}
+
+#[test]
+fn claim_account_with_sweep_empty_default_account_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let evm_address = AddressUnificationModule::evm_address(&alice());
+		assert_ok!(AddressUnificationModule::claim_account_with_sweep(
+			RuntimeOrigin::signed(ALICE),
+			evm_address,
+			AddressUnificationModule::eth_sign_with_deadline(&alice(), &ALICE, 10),
+			10,
+			Default::default()
+		));
+		System::assert_last_event(RuntimeEvent::AddressUnificationModule(
+			crate::Event::ClaimAccount { account_id: ALICE, evm_address },
+		));
+		assert!(!System::events().iter().any(|record| matches!(
+			record.event,
+			RuntimeEvent::AddressUnificationModule(crate::Event::DefaultAccountSwept { .. })
+		)));
+	});
+}
+
+#[test]
+fn claim_account_with_sweep_should_not_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let evm_address = AddressUnificationModule::evm_address(&alice());
+		let default_account = fund_default_account(evm_address, 1);
+
+		assert_noop!(
+			AddressUnificationModule::claim_account_with_sweep(
+				RuntimeOrigin::signed(ALICE),
+				evm_address,
+				AddressUnificationModule::eth_sign_with_deadline(&alice(), &BOB, 10),
+				10,
+				vec![1].try_into().unwrap()
+			),
+			Error::<Runtime>::InvalidSignature
+		);
+
+		// the asset balance keeps the default account alive
+		assert_noop!(
+			AddressUnificationModule::claim_account_with_sweep(
+				RuntimeOrigin::signed(ALICE),
+				evm_address,
+				AddressUnificationModule::eth_sign_with_deadline(&alice(), &ALICE, 10),
+				10,
+				Default::default()
+			),
+			Error::<Runtime>::NonEmptyAccounts
+		);
+
+		// locked balances cannot be swept
+		Balances::set_lock(*b"evm_lock", &default_account, 40, WithdrawReasons::all());
+		assert_noop!(
+			AddressUnificationModule::claim_account_with_sweep(
+				RuntimeOrigin::signed(ALICE),
+				evm_address,
+				AddressUnificationModule::eth_sign_with_deadline(&alice(), &ALICE, 10),
+				10,
+				vec![1].try_into().unwrap()
+			),
+			Error::<Runtime>::NonEmptyAccounts
+		);
+	});
+}
+
+#[test]
+fn claim_account_with_deadline_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let evm_address = AddressUnificationModule::evm_address(&alice());
+		assert_eq!(AddressUnificationModule::claim_nonces(evm_address), 0);
+
+		assert_ok!(AddressUnificationModule::claim_account_with_deadline(
+			RuntimeOrigin::signed(ALICE),
+			evm_address,
+			AddressUnificationModule::eth_sign_with_deadline(&alice(), &ALICE, 10),
+			10
+		));
+		System::assert_last_event(RuntimeEvent::AddressUnificationModule(
+			crate::Event::ClaimAccount { account_id: ALICE, evm_address },
+		));
+		assert_eq!(AddressUnificationModule::accounts(evm_address), Some(ALICE));
+		assert_eq!(AddressUnificationModule::claim_nonces(evm_address), 1);
+	});
+}
+
+#[test]
+fn claim_account_with_deadline_should_not_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let evm_address = AddressUnificationModule::evm_address(&alice());
+		let signature = AddressUnificationModule::eth_sign_with_deadline(&alice(), &ALICE, 10);
+
+		// the signature covers the deadline
+		assert_noop!(
+			AddressUnificationModule::claim_account_with_deadline(
+				RuntimeOrigin::signed(ALICE),
+				evm_address,
+				signature,
+				11
+			),
+			Error::<Runtime>::InvalidSignature
+		);
+		// a legacy signature is not accepted
+		assert_noop!(
+			AddressUnificationModule::claim_account_with_deadline(
+				RuntimeOrigin::signed(ALICE),
+				evm_address,
+				AddressUnificationModule::eth_sign(&alice(), &ALICE),
+				10
+			),
+			Error::<Runtime>::InvalidSignature
+		);
+
+		System::set_block_number(11);
+		assert_noop!(
+			AddressUnificationModule::claim_account_with_deadline(
+				RuntimeOrigin::signed(ALICE),
+				evm_address,
+				signature,
+				10
+			),
+			Error::<Runtime>::ClaimExpired
+		);
+		System::set_block_number(10);
+
+		// the signature cannot be replayed once the nonce is used up
+		assert_ok!(AddressUnificationModule::claim_account_with_deadline(
+			RuntimeOrigin::signed(ALICE),
+			evm_address,
+			signature,
+			10
+		));
+		assert_ok!(AddressUnificationModule::unclaim_account(RuntimeOrigin::signed(ALICE)));
+		assert_noop!(
+			AddressUnificationModule::claim_account_with_deadline(
+				RuntimeOrigin::signed(ALICE),
+				evm_address,
+				signature,
+				10
+			),
+			Error::<Runtime>::InvalidSignature
+		);
+	});
+}
+
+#[test]
+fn legacy_claim_signatures_can_be_disabled() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert!(AddressUnificationModule::legacy_claim_signatures());
+		assert_noop!(
+			AddressUnificationModule::set_legacy_claim_signatures(
+				RuntimeOrigin::signed(ALICE),
+				false
+			),
+			DispatchError::BadOrigin
+		);
+
+		assert_ok!(AddressUnificationModule::set_legacy_claim_signatures(
+			RuntimeOrigin::root(),
+			false
+		));
+		System::assert_last_event(RuntimeEvent::AddressUnificationModule(
+			crate::Event::LegacyClaimSignaturesSet { allowed: false },
+		));
+		assert_noop!(
+			AddressUnificationModule::claim_account(
+				RuntimeOrigin::signed(ALICE),
+				AddressUnificationModule::evm_address(&alice()),
+				AddressUnificationModule::eth_sign(&alice(), &ALICE)
+			),
+			Error::<Runtime>::LegacyClaimSignaturesDisabled
+		);
+
+		assert_ok!(AddressUnificationModule::set_legacy_claim_signatures(
+			RuntimeOrigin::root(),
+			true
+		));
+		assert_ok!(AddressUnificationModule::claim_account(
+			RuntimeOrigin::signed(ALICE),
+			AddressUnificationModule::evm_address(&alice()),
+			AddressUnificationModule::eth_sign(&alice(), &ALICE)
+		));
+	});
+}
+
+#[test]
+fn claim_typed_data_can_be_signed() {
+	ExtBuilder::default().build().execute_with(|| {
+		let evm_address = AddressUnificationModule::evm_address(&alice());
+		ClaimNonces::<Runtime>::insert(evm_address, 3);
+
+		let typed_data = AddressUnificationModule::claim_typed_data(&ALICE, evm_address, 10);
+		assert_eq!(typed_data.nonce, 3);
+		assert_eq!(typed_data.deadline, 10);
+		assert_eq!(typed_data.domain.version, b"2".to_vec());
+		assert_eq!(typed_data.substrate_address, ALICE.encode());
+		assert_eq!(typed_data.digest.0, keccak_256(&typed_data.signable_message));
+
+		let (sig, recovery_id) =
+			libsecp256k1::sign(&libsecp256k1::Message::parse(&typed_data.digest.0), &alice());
+		let mut signature = [0u8; 65];
+		signature[0..64].copy_from_slice(&sig.serialize()[..]);
+		signature[64] = recovery_id.serialize();
+
+		assert_eq!(
+			signature,
+			AddressUnificationModule::eth_sign_with_deadline(&alice(), &ALICE, 10)
+		);
+		assert_ok!(AddressUnificationModule::claim_account_with_deadline(
+			RuntimeOrigin::signed(ALICE),
+			evm_address,
+			signature,
+			10
+		));
+	});
+}
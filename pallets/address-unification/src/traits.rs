@@ -1,4 +1,13 @@
+use frame_support::{
+	pallet_prelude::*,
+	traits::{
+		fungibles,
+		tokens::{Fortitude, Preservation},
+	},
+};
 use peaq_primitives_xcm::evm::EvmAddress;
+use sp_runtime::traits::Zero;
+use sp_std::marker::PhantomData;
 
 /// A mapping between `AccountId` and `EvmAddress`.
 pub trait EVMAddressMapping<AccountId> {
@@ -14,3 +23,80 @@ pub trait EVMAddressMapping<AccountId> {
 	/// and false if is not.
 	fn is_linked(account_id: &AccountId, evm: &EvmAddress) -> bool;
 }
+
+/// Moves asset balances out of an account, used to sweep the default account of an EVM
+/// address into the claiming account.
+pub trait SweepAssets<AccountId> {
+	type AssetId: Parameter + MaxEncodedLen + Copy;
+
+	/// Moves the whole transferable balance of `asset` from `source` to `dest`.
+	/// Returns whether anything was moved.
+	fn sweep(
+		asset: Self::AssetId,
+		source: &AccountId,
+		dest: &AccountId,
+	) -> Result<bool, DispatchError>;
+}
+
+/// No assets to sweep.
+impl<AccountId> SweepAssets<AccountId> for () {
+	type AssetId = u32;
+
+	fn sweep(_: Self::AssetId, _: &AccountId, _: &AccountId) -> Result<bool, DispatchError> {
+		Ok(false)
+	}
+}
+
+/// Sweeps the assets of a `fungibles` implementation, e.g. `pallet_assets`.
+pub struct FungiblesSweeper<F>(PhantomData<F>);
+
+impl<AccountId, F> SweepAssets<AccountId> for FungiblesSweeper<F>
+where
+	F: fungibles::Mutate<AccountId>,
+	F::AssetId: Parameter + MaxEncodedLen + Copy,
+{
+	type AssetId = F::AssetId;
+
+	fn sweep(
+		asset: Self::AssetId,
+		source: &AccountId,
+		dest: &AccountId,
+	) -> Result<bool, DispatchError> {
+		let amount =
+			F::reducible_balance(asset, source, Preservation::Expendable, Fortitude::Polite);
+		if amount.is_zero() {
+			return Ok(false)
+		}
+		F::transfer(asset, source, dest, amount, Preservation::Expendable)?;
+		Ok(true)
+	}
+}
+
+/// Provides funded assets to benchmark the sweep.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AssetId, AccountId> {
+	/// Creates the asset with the given index and funds `who` with it.
+	fn create_funded_asset(index: u32, who: &AccountId) -> AssetId;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl<AssetId: From<u32>, AccountId> BenchmarkHelper<AssetId, AccountId> for () {
+	fn create_funded_asset(index: u32, _: &AccountId) -> AssetId {
+		index.into()
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl<AccountId, F> BenchmarkHelper<F::AssetId, AccountId> for FungiblesSweeper<F>
+where
+	AccountId: Clone,
+	F: fungibles::Create<AccountId> + fungibles::Mutate<AccountId>,
+	F::AssetId: From<u32>,
+{
+	fn create_funded_asset(index: u32, who: &AccountId) -> F::AssetId {
+		let asset = F::AssetId::from(index);
+		F::create(asset, who.clone(), true, 1u32.into()).expect("asset can be created");
+		F::mint_into(asset, who, 1_000u32.into()).expect("asset can be minted");
+		asset
+	}
+}
@@ -6,4 +6,11 @@ use frame_support::weights::Weight;
 pub trait WeightInfo {
 	fn claim_account() -> Weight;
 	fn claim_default_account() -> Weight;
+	fn unclaim_account() -> Weight;
+	fn request_relink() -> Weight;
+	fn execute_relink() -> Weight;
+	fn cancel_relink() -> Weight;
+	fn claim_account_with_sweep(a: u32) -> Weight;
+	fn claim_account_with_deadline() -> Weight;
+	fn set_legacy_claim_signatures() -> Weight;
 }
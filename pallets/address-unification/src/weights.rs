@@ -39,14 +39,16 @@ impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
 	/// Proof: System BlockHash (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:0)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AddressUnification LegacyClaimSignatures (r:1 w:0)
+	/// Proof: AddressUnification LegacyClaimSignatures (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
 	fn claim_account() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `173`
 		//  Estimated: `3593`
-		// Minimum execution time: 95_973_000 picoseconds.
-		Weight::from_parts(96_814_000, 0)
+		// Minimum execution time: 97_254_000 picoseconds.
+		Weight::from_parts(98_102_000, 0)
 			.saturating_add(Weight::from_parts(0, 3593))
-			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: AddressUnification EvmAddresses (r:1 w:1)
@@ -65,4 +67,133 @@ impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
+	/// Storage: AddressUnification EvmAddresses (r:1 w:1)
+	/// Proof: AddressUnification EvmAddresses (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AddressUnification PendingRelinks (r:0 w:1)
+	/// Proof: AddressUnification PendingRelinks (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: AddressUnification Accounts (r:0 w:1)
+	/// Proof: AddressUnification Accounts (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	fn unclaim_account() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `257`
+		//  Estimated: `6196`
+		// Minimum execution time: 38_214_000 picoseconds.
+		Weight::from_parts(38_902_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: AddressUnification EvmAddresses (r:1 w:0)
+	/// Proof: AddressUnification EvmAddresses (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: AddressUnification PendingRelinks (r:1 w:1)
+	/// Proof: AddressUnification PendingRelinks (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: AddressUnification Accounts (r:1 w:0)
+	/// Proof: AddressUnification Accounts (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: AddressUnification ClaimNonces (r:1 w:1)
+	/// Proof: AddressUnification ClaimNonces (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: System BlockHash (r:1 w:0)
+	/// Proof: System BlockHash (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	fn request_relink() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `215`
+		//  Estimated: `3533`
+		// Minimum execution time: 99_127_000 picoseconds.
+		Weight::from_parts(100_052_000, 0)
+			.saturating_add(Weight::from_parts(0, 3533))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: AddressUnification PendingRelinks (r:1 w:1)
+	/// Proof: AddressUnification PendingRelinks (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: AddressUnification EvmAddresses (r:1 w:1)
+	/// Proof: AddressUnification EvmAddresses (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: AddressUnification Accounts (r:1 w:2)
+	/// Proof: AddressUnification Accounts (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: System Account (r:3 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn execute_relink() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `331`
+		//  Estimated: `8799`
+		// Minimum execution time: 45_730_000 picoseconds.
+		Weight::from_parts(46_518_000, 0)
+			.saturating_add(Weight::from_parts(0, 8799))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: AddressUnification PendingRelinks (r:1 w:1)
+	/// Proof: AddressUnification PendingRelinks (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	fn cancel_relink() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `188`
+		//  Estimated: `3533`
+		// Minimum execution time: 17_406_000 picoseconds.
+		Weight::from_parts(17_893_000, 0)
+			.saturating_add(Weight::from_parts(0, 3533))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: AddressUnification EvmAddresses (r:1 w:1)
+	/// Proof: AddressUnification EvmAddresses (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: AddressUnification Accounts (r:1 w:1)
+	/// Proof: AddressUnification Accounts (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: System BlockHash (r:1 w:0)
+	/// Proof: System BlockHash (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: AddressUnification ClaimNonces (r:1 w:1)
+	/// Proof: AddressUnification ClaimNonces (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:16 w:16)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(222), added: 2697, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:32 w:32)
+	/// Proof: Assets Account (max_values: None, max_size: Some(146), added: 2621, mode: MaxEncodedLen)
+	/// The range of component `a` is `[0, 16]`.
+	fn claim_account_with_sweep(a: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `276 + a * (285 ±0)`
+		//  Estimated: `6196 + a * (5180 ±0)`
+		// Minimum execution time: 152_118_000 picoseconds.
+		Weight::from_parts(155_902_334, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			// Standard Error: 38_211
+			.saturating_add(Weight::from_parts(41_367_925, 0).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().writes(5))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(0, 5180).saturating_mul(a.into()))
+	}
+	/// Storage: AddressUnification EvmAddresses (r:1 w:1)
+	/// Proof: AddressUnification EvmAddresses (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: AddressUnification Accounts (r:1 w:1)
+	/// Proof: AddressUnification Accounts (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: AddressUnification ClaimNonces (r:1 w:1)
+	/// Proof: AddressUnification ClaimNonces (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: System BlockHash (r:1 w:0)
+	/// Proof: System BlockHash (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn claim_account_with_deadline() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `173`
+		//  Estimated: `3593`
+		// Minimum execution time: 99_415_000 picoseconds.
+		Weight::from_parts(100_367_000, 0)
+			.saturating_add(Weight::from_parts(0, 3593))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: AddressUnification LegacyClaimSignatures (r:0 w:1)
+	/// Proof: AddressUnification LegacyClaimSignatures (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	fn set_legacy_claim_signatures() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 8_214_000 picoseconds.
+		Weight::from_parts(8_532_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
@@ -0,0 +1,93 @@
+//! RPC interface for the block-reward pallet.
+
+use std::sync::Arc;
+
+use jsonrpsee::{
+	core::{async_trait, RpcResult},
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use parity_scale_codec::Codec;
+use serde::Serialize;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+pub use pallet_block_reward_runtime_api::{
+	BeneficiaryReward, BlockRewardApi as BlockRewardRuntimeApi, BlockRewardInfo, RewardBeneficiary,
+	YearlyIssuance,
+};
+
+#[rpc(client, server)]
+pub trait BlockRewardApi<BlockHash, AccountId, Balance, Moment> {
+	/// Return the current block reward, its distribution and the end of the
+	/// current inflation year.
+	#[method(name = "blockReward_info")]
+	fn block_reward_info(
+		&self,
+		at: Option<BlockHash>,
+	) -> RpcResult<BlockRewardInfo<AccountId, Balance, Moment>>;
+
+	/// Return the projected issuance of the current and the following years.
+	#[method(name = "blockReward_issuanceSchedule")]
+	fn issuance_schedule(
+		&self,
+		years: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<YearlyIssuance<Balance>>>;
+}
+
+/// Provides RPC methods to query the block reward and the inflation schedule.
+pub struct BlockReward<C, B> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> BlockReward<C, B> {
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+/// Error code of failed runtime calls.
+const RUNTIME_ERROR: i32 = 1;
+
+fn runtime_error(message: &str, error: impl std::fmt::Debug) -> jsonrpsee::core::Error {
+	CallError::Custom(ErrorObject::owned(RUNTIME_ERROR, message, Some(format!("{:?}", error))))
+		.into()
+}
+
+#[async_trait]
+impl<C, Block, AccountId, Balance, Moment>
+	BlockRewardApiServer<<Block as BlockT>::Hash, AccountId, Balance, Moment> for BlockReward<C, Block>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: BlockRewardRuntimeApi<Block, AccountId, Balance, Moment>,
+	AccountId: Codec + Serialize + Send + Sync + 'static,
+	Balance: Codec + Serialize + Send + Sync + 'static,
+	Moment: Codec + Serialize + Send + Sync + 'static,
+{
+	fn block_reward_info(
+		&self,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<BlockRewardInfo<AccountId, Balance, Moment>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.block_reward_info(at)
+			.map_err(|e| runtime_error("Unable to query the block reward.", e))
+	}
+
+	fn issuance_schedule(
+		&self,
+		years: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<YearlyIssuance<Balance>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.issuance_schedule(at, years)
+			.map_err(|e| runtime_error("Unable to query the issuance schedule.", e))
+	}
+}
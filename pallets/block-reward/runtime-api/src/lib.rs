@@ -0,0 +1,96 @@
+//! Runtime API definition of the block-reward pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+// These clippy lints are disabled because the macro-generated code triggers them.
+#![allow(clippy::too_many_arguments)]
+
+use parity_scale_codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::{Perbill, RuntimeDebug};
+use sp_std::vec::Vec;
+
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+/// Recipient of a portion of the block reward.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub enum RewardBeneficiary<AccountId> {
+	Treasury,
+	CollatorsDelegators,
+	Coretime,
+	SubsidizationPool,
+	DepinStaking,
+	DepinIncentivization,
+	Burn,
+	/// An account registered directly or derived from a pallet id.
+	Account(AccountId),
+}
+
+/// The portion of the block reward which goes to a beneficiary.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct BeneficiaryReward<AccountId, Balance> {
+	/// The recipient of the reward.
+	pub beneficiary: RewardBeneficiary<AccountId>,
+	/// The share of the block reward.
+	pub share: Perbill,
+	/// The absolute amount paid out per block.
+	pub amount: Balance,
+}
+
+/// The current block reward and how it is distributed.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct BlockRewardInfo<AccountId, Balance, Moment> {
+	/// The amount issued per block.
+	pub block_reward: Balance,
+	/// The split of the block reward between the beneficiaries.
+	pub distribution: Vec<BeneficiaryReward<AccountId, Balance>>,
+	/// The current inflation year.
+	pub current_year: u128,
+	/// The inflation rate of the current year.
+	pub inflation_rate: Perbill,
+	/// The timestamp at which the current inflation year ends and the
+	/// inflation parameters are recalculated, if the year has started.
+	pub next_recalculation_at: Option<Moment>,
+}
+
+/// The projected issuance of a single inflation year.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct YearlyIssuance<Balance> {
+	/// The inflation year.
+	pub year: u128,
+	/// The inflation rate of the year.
+	pub inflation_rate: Perbill,
+	/// The amount issued per block during the year, assuming the expected
+	/// block time.
+	pub block_reward: Balance,
+	/// The amount issued during the year. For the current year only the
+	/// remaining issuance is taken into account.
+	pub issuance: Balance,
+	/// The total issuance at the end of the year.
+	pub total_issuance: Balance,
+}
+
+sp_api::decl_runtime_apis! {
+	/// The API to query the block reward and the inflation schedule.
+	pub trait BlockRewardApi<AccountId, Balance, Moment>
+	where
+		AccountId: Codec,
+		Balance: Codec,
+		Moment: Codec,
+	{
+		/// Return the current block reward, its distribution and the end of
+		/// the current inflation year.
+		fn block_reward_info() -> BlockRewardInfo<AccountId, Balance, Moment>;
+		/// Return the projected issuance of the current and the following
+		/// years, `years` entries in total.
+		fn issuance_schedule(years: u32) -> Vec<YearlyIssuance<Balance>>;
+	}
+}
@@ -0,0 +1,135 @@
+//! Helper functions for the implementation of the `BlockRewardApi` runtime API.
+
+use crate::{BeneficiaryDistribution, BeneficiaryKind, Config, Pallet};
+use frame_support::traits::Currency;
+use inflation_manager::{
+	Config as InflationManagerConfig, CurrentYear, DoInitializeAt, FiscalYear,
+	InflationConfiguration, InflationParameters, Pallet as InflationManagerPallet,
+	PendingInflationConfiguration, TotalIssuanceNum,
+};
+use pallet_block_reward_runtime_api::{
+	BeneficiaryReward, BlockRewardInfo, RewardBeneficiary, YearlyIssuance,
+};
+use peaq_primitives_xcm::Balance;
+use sp_runtime::{
+	traits::{AccountIdConversion, Saturating, Zero},
+	SaturatedConversion,
+};
+use sp_std::vec::Vec;
+
+/// Maximum number of years returned by `issuance_schedule`.
+const MAX_SCHEDULE_YEARS: u32 = 100;
+
+impl<T: Config + InflationManagerConfig> Pallet<T> {
+	/// Return the current block reward, its distribution and the end of the current year.
+	pub fn block_reward_info() -> BlockRewardInfo<T::AccountId, Balance, T::Moment> {
+		let block_reward = InflationManagerPallet::<T>::block_rewards();
+
+		let mut beneficiaries = BeneficiaryDistribution::<T>::get().into_inner();
+		if beneficiaries.is_empty() {
+			beneficiaries = Self::reward_config().to_beneficiaries();
+		}
+
+		// Same split as the distribution, the last beneficiary receives the remainder
+		let mut remainder = block_reward;
+		let last = beneficiaries.len().saturating_sub(1);
+		let distribution = beneficiaries
+			.into_iter()
+			.enumerate()
+			.map(|(i, (beneficiary, share))| {
+				let amount = if i == last { remainder } else { share * block_reward };
+				remainder = remainder.saturating_sub(amount);
+				BeneficiaryReward { beneficiary: Self::api_beneficiary(beneficiary), share, amount }
+			})
+			.collect();
+
+		BlockRewardInfo {
+			block_reward,
+			distribution,
+			current_year: CurrentYear::<T>::get(),
+			inflation_rate: InflationParameters::<T>::get().inflation_rate,
+			next_recalculation_at: FiscalYear::<T>::get()
+				.map(|fiscal_year| fiscal_year.end)
+				.filter(|end| !end.is_zero()),
+		}
+	}
+
+	/// Return the projected issuance of the current and the following years.
+	///
+	/// The projection follows the yearly recalculation of the inflation-manager, including the
+	/// maximum currency supply, and assumes that nothing but the block rewards changes the
+	/// total issuance.
+	pub fn issuance_schedule(years: u32) -> Vec<YearlyIssuance<Balance>> {
+		// A pending configuration is in effect for the following years
+		let inflation_config = PendingInflationConfiguration::<T>::get()
+			.map(|pending| pending.inflation_configuration)
+			.unwrap_or_else(InflationConfiguration::<T>::get);
+
+		let mut year = CurrentYear::<T>::get();
+		let mut inflation_rate = InflationParameters::<T>::get().inflation_rate;
+		let mut block_reward = InflationManagerPallet::<T>::block_rewards();
+		let mut total_issuance = <T as Config>::Currency::total_issuance();
+		let mut issuance = match FiscalYear::<T>::get() {
+			Some(fiscal_year) if year > 0 => fiscal_year.budget.saturating_sub(fiscal_year.issued),
+			// Until the delayed TGE a fixed reward is issued per block
+			_ => {
+				let now = frame_system::Pallet::<T>::block_number();
+				let blocks: Balance =
+					DoInitializeAt::<T>::get().saturating_sub(now).saturated_into();
+				block_reward.saturating_mul(blocks)
+			},
+		};
+
+		let mut schedule = Vec::new();
+		for _ in 0..years.min(MAX_SCHEDULE_YEARS) {
+			issuance = InflationManagerPallet::<T>::capped_issuance_for(issuance, total_issuance);
+			total_issuance = total_issuance.saturating_add(issuance);
+			schedule.push(YearlyIssuance {
+				year,
+				inflation_rate,
+				block_reward,
+				issuance,
+				total_issuance,
+			});
+
+			// The delayed TGE tops up the total issuance when the first year starts
+			if year == 0 {
+				total_issuance = total_issuance.max(TotalIssuanceNum::<T>::get());
+			}
+
+			year = year.saturating_add(1);
+			let inflation_parameters =
+				InflationManagerPallet::<T>::adjusted_inflation_parameters_for_year(
+					&inflation_config,
+					year,
+				);
+			inflation_rate = inflation_parameters.inflation_rate;
+			block_reward = InflationManagerPallet::<T>::rewards_per_block_for(
+				&inflation_parameters,
+				total_issuance,
+			);
+			issuance = InflationManagerPallet::<T>::yearly_issuance_for(
+				&inflation_parameters,
+				total_issuance,
+			);
+		}
+		schedule
+	}
+
+	fn api_beneficiary(
+		beneficiary: BeneficiaryKind<T::AccountId>,
+	) -> RewardBeneficiary<T::AccountId> {
+		match beneficiary {
+			BeneficiaryKind::Treasury => RewardBeneficiary::Treasury,
+			BeneficiaryKind::CollatorsDelegators => RewardBeneficiary::CollatorsDelegators,
+			BeneficiaryKind::Coretime => RewardBeneficiary::Coretime,
+			BeneficiaryKind::SubsidizationPool => RewardBeneficiary::SubsidizationPool,
+			BeneficiaryKind::DepinStaking => RewardBeneficiary::DepinStaking,
+			BeneficiaryKind::DepinIncentivization => RewardBeneficiary::DepinIncentivization,
+			BeneficiaryKind::Burn => RewardBeneficiary::Burn,
+			BeneficiaryKind::Pallet(pallet_id) =>
+				RewardBeneficiary::Account(pallet_id.into_account_truncating()),
+			BeneficiaryKind::Account(account) => RewardBeneficiary::Account(account),
+		}
+	}
+}
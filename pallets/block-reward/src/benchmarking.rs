@@ -2,8 +2,10 @@
 
 use super::*;
 
-use frame_benchmarking::v1::{benchmarks, impl_benchmark_test_suite};
+use frame_benchmarking::v1::{account, benchmarks, impl_benchmark_test_suite};
 use frame_system::{Pallet as System, RawOrigin};
+use sp_runtime::Perbill;
+use sp_std::vec::Vec;
 
 /// Assert that the last event equals the provided one.
 fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
@@ -20,6 +22,30 @@ benchmarks! {
 		assert_last_event::<T>(Event::<T>::DistributionConfigurationChanged(reward_config).into());
 	}
 
+	set_beneficiaries {
+		let n in 1 .. T::MaxBeneficiaries::get();
+
+		let share = Perbill::from_parts(Perbill::one().deconstruct() / n);
+		let mut beneficiaries: Vec<_> = (1..n)
+			.map(|i| (BeneficiaryKind::Account(account("beneficiary", i, 0)), share))
+			.collect();
+		let rest = Perbill::from_parts(Perbill::one().deconstruct() - share.deconstruct() * (n - 1));
+		beneficiaries.push((BeneficiaryKind::Treasury, rest));
+		let beneficiaries: BeneficiariesOf<T> = beneficiaries.try_into().unwrap();
+		assert!(is_consistent_distribution(&beneficiaries));
+	}: _(RawOrigin::Root, beneficiaries.clone())
+	verify {
+		assert_last_event::<T>(Event::<T>::BeneficiariesChanged(beneficiaries).into());
+	}
+
+	set_fee_configuration {
+		let fee_config = RewardDistributionConfig::default();
+		assert!(fee_config.is_consistent());
+	}: _(RawOrigin::Root, fee_config.clone())
+	verify {
+		assert_last_event::<T>(Event::<T>::FeeDistributionConfigurationChanged(fee_config).into());
+	}
+
 }
 
 #[cfg(test)]
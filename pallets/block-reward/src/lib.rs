@@ -12,13 +12,21 @@
 //! Major on-chain factors which can influence reward distribution are total issuance and total
 //! value locked by dapps staking.
 //!
+//! A portion of the distributed imbalances can be burned. The burned amount is tracked in
+//! `TotalBurned` and reported once per block by the `TokensBurned` event.
+//!
+//! Block rewards are clipped to the `MaxCurrencySupply` of the inflation-manager, the
+//! `MaxCurrencySupplyReached` event is emitted once, in the block where minting stops.
+//!
 //! ## Interface
 //!
 //! ### Dispatchable Function
 //!
 //! - `set_configuration` - used to change reward distribution configuration parameters
+//! - `set_fee_configuration` - used to change transaction fee distribution configuration parameters
+//! - `set_beneficiaries` - used to switch to the dynamic distribution mode with a governance
+//!   defined list of beneficiaries
 //! - `set_block_issue_reward` - used to change block issue reward configuration parameter
-//! - `set_max_currency_supply` - used to change the maximum currency supply parameter
 //!
 //! ### Other
 //!
@@ -26,15 +34,15 @@
 //!   production. Note: We assume that it's impossible to set timestamp two times in a block.
 //! - `on_unbalanced` - This pallet implements the `OnUnbalanced` trait to handle the distribution
 //!   of tokens generally. Any kind of `Imbalance` can be passed to that method, to be distributed
-//!   the same way as block-rewards as `BeneficiaryPayout`. In case of a vector of imbalances you
-//!   can also use `on_unblananceds`.
+//!   by the fee distribution configuration as `BeneficiaryPayout`. In case of a vector of
+//!   imbalances you can also use `on_unblananceds`.
 //!
 //! ## Usage
 //!
 //! 1. Pallet should be set as a handler of `OnTimestampSet`.
 //! 2. `BeneficiaryPayout` handler should be defined as an impl of `BeneficiaryPayout` trait.
 //! 3. Set `RewardAmount` to desired block reward value in the genesis configuration.
-//! 4. Set `MaxCurrencySupply` to limit maximum currency supply in the genesis configuration.
+//! 4. Set `MaxCurrencySupply` of the inflation-manager to limit the maximum currency supply.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -47,6 +55,7 @@ use frame_support::{
 use frame_system::{ensure_root, pallet_prelude::*};
 use inflation_manager::{Config as InflationManagerConfig, Pallet as InflationManagerPallet};
 use peaq_primitives_xcm::Balance;
+use sp_runtime::traits::{AccountIdConversion, Zero};
 
 #[cfg(feature = "runtime-benchmarks")]
 pub mod benchmarking;
@@ -55,6 +64,7 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+mod api;
 pub mod migrations;
 
 pub mod types;
@@ -79,7 +89,7 @@ pub mod pallet {
 
 	use super::*;
 
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(7);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -96,6 +106,10 @@ pub mod pallet {
 		/// The overarching event type.
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
+		/// Maximum number of beneficiaries in the dynamic distribution mode.
+		#[pallet::constant]
+		type MaxBeneficiaries: Get<u32>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -105,17 +119,62 @@ pub mod pallet {
 	pub(super) type RewardDistributionConfigStorage<T: Config> =
 		StorageValue<_, RewardDistributionConfig, ValueQuery>;
 
+	/// Distribution configuration of the transaction fees.
+	#[pallet::storage]
+	#[pallet::getter(fn fee_config)]
+	pub(super) type FeeDistributionConfigStorage<T: Config> =
+		StorageValue<_, RewardDistributionConfig, ValueQuery>;
+
+	/// Total amount of tokens burned by the distribution so far. Burned tokens are removed from
+	/// the total issuance, which therefore already is the net issuance.
+	#[pallet::storage]
+	#[pallet::getter(fn total_burned)]
+	pub type TotalBurned<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+	/// Amount of tokens burned in the current block.
+	#[pallet::storage]
+	pub(super) type BurnedInBlock<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+	/// Maximum currency supply which the total issuance has reached, `MaxCurrencySupplyReached`
+	/// is emitted only once per cap.
+	#[pallet::storage]
+	pub(super) type ReachedMaxCurrencySupply<T: Config> =
+		StorageValue<_, BalanceOf<T>, OptionQuery>;
+
+	/// Beneficiaries of the dynamic distribution mode for block rewards. If empty, the static
+	/// `RewardDistributionConfigStorage` is used instead.
+	#[pallet::storage]
+	#[pallet::getter(fn beneficiaries)]
+	pub(super) type BeneficiaryDistribution<T: Config> =
+		StorageValue<_, BeneficiariesOf<T>, ValueQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
 	pub enum Event<T: Config> {
 		/// Distribution configuration has been updated.
 		DistributionConfigurationChanged(RewardDistributionConfig),
 
+		/// Fee distribution configuration has been updated.
+		FeeDistributionConfigurationChanged(RewardDistributionConfig),
+
 		/// Rewards have been distributed
 		BlockRewardsDistributed(BalanceOf<T>),
 
 		/// Rewards have been distributed
 		TransactionFeesDistributed(BalanceOf<T>),
+
+		/// Beneficiaries of the dynamic distribution mode have been updated.
+		BeneficiariesChanged(BeneficiariesOf<T>),
+
+		/// A beneficiary of the dynamic distribution mode has been paid out.
+		BeneficiaryRewarded(BeneficiaryKind<T::AccountId>, BalanceOf<T>),
+
+		/// Tokens have been burned in this block. \[amount, total burned\]
+		TokensBurned(BalanceOf<T>, BalanceOf<T>),
+
+		/// The total issuance has reached the maximum currency supply, block rewards are no
+		/// longer minted. \[max currency supply\]
+		MaxCurrencySupplyReached(BalanceOf<T>),
 	}
 
 	#[pallet::error]
@@ -127,13 +186,18 @@ pub mod pallet {
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		pub reward_config: RewardDistributionConfig,
+		pub fee_config: RewardDistributionConfig,
 		pub _phantom: PhantomData<T>,
 	}
 
 	#[cfg(feature = "std")]
 	impl<T: Config> Default for GenesisConfig<T> {
 		fn default() -> Self {
-			Self { reward_config: Default::default(), _phantom: PhantomData }
+			Self {
+				reward_config: Default::default(),
+				fee_config: Default::default(),
+				_phantom: PhantomData,
+			}
 		}
 	}
 
@@ -142,11 +206,25 @@ pub mod pallet {
 		fn build(&self) {
 			assert!(self.reward_config.is_consistent());
 			RewardDistributionConfigStorage::<T>::put(self.reward_config.clone());
+			assert!(self.fee_config.is_consistent());
+			FeeDistributionConfigStorage::<T>::put(self.fee_config.clone());
 		}
 	}
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_now: BlockNumberFor<T>) -> Weight {
+			// on_finalize reads and kills BurnedInBlock and reads TotalBurned
+			T::DbWeight::get().reads_writes(2, 1)
+		}
+
+		fn on_finalize(_now: BlockNumberFor<T>) {
+			let burned = BurnedInBlock::<T>::take();
+			if !burned.is_zero() {
+				Self::deposit_event(Event::<T>::TokensBurned(burned, Self::total_burned()));
+			}
+		}
+
 		fn on_runtime_upgrade() -> frame_support::weights::Weight {
 			migrations::on_runtime_upgrade::<T>()
 		}
@@ -162,6 +240,8 @@ pub mod pallet {
 		///
 		/// - `reward_distro_params` - reward distribution params
 		///
+		/// Switches back to the static distribution mode, if the dynamic one was in use.
+		///
 		/// Emits `DistributionConfigurationChanged` with config embeded into event itself.
 		#[pallet::call_index(0)]
 		#[pallet::weight(T::WeightInfo::set_configuration())]
@@ -176,20 +256,106 @@ pub mod pallet {
 				Error::<T>::InvalidDistributionConfiguration
 			);
 			RewardDistributionConfigStorage::<T>::put(reward_distro_params.clone());
+			BeneficiaryDistribution::<T>::kill();
 
 			Self::deposit_event(Event::<T>::DistributionConfigurationChanged(reward_distro_params));
 
 			Ok(().into())
 		}
+
+		/// Sets the transaction fee distribution configuration parameters which will be used from
+		/// next fee distribution.
+		///
+		/// It is mandatory that all components of configuration sum up to one whole (**100%**),
+		/// otherwise an error `InvalidDistributionConfiguration` will be raised.
+		///
+		/// - `fee_distro_params` - fee distribution params
+		///
+		/// Emits `FeeDistributionConfigurationChanged` with config embeded into event itself.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::set_fee_configuration())]
+		pub fn set_fee_configuration(
+			origin: OriginFor<T>,
+			fee_distro_params: RewardDistributionConfig,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+
+			ensure!(
+				fee_distro_params.is_consistent(),
+				Error::<T>::InvalidDistributionConfiguration
+			);
+			FeeDistributionConfigStorage::<T>::put(fee_distro_params.clone());
+
+			Self::deposit_event(Event::<T>::FeeDistributionConfigurationChanged(fee_distro_params));
+
+			Ok(().into())
+		}
+
+		/// Sets the list of beneficiaries and switches block rewards to the dynamic distribution
+		/// mode, which will be used from next block reward distribution.
+		///
+		/// It is mandatory that the shares of all beneficiaries sum up to one whole (**100%**),
+		/// otherwise an error `InvalidDistributionConfiguration` will be raised. The last
+		/// beneficiary receives the rounding remainder.
+		///
+		/// - `beneficiaries` - list of beneficiaries and their shares
+		///
+		/// Emits `BeneficiariesChanged` with the list embeded into event itself.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::set_beneficiaries(beneficiaries.len() as u32))]
+		pub fn set_beneficiaries(
+			origin: OriginFor<T>,
+			beneficiaries: BeneficiariesOf<T>,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+
+			ensure!(
+				is_consistent_distribution(&beneficiaries),
+				Error::<T>::InvalidDistributionConfiguration
+			);
+			BeneficiaryDistribution::<T>::put(beneficiaries.clone());
+
+			Self::deposit_event(Event::<T>::BeneficiariesChanged(beneficiaries));
+
+			Ok(().into())
+		}
 	}
 
 	impl<Moment, T: Config + InflationManagerConfig> OnTimestampSet<Moment> for Pallet<T> {
 		fn on_timestamp_set(_moment: Moment) {
-			let inflation = <T as pallet::Config>::Currency::issue(
-				InflationManagerPallet::<T>::block_rewards(),
-			);
+			let block_rewards = InflationManagerPallet::<T>::block_rewards();
+			let remaining_supply = InflationManagerPallet::<T>::remaining_supply();
+			let amount =
+				remaining_supply.map_or(block_rewards, |remaining| block_rewards.min(remaining));
+
+			let inflation = <T as pallet::Config>::Currency::issue(amount);
 			let value = inflation.peek();
-			Self::distribute_imbalances(inflation, Event::<T>::BlockRewardsDistributed(value));
+			InflationManagerPallet::<T>::note_issued(value);
+
+			let beneficiaries = Self::beneficiaries();
+			if beneficiaries.is_empty() {
+				Self::distribute_imbalances(inflation, Self::reward_config());
+			} else {
+				Self::distribute_to_beneficiaries(inflation, &beneficiaries);
+			}
+			Self::deposit_event(Event::<T>::BlockRewardsDistributed(value));
+
+			// The total issuance has reached the cap with this block. Burns can lower the total
+			// issuance again afterwards, so it is reported only the first time.
+			if remaining_supply
+				.map_or(false, |remaining| !remaining.is_zero() && amount == remaining)
+			{
+				if let Some(max_currency_supply) =
+					InflationManagerPallet::<T>::max_currency_supply()
+				{
+					if ReachedMaxCurrencySupply::<T>::get() != Some(max_currency_supply) {
+						ReachedMaxCurrencySupply::<T>::put(max_currency_supply);
+						Self::deposit_event(Event::<T>::MaxCurrencySupplyReached(
+							max_currency_supply,
+						));
+					}
+				}
+			}
 		}
 	}
 
@@ -203,7 +369,8 @@ pub mod pallet {
 
 		fn on_nonzero_unbalanced(amount: NegativeImbalanceOf<T>) {
 			let value = amount.peek();
-			Self::distribute_imbalances(amount, Event::<T>::TransactionFeesDistributed(value));
+			Self::distribute_imbalances(amount, Self::fee_config());
+			Self::deposit_event(Event::<T>::TransactionFeesDistributed(value));
 		}
 	}
 
@@ -212,9 +379,11 @@ pub mod pallet {
 		///
 		/// # Arguments
 		/// * `imbalance` - imbalance that will be split and distributed
-		fn distribute_imbalances(imbalance: NegativeImbalanceOf<T>, dpt_event: Event<T>) {
-			let distro_params = Self::reward_config();
-
+		/// * `distro_params` - configuration used to split the imbalance
+		fn distribute_imbalances(
+			imbalance: NegativeImbalanceOf<T>,
+			distro_params: RewardDistributionConfig,
+		) {
 			// Pre-calculate balance which will be deposited for each beneficiary
 			let collator_delegator_balance =
 				distro_params.collators_delegators_percent * imbalance.peek();
@@ -224,6 +393,7 @@ pub mod pallet {
 			let depin_staking_balance = distro_params.depin_staking_percent * imbalance.peek();
 			let depin_incentivization_balance =
 				distro_params.depin_incentivization_percent * imbalance.peek();
+			let burn_balance = distro_params.burn_percent * imbalance.peek();
 
 			// Prepare imbalances
 			let (collator_delegator_imbalance, remainder) =
@@ -232,8 +402,9 @@ pub mod pallet {
 			let (subsidization_pool_imbalance, remainder) =
 				remainder.split(subsidization_pool_balance);
 			let (depin_staking_imbalance, remainder) = remainder.split(depin_staking_balance);
-			let (depin_incentivization_imbalance, treasury_imbalance) =
+			let (depin_incentivization_imbalance, remainder) =
 				remainder.split(depin_incentivization_balance);
+			let (burn_imbalance, treasury_imbalance) = remainder.split(burn_balance);
 
 			// Payout beneficiaries
 			T::BeneficiaryPayout::treasury(treasury_imbalance);
@@ -242,8 +413,93 @@ pub mod pallet {
 			T::BeneficiaryPayout::subsidization_pool(subsidization_pool_imbalance);
 			T::BeneficiaryPayout::depin_staking(depin_staking_imbalance);
 			T::BeneficiaryPayout::depin_incentivization(depin_incentivization_imbalance);
+			Self::burn(burn_imbalance);
+		}
+
+		/// Distribute imbalances between the beneficiaries of the dynamic distribution mode.
+		///
+		/// The last beneficiary receives whatever is left after all other shares were split off.
+		fn distribute_to_beneficiaries(
+			imbalance: NegativeImbalanceOf<T>,
+			beneficiaries: &BeneficiariesOf<T>,
+		) {
+			let (last, others) = match beneficiaries.split_last() {
+				Some(((last, _), others)) => (last, others),
+				None => return,
+			};
+
+			let total = imbalance.peek();
+			let mut remainder = imbalance;
+			for (beneficiary, share) in others {
+				let (reward, rest) = remainder.split(*share * total);
+				remainder = rest;
+				Self::payout(beneficiary, reward);
+			}
+			Self::payout(last, remainder);
+		}
+
+		/// Payout a single beneficiary of the dynamic distribution mode.
+		fn payout(beneficiary: &BeneficiaryKind<T::AccountId>, reward: NegativeImbalanceOf<T>) {
+			let value = reward.peek();
+			if value.is_zero() {
+				return
+			}
+
+			match beneficiary {
+				BeneficiaryKind::Treasury => T::BeneficiaryPayout::treasury(reward),
+				BeneficiaryKind::CollatorsDelegators =>
+					T::BeneficiaryPayout::collators_delegators(reward),
+				BeneficiaryKind::Coretime => T::BeneficiaryPayout::coretime(reward),
+				BeneficiaryKind::SubsidizationPool =>
+					T::BeneficiaryPayout::subsidization_pool(reward),
+				BeneficiaryKind::DepinStaking => T::BeneficiaryPayout::depin_staking(reward),
+				BeneficiaryKind::DepinIncentivization =>
+					T::BeneficiaryPayout::depin_incentivization(reward),
+				BeneficiaryKind::Pallet(pallet_id) =>
+					return Self::payout_account(
+						beneficiary,
+						&pallet_id.into_account_truncating(),
+						reward,
+					),
+				BeneficiaryKind::Account(account) =>
+					return Self::payout_account(beneficiary, account, reward),
+				BeneficiaryKind::Burn => Self::burn(reward),
+			}
+			Self::deposit_event(Event::<T>::BeneficiaryRewarded(beneficiary.clone(), value));
+		}
+
+		/// Payout a beneficiary account of the dynamic distribution mode.
+		///
+		/// A reward below the existential deposit cannot create a new account, so it is burned
+		/// instead of being dropped silently.
+		fn payout_account(
+			beneficiary: &BeneficiaryKind<T::AccountId>,
+			account: &T::AccountId,
+			reward: NegativeImbalanceOf<T>,
+		) {
+			let value = reward.peek();
+			if let Err(reward) = <T as Config>::Currency::resolve_into_existing(account, reward) {
+				if value < <T as Config>::Currency::minimum_balance() {
+					Self::burn(reward);
+					return
+				}
+				// Depositing at least the existential deposit always creates the account
+				<T as Config>::Currency::resolve_creating(account, reward);
+			}
+			Self::deposit_event(Event::<T>::BeneficiaryRewarded(beneficiary.clone(), value));
+		}
 
-			Self::deposit_event(dpt_event);
+		/// Burn the imbalance and keep track of the burned amount.
+		fn burn(imbalance: NegativeImbalanceOf<T>) {
+			let value = imbalance.peek();
+			if value.is_zero() {
+				return
+			}
+
+			TotalBurned::<T>::mutate(|total| *total = total.saturating_add(value));
+			BurnedInBlock::<T>::mutate(|burned| *burned = burned.saturating_add(value));
+			// Dropping the negative imbalance reduces the total issuance
+			drop(imbalance);
 		}
 	}
 }
@@ -19,7 +19,24 @@ pub enum ObsoleteStorageReleases {
 }
 
 pub(crate) fn on_runtime_upgrade<T: Config>() -> Weight {
-	v2::MigrateToV2x::<T>::on_runtime_upgrade()
+	let mut weight = v2::MigrateToV2x::<T>::on_runtime_upgrade();
+	weight.saturating_accrue(v5::MigrateToV5::<T>::on_runtime_upgrade());
+	weight.saturating_accrue(v6::MigrateToV6::<T>::on_runtime_upgrade());
+	weight.saturating_accrue(v7::MigrateToV7::<T>::on_runtime_upgrade());
+	weight
+}
+
+/// Distribution configuration storages in the layout used before the burn portion was introduced
+mod pre_v7 {
+	use super::*;
+
+	#[storage_alias]
+	pub(super) type RewardDistributionConfigStorage<T: Config> =
+		StorageValue<Pallet<T>, RewardDistributionConfigV1, OptionQuery>;
+
+	#[storage_alias]
+	pub(super) type FeeDistributionConfigStorage<T: Config> =
+		StorageValue<Pallet<T>, RewardDistributionConfigV1, OptionQuery>;
 }
 
 mod v2 {
@@ -46,7 +63,7 @@ mod v2 {
 			let mut weight_writes = 0;
 			let mut weight_reads = 2;
 
-			let current = Pallet::<T>::current_storage_version();
+			let current = StorageVersion::new(4);
 			let onchain_version = Pallet::<T>::on_chain_storage_version();
 
 			if onchain_version < current {
@@ -74,9 +91,9 @@ mod v2 {
 				}
 
 				log!(info, "Enter and do the migration, {:?} < {:?}", onchain_version, current);
-				if RewardDistributionConfigStorage::<T>::exists() {
+				if pre_v7::RewardDistributionConfigStorage::<T>::exists() {
 					log!(info, "Migrating block_reward to Releases::V2_3_0");
-					let new_config = RewardDistributionConfig {
+					let new_config = RewardDistributionConfigV1 {
 						treasury_percent: Perbill::from_percent(25),
 						depin_staking_percent: Perbill::from_percent(5),
 						depin_incentivization_percent: Perbill::from_percent(15),
@@ -84,7 +101,7 @@ mod v2 {
 						coretime_percent: Perbill::from_percent(10),
 						subsidization_pool_percent: Perbill::from_percent(5),
 					};
-					RewardDistributionConfigStorage::<T>::put(new_config);
+					pre_v7::RewardDistributionConfigStorage::<T>::put(new_config);
 					log!(info, "Releases::V2_3_0 Migrating Done.");
 					weight_reads += 1;
 					weight_writes += 1;
@@ -99,3 +116,120 @@ mod v2 {
 		}
 	}
 }
+
+mod v5 {
+	use super::*;
+
+	/// Migration implementation that converts the static reward distribution configuration into
+	/// the list of beneficiaries of the dynamic distribution mode
+	pub struct MigrateToV5<T>(sp_std::marker::PhantomData<T>);
+
+	impl<T: Config> MigrateToV5<T> {
+		pub fn on_runtime_upgrade() -> Weight {
+			let mut weight_writes = 0;
+			let mut weight_reads = 1;
+
+			let current = StorageVersion::new(5);
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+
+			if onchain_version < current {
+				log!(info, "Enter and do the migration, {:?} < {:?}", onchain_version, current);
+				let config: RewardDistributionConfig =
+					pre_v7::RewardDistributionConfigStorage::<T>::get()
+						.map(Into::into)
+						.unwrap_or_default();
+				match BeneficiariesOf::<T>::try_from(config.to_beneficiaries()) {
+					Ok(beneficiaries) => {
+						BeneficiaryDistribution::<T>::put(beneficiaries);
+						weight_writes += 1;
+					},
+					Err(_) => log!(
+						warn,
+						"MaxBeneficiaries too low, keeping the static distribution configuration"
+					),
+				}
+				weight_reads += 1;
+
+				current.put::<Pallet<T>>();
+				weight_writes += 1;
+				log!(info, "Migrating to {:?} Done.", current);
+			}
+			T::DbWeight::get().reads_writes(weight_reads, weight_writes)
+		}
+	}
+}
+
+mod v6 {
+	use super::*;
+
+	/// Migration implementation that initializes the transaction fee distribution configuration
+	/// with the reward distribution configuration, which has been used for fees so far
+	pub struct MigrateToV6<T>(sp_std::marker::PhantomData<T>);
+
+	impl<T: Config> MigrateToV6<T> {
+		pub fn on_runtime_upgrade() -> Weight {
+			let mut weight_writes = 0;
+			let mut weight_reads = 1;
+
+			let current = StorageVersion::new(6);
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+
+			if onchain_version < current {
+				log!(info, "Enter and do the migration, {:?} < {:?}", onchain_version, current);
+				if let Some(config) = pre_v7::RewardDistributionConfigStorage::<T>::get() {
+					pre_v7::FeeDistributionConfigStorage::<T>::put(config);
+					weight_writes += 1;
+				}
+				weight_reads += 1;
+
+				current.put::<Pallet<T>>();
+				weight_writes += 1;
+				log!(info, "Migrating to {:?} Done.", current);
+			}
+			T::DbWeight::get().reads_writes(weight_reads, weight_writes)
+		}
+	}
+}
+
+mod v7 {
+	use super::*;
+
+	/// Migration implementation that adds the burn portion to the reward and fee distribution
+	/// configurations
+	pub struct MigrateToV7<T>(sp_std::marker::PhantomData<T>);
+
+	impl<T: Config> MigrateToV7<T> {
+		pub fn on_runtime_upgrade() -> Weight {
+			let mut weight_writes = 0;
+			let mut weight_reads = 1;
+
+			let current = Pallet::<T>::current_storage_version();
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+
+			if onchain_version < current {
+				log!(info, "Enter and do the migration, {:?} < {:?}", onchain_version, current);
+				if RewardDistributionConfigStorage::<T>::translate::<RewardDistributionConfigV1, _>(
+					|config| config.map(Into::into),
+				)
+				.is_err()
+				{
+					log!(error, "Failed to decode the reward distribution configuration");
+				}
+				if FeeDistributionConfigStorage::<T>::translate::<RewardDistributionConfigV1, _>(
+					|config| config.map(Into::into),
+				)
+				.is_err()
+				{
+					log!(error, "Failed to decode the fee distribution configuration");
+				}
+				weight_reads += 2;
+				weight_writes += 2;
+
+				current.put::<Pallet<T>>();
+				weight_writes += 1;
+				log!(info, "Migrating to {:?} Done.", current);
+			}
+			T::DbWeight::get().reads_writes(weight_reads, weight_writes)
+		}
+	}
+}
@@ -160,6 +160,7 @@ parameter_types! {
 	};
 	pub const InitializeInflationAt: BlockNumber = 0;
 	pub const BlockRewardBeforeInitialize: Balance = 0;
+	pub const InflationYearLength: u64 = 60_000;
 }
 
 impl inflation_manager::Config for TestRuntime {
@@ -172,12 +173,16 @@ impl inflation_manager::Config for TestRuntime {
 	type WeightInfo = inflation_manager::weights::WeightInfo<TestRuntime>;
 	type DoInitializeAt = InitializeInflationAt;
 	type BlockRewardBeforeInitialize = BlockRewardBeforeInitialize;
+	type YearLength = InflationYearLength;
+	type ConfigurationOrigin = frame_system::EnsureRoot<AccountId>;
+	type InflationAdjustment = ();
 }
 
 impl pallet_block_reward::Config for TestRuntime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
 	type BeneficiaryPayout = BeneficiaryPayout;
+	type MaxBeneficiaries = ConstU32<16>;
 	type WeightInfo = pallet_block_reward::weights::WeightInfo<TestRuntime>;
 }
 
@@ -199,6 +204,7 @@ impl ExternalityBuilder {
 			.ok();
 		pallet_block_reward::GenesisConfig::<TestRuntime> {
 			reward_config: pallet_block_reward::RewardDistributionConfig::default(),
+			fee_config: pallet_block_reward::RewardDistributionConfig::default(),
 			_phantom: Default::default(),
 		}
 		.assimilate_storage(&mut storage)
@@ -1,10 +1,13 @@
 use super::{pallet::Error, Event, *};
 use frame_support::{
 	assert_noop, assert_ok,
-	traits::{Currency, OnTimestampSet},
+	storage::unhashed,
+	traits::{Currency, Hooks, OnTimestampSet},
+	PalletId,
 };
 use mock::*;
 use pallet_balances::NegativeImbalance;
+use pallet_block_reward_runtime_api::{BeneficiaryReward, RewardBeneficiary};
 use sp_runtime::{
 	traits::{AccountIdConversion, BadOrigin, Zero},
 	Perbill,
@@ -26,6 +29,7 @@ fn reward_distribution_config_is_consistent() {
 		subsidization_pool_percent: Zero::zero(),
 		depin_staking_percent: Zero::zero(),
 		depin_incentivization_percent: Zero::zero(),
+		burn_percent: Zero::zero(),
 	};
 	assert!(reward_config.is_consistent());
 
@@ -37,6 +41,7 @@ fn reward_distribution_config_is_consistent() {
 		subsidization_pool_percent: Zero::zero(),
 		depin_staking_percent: Perbill::from_percent(50),
 		depin_incentivization_percent: Perbill::from_percent(50),
+		burn_percent: Zero::zero(),
 	};
 	assert!(reward_config.is_consistent());
 
@@ -48,6 +53,7 @@ fn reward_distribution_config_is_consistent() {
 		subsidization_pool_percent: Zero::zero(),
 		depin_staking_percent: Zero::zero(),
 		depin_incentivization_percent: Zero::zero(),
+		burn_percent: Zero::zero(),
 	};
 	assert!(!reward_config.is_consistent());
 
@@ -60,6 +66,7 @@ fn reward_distribution_config_is_consistent() {
 		subsidization_pool_percent: Perbill::from_percent(8),
 		depin_staking_percent: Perbill::from_percent(31),
 		depin_incentivization_percent: Perbill::from_percent(31),
+		burn_percent: Zero::zero(),
 	};
 	assert!(reward_config.is_consistent());
 }
@@ -82,6 +89,7 @@ fn reward_distribution_config_not_consistent() {
 		subsidization_pool_percent: Perbill::from_percent(14),
 		depin_staking_percent: Perbill::from_percent(20),
 		depin_incentivization_percent: Perbill::from_percent(20),
+		burn_percent: Zero::zero(),
 	};
 	assert!(!reward_config.is_consistent());
 
@@ -94,6 +102,7 @@ fn reward_distribution_config_not_consistent() {
 		subsidization_pool_percent: Perbill::from_percent(9),
 		depin_staking_percent: Perbill::from_percent(20),
 		depin_incentivization_percent: Perbill::from_percent(20),
+		burn_percent: Zero::zero(),
 	};
 	assert!(!reward_config.is_consistent());
 }
@@ -131,6 +140,7 @@ pub fn set_configuration_is_ok() {
 			subsidization_pool_percent: Perbill::from_percent(7),
 			depin_staking_percent: Perbill::from_percent(14),
 			depin_incentivization_percent: Perbill::from_percent(14),
+			burn_percent: Zero::zero(),
 		};
 		assert!(reward_config.is_consistent());
 
@@ -143,6 +153,391 @@ pub fn set_configuration_is_ok() {
 	})
 }
 
+#[test]
+pub fn set_beneficiaries_fails() {
+	ExternalityBuilder::build().execute_with(|| {
+		let beneficiaries: BeneficiariesOf<TestRuntime> =
+			vec![(BeneficiaryKind::Treasury, Perbill::from_percent(100))]
+				.try_into()
+				.unwrap();
+
+		// 1
+		assert_noop!(
+			BlockReward::set_beneficiaries(RuntimeOrigin::signed(1), beneficiaries),
+			BadOrigin
+		);
+
+		// 2
+		// 99%
+		let beneficiaries: BeneficiariesOf<TestRuntime> = vec![
+			(BeneficiaryKind::Treasury, Perbill::from_percent(49)),
+			(BeneficiaryKind::Account(4), Perbill::from_percent(50)),
+		]
+		.try_into()
+		.unwrap();
+		assert!(!is_consistent_distribution(&beneficiaries));
+		assert_noop!(
+			BlockReward::set_beneficiaries(RuntimeOrigin::root(), beneficiaries),
+			Error::<TestRuntime>::InvalidDistributionConfiguration,
+		);
+
+		// 3
+		assert_noop!(
+			BlockReward::set_beneficiaries(RuntimeOrigin::root(), Default::default()),
+			Error::<TestRuntime>::InvalidDistributionConfiguration,
+		);
+	})
+}
+
+#[test]
+pub fn set_beneficiaries_is_ok() {
+	ExternalityBuilder::build().execute_with(|| {
+		let beneficiaries: BeneficiariesOf<TestRuntime> = vec![
+			(BeneficiaryKind::CollatorsDelegators, Perbill::from_percent(60)),
+			(BeneficiaryKind::Pallet(PalletId(*b"custompt")), Perbill::from_percent(15)),
+			(BeneficiaryKind::Treasury, Perbill::from_percent(25)),
+		]
+		.try_into()
+		.unwrap();
+
+		assert_ok!(BlockReward::set_beneficiaries(RuntimeOrigin::root(), beneficiaries.clone()));
+		System::assert_last_event(mock::RuntimeEvent::BlockReward(Event::BeneficiariesChanged(
+			beneficiaries.clone(),
+		)));
+		assert_eq!(BeneficiaryDistribution::<TestRuntime>::get(), beneficiaries);
+
+		// setting the static configuration switches back to the static mode
+		assert_ok!(BlockReward::set_configuration(RuntimeOrigin::root(), Default::default()));
+		assert!(BeneficiaryDistribution::<TestRuntime>::get().is_empty());
+	})
+}
+
+#[test]
+pub fn dynamic_reward_distribution_as_expected() {
+	ExternalityBuilder::build().execute_with(|| {
+		let custom_pot = PalletId(*b"custompt");
+		let beneficiaries: BeneficiariesOf<TestRuntime> = vec![
+			(BeneficiaryKind::Account(4), Perbill::from_percent(50)),
+			(BeneficiaryKind::Pallet(custom_pot), Perbill::from_percent(30)),
+			(BeneficiaryKind::Treasury, Perbill::from_percent(20)),
+		]
+		.try_into()
+		.unwrap();
+		assert_ok!(BlockReward::set_beneficiaries(RuntimeOrigin::root(), beneficiaries));
+
+		let block_reward: Balance = InflationManager::block_rewards();
+		let account_reward = Perbill::from_percent(50) * block_reward;
+		let pallet_reward = Perbill::from_percent(30) * block_reward;
+		// the last beneficiary receives the remainder
+		let treasury_reward = block_reward - account_reward - pallet_reward;
+
+		BlockReward::on_timestamp_set(0);
+
+		assert_eq!(<TestRuntime as Config>::Currency::free_balance(&4), account_reward);
+		assert_eq!(
+			<TestRuntime as Config>::Currency::free_balance(&custom_pot.into_account_truncating()),
+			pallet_reward
+		);
+		assert_eq!(
+			<TestRuntime as Config>::Currency::free_balance(
+				&TREASURY_POT.into_account_truncating()
+			),
+			treasury_reward
+		);
+		assert!(FreeBalanceSnapshot::new().collators_delegators.is_zero());
+
+		System::assert_has_event(mock::RuntimeEvent::BlockReward(Event::BeneficiaryRewarded(
+			BeneficiaryKind::Account(4),
+			account_reward,
+		)));
+		System::assert_has_event(mock::RuntimeEvent::BlockReward(Event::BeneficiaryRewarded(
+			BeneficiaryKind::Pallet(custom_pot),
+			pallet_reward,
+		)));
+		System::assert_has_event(mock::RuntimeEvent::BlockReward(Event::BeneficiaryRewarded(
+			BeneficiaryKind::Treasury,
+			treasury_reward,
+		)));
+		System::assert_last_event(mock::RuntimeEvent::BlockReward(Event::BlockRewardsDistributed(
+			block_reward,
+		)));
+	})
+}
+
+#[test]
+pub fn dust_reward_to_new_account_is_burned() {
+	ExternalityBuilder::build().execute_with(|| {
+		let dust = EXISTENTIAL_DEPOSIT - 1;
+		let total_issuance = <TestRuntime as Config>::Currency::total_issuance();
+		System::reset_events();
+
+		// A reward below the existential deposit cannot create the account
+		BlockReward::payout(
+			&BeneficiaryKind::Account(5),
+			<TestRuntime as Config>::Currency::issue(dust),
+		);
+		assert!(<TestRuntime as Config>::Currency::free_balance(&5).is_zero());
+		assert_eq!(BlockReward::total_burned(), dust);
+		assert_eq!(<TestRuntime as Config>::Currency::total_issuance(), total_issuance);
+		assert!(!System::events().iter().any(|record| matches!(
+			record.event,
+			mock::RuntimeEvent::BlockReward(Event::BeneficiaryRewarded(..))
+		)));
+
+		// An existing account receives any reward
+		BlockReward::payout(
+			&BeneficiaryKind::Account(2),
+			<TestRuntime as Config>::Currency::issue(dust),
+		);
+		assert_eq!(<TestRuntime as Config>::Currency::free_balance(&2), 800 + dust);
+		assert_eq!(BlockReward::total_burned(), dust);
+		System::assert_last_event(mock::RuntimeEvent::BlockReward(Event::BeneficiaryRewarded(
+			BeneficiaryKind::Account(2),
+			dust,
+		)));
+	})
+}
+
+#[test]
+pub fn migration_converts_reward_config_into_beneficiaries() {
+	ExternalityBuilder::build().execute_with(|| {
+		let reward_config = RewardDistributionConfigV1 {
+			treasury_percent: Perbill::from_percent(10),
+			collators_delegators_percent: Perbill::from_percent(40),
+			coretime_percent: Perbill::from_percent(2),
+			subsidization_pool_percent: Perbill::from_percent(8),
+			depin_staking_percent: Perbill::from_percent(20),
+			depin_incentivization_percent: Perbill::from_percent(20),
+		};
+		unhashed::put(
+			&RewardDistributionConfigStorage::<TestRuntime>::hashed_key(),
+			&reward_config,
+		);
+		StorageVersion::new(4).put::<BlockReward>();
+
+		migrations::on_runtime_upgrade::<TestRuntime>();
+
+		let reward_config = RewardDistributionConfig::from(reward_config);
+		assert_eq!(BlockReward::on_chain_storage_version(), StorageVersion::new(7));
+		assert_eq!(BlockReward::reward_config(), reward_config);
+		assert_eq!(BlockReward::beneficiaries().into_inner(), reward_config.to_beneficiaries());
+
+		// The migrated beneficiaries are paid out exactly like in the static mode
+		let init_balance_state = FreeBalanceSnapshot::new();
+		let rewards = Rewards::calculate(&reward_config);
+
+		BlockReward::on_timestamp_set(0);
+
+		let final_balance_state = FreeBalanceSnapshot::new();
+		init_balance_state.assert_distribution(&final_balance_state, &rewards);
+	})
+}
+
+#[test]
+pub fn set_fee_configuration_fails() {
+	ExternalityBuilder::build().execute_with(|| {
+		// 1
+		assert_noop!(
+			BlockReward::set_fee_configuration(RuntimeOrigin::signed(1), Default::default()),
+			BadOrigin
+		);
+
+		// 2
+		let fee_config = RewardDistributionConfig {
+			collators_delegators_percent: Perbill::from_percent(100),
+			..Default::default()
+		};
+		assert!(!fee_config.is_consistent());
+		assert_noop!(
+			BlockReward::set_fee_configuration(RuntimeOrigin::root(), fee_config),
+			Error::<TestRuntime>::InvalidDistributionConfiguration,
+		);
+	})
+}
+
+#[test]
+pub fn set_fee_configuration_is_ok() {
+	ExternalityBuilder::build().execute_with(|| {
+		let reward_config = RewardDistributionConfigStorage::<TestRuntime>::get();
+		let fee_config = RewardDistributionConfig {
+			treasury_percent: Perbill::from_percent(50),
+			collators_delegators_percent: Perbill::from_percent(50),
+			coretime_percent: Zero::zero(),
+			subsidization_pool_percent: Zero::zero(),
+			depin_staking_percent: Zero::zero(),
+			depin_incentivization_percent: Zero::zero(),
+			burn_percent: Zero::zero(),
+		};
+		assert!(fee_config.is_consistent());
+
+		assert_ok!(BlockReward::set_fee_configuration(RuntimeOrigin::root(), fee_config.clone()));
+		System::assert_last_event(mock::RuntimeEvent::BlockReward(
+			Event::FeeDistributionConfigurationChanged(fee_config.clone()),
+		));
+
+		assert_eq!(FeeDistributionConfigStorage::<TestRuntime>::get(), fee_config);
+		// the block reward configuration is untouched
+		assert_eq!(RewardDistributionConfigStorage::<TestRuntime>::get(), reward_config);
+	})
+}
+
+#[test]
+pub fn fee_distribution_as_expected() {
+	ExternalityBuilder::build().execute_with(|| {
+		let fee_config = RewardDistributionConfig {
+			treasury_percent: Perbill::from_percent(30),
+			collators_delegators_percent: Perbill::from_percent(70),
+			coretime_percent: Zero::zero(),
+			subsidization_pool_percent: Zero::zero(),
+			depin_staking_percent: Zero::zero(),
+			depin_incentivization_percent: Zero::zero(),
+			burn_percent: Zero::zero(),
+		};
+		assert_ok!(BlockReward::set_fee_configuration(RuntimeOrigin::root(), fee_config));
+
+		let amount = 1_000_000_000_000 as Balance;
+		let init_balance_state = FreeBalanceSnapshot::new();
+
+		BlockReward::on_unbalanced(<TestRuntime as Config>::Currency::issue(amount));
+
+		let final_balance_state = FreeBalanceSnapshot::new();
+		let expected = Rewards {
+			treasury_reward: Perbill::from_percent(30) * amount,
+			collators_delegators_reward: Perbill::from_percent(70) * amount,
+			coretime_reward: Zero::zero(),
+			subsidization_pool_reward: Zero::zero(),
+			depin_staking_reward: Zero::zero(),
+			depin_incentivization_reward: Zero::zero(),
+			burned: Zero::zero(),
+		};
+		init_balance_state.assert_distribution(&final_balance_state, &expected);
+		System::assert_last_event(mock::RuntimeEvent::BlockReward(
+			Event::TransactionFeesDistributed(amount),
+		));
+
+		// block rewards keep following the reward distribution configuration
+		let init_balance_state = FreeBalanceSnapshot::new();
+		let rewards = Rewards::calculate(&BlockReward::reward_config());
+
+		BlockReward::on_timestamp_set(0);
+
+		let final_balance_state = FreeBalanceSnapshot::new();
+		init_balance_state.assert_distribution(&final_balance_state, &rewards);
+	})
+}
+
+#[test]
+pub fn migration_initializes_fee_config() {
+	ExternalityBuilder::build().execute_with(|| {
+		let reward_config = RewardDistributionConfigV1 {
+			treasury_percent: Perbill::from_percent(10),
+			collators_delegators_percent: Perbill::from_percent(90),
+			coretime_percent: Zero::zero(),
+			subsidization_pool_percent: Zero::zero(),
+			depin_staking_percent: Zero::zero(),
+			depin_incentivization_percent: Zero::zero(),
+		};
+		unhashed::put(
+			&RewardDistributionConfigStorage::<TestRuntime>::hashed_key(),
+			&reward_config,
+		);
+		FeeDistributionConfigStorage::<TestRuntime>::kill();
+		StorageVersion::new(5).put::<BlockReward>();
+
+		migrations::on_runtime_upgrade::<TestRuntime>();
+
+		assert_eq!(BlockReward::on_chain_storage_version(), StorageVersion::new(7));
+		assert_eq!(BlockReward::fee_config(), RewardDistributionConfig::from(reward_config));
+	})
+}
+
+#[test]
+pub fn migration_adds_burn_portion() {
+	ExternalityBuilder::build().execute_with(|| {
+		let reward_config = RewardDistributionConfigV1 {
+			treasury_percent: Perbill::from_percent(10),
+			collators_delegators_percent: Perbill::from_percent(90),
+			coretime_percent: Zero::zero(),
+			subsidization_pool_percent: Zero::zero(),
+			depin_staking_percent: Zero::zero(),
+			depin_incentivization_percent: Zero::zero(),
+		};
+		let fee_config = RewardDistributionConfigV1 {
+			treasury_percent: Perbill::from_percent(50),
+			collators_delegators_percent: Perbill::from_percent(50),
+			..reward_config.clone()
+		};
+		unhashed::put(
+			&RewardDistributionConfigStorage::<TestRuntime>::hashed_key(),
+			&reward_config,
+		);
+		unhashed::put(&FeeDistributionConfigStorage::<TestRuntime>::hashed_key(), &fee_config);
+		StorageVersion::new(6).put::<BlockReward>();
+
+		migrations::on_runtime_upgrade::<TestRuntime>();
+
+		assert_eq!(BlockReward::on_chain_storage_version(), StorageVersion::new(7));
+		let reward_config = RewardDistributionConfig::from(reward_config);
+		assert!(reward_config.burn_percent.is_zero());
+		assert_eq!(BlockReward::reward_config(), reward_config);
+		assert_eq!(BlockReward::fee_config(), RewardDistributionConfig::from(fee_config));
+	})
+}
+
+#[test]
+pub fn burn_as_expected() {
+	ExternalityBuilder::build().execute_with(|| {
+		let reward_config = RewardDistributionConfig {
+			burn_percent: Perbill::from_percent(10),
+			treasury_percent: Perbill::from_percent(15),
+			..Default::default()
+		};
+		assert!(reward_config.is_consistent());
+		assert_ok!(BlockReward::set_configuration(RuntimeOrigin::root(), reward_config.clone()));
+		let fee_config = RewardDistributionConfig {
+			treasury_percent: Perbill::from_percent(50),
+			collators_delegators_percent: Perbill::from_percent(30),
+			coretime_percent: Zero::zero(),
+			subsidization_pool_percent: Zero::zero(),
+			depin_staking_percent: Zero::zero(),
+			depin_incentivization_percent: Zero::zero(),
+			burn_percent: Perbill::from_percent(20),
+		};
+		assert_ok!(BlockReward::set_fee_configuration(RuntimeOrigin::root(), fee_config));
+
+		let block_reward: Balance = InflationManager::block_rewards();
+		let fee = 1_000_000_000_000 as Balance;
+		let burned = Perbill::from_percent(10) * block_reward + Perbill::from_percent(20) * fee;
+
+		let rewards = Rewards::calculate(&reward_config);
+		let init_issuance = <TestRuntime as Config>::Currency::total_issuance();
+		let init_balance_state = FreeBalanceSnapshot::new();
+		BlockReward::on_timestamp_set(0);
+		init_balance_state.assert_distribution(&FreeBalanceSnapshot::new(), &rewards);
+
+		BlockReward::on_unbalanced(<TestRuntime as Config>::Currency::issue(fee));
+
+		assert_eq!(BlockReward::total_burned(), burned);
+		assert_eq!(
+			<TestRuntime as Config>::Currency::total_issuance(),
+			init_issuance + block_reward + fee - burned
+		);
+
+		// Burned tokens are reported once per block
+		BlockReward::on_finalize(1);
+		System::assert_last_event(mock::RuntimeEvent::BlockReward(Event::TokensBurned(
+			burned, burned,
+		)));
+		assert!(BurnedInBlock::<TestRuntime>::get().is_zero());
+
+		// Nothing has been burned in the next block
+		System::reset_events();
+		BlockReward::on_finalize(2);
+		assert!(System::events().is_empty());
+		assert_eq!(BlockReward::total_burned(), burned);
+	})
+}
+
 #[test]
 pub fn inflation_and_total_issuance_as_expected() {
 	ExternalityBuilder::build().execute_with(|| {
@@ -178,6 +573,7 @@ pub fn reward_distribution_as_expected() {
 			subsidization_pool_percent: Perbill::from_percent(8),
 			depin_staking_percent: Perbill::from_percent(20),
 			depin_incentivization_percent: Perbill::from_percent(20),
+			burn_percent: Zero::zero(),
 		};
 		assert!(reward_config.is_consistent());
 		assert_ok!(BlockReward::set_configuration(RuntimeOrigin::root(), reward_config.clone()));
@@ -205,6 +601,7 @@ pub fn reward_distribution_no_adjustable_part() {
 			subsidization_pool_percent: Perbill::from_percent(10),
 			depin_staking_percent: Perbill::from_percent(50),
 			depin_incentivization_percent: Perbill::from_percent(25),
+			burn_percent: Zero::zero(),
 		};
 		assert!(reward_config.is_consistent());
 		assert_ok!(BlockReward::set_configuration(RuntimeOrigin::root(), reward_config.clone()));
@@ -226,6 +623,167 @@ pub fn reward_distribution_no_adjustable_part() {
 	})
 }
 
+#[test]
+pub fn runtime_api_queries() {
+	ExternalityBuilder::build().execute_with(|| {
+		// The first year starts with the first timestamp
+		assert_eq!(BlockReward::block_reward_info().next_recalculation_at, None);
+		Timestamp::set_timestamp(1_000);
+		InflationManager::on_finalize(System::block_number());
+
+		let block_reward: Balance = InflationManager::block_rewards();
+		let rewards = Rewards::calculate(&BlockReward::reward_config());
+
+		let info = BlockReward::block_reward_info();
+		assert_eq!(info.block_reward, block_reward);
+		assert_eq!(info.current_year, 1);
+		assert_eq!(info.next_recalculation_at, Some(1_000 + InflationYearLength::get()));
+		let amount_of = |beneficiary: RewardBeneficiary<AccountId>| {
+			info.distribution
+				.iter()
+				.find(|reward| reward.beneficiary == beneficiary)
+				.map(|reward| reward.amount)
+				.unwrap()
+		};
+		assert_eq!(amount_of(RewardBeneficiary::Treasury), rewards.treasury_reward);
+		assert_eq!(
+			amount_of(RewardBeneficiary::CollatorsDelegators),
+			rewards.collators_delegators_reward
+		);
+		assert_eq!(amount_of(RewardBeneficiary::Coretime), rewards.coretime_reward);
+		assert_eq!(
+			info.distribution.iter().map(|reward| reward.amount).sum::<Balance>(),
+			block_reward
+		);
+
+		// Pallet ids are resolved into their accounts
+		let custom_pot = PalletId(*b"custompt");
+		let beneficiaries: BeneficiariesOf<TestRuntime> = vec![
+			(BeneficiaryKind::Pallet(custom_pot), Perbill::from_percent(40)),
+			(BeneficiaryKind::Account(4), Perbill::from_percent(60)),
+		]
+		.try_into()
+		.unwrap();
+		assert_ok!(BlockReward::set_beneficiaries(RuntimeOrigin::root(), beneficiaries));
+		assert_eq!(
+			BlockReward::block_reward_info().distribution,
+			vec![
+				BeneficiaryReward {
+					beneficiary: RewardBeneficiary::Account(custom_pot.into_account_truncating()),
+					share: Perbill::from_percent(40),
+					amount: Perbill::from_percent(40) * block_reward,
+				},
+				BeneficiaryReward {
+					beneficiary: RewardBeneficiary::Account(4),
+					share: Perbill::from_percent(60),
+					amount: block_reward - Perbill::from_percent(40) * block_reward,
+				},
+			]
+		);
+
+		let total_issuance = <TestRuntime as Config>::Currency::total_issuance();
+		let fiscal_year = InflationManager::fiscal_year().unwrap();
+		let inflation_config = InflationManager::inflation_configuration();
+		let schedule = BlockReward::issuance_schedule(20);
+		assert_eq!(schedule.len(), 20);
+
+		let current = &schedule[0];
+		assert_eq!(current.year, 1);
+		assert_eq!(current.block_reward, block_reward);
+		assert_eq!(current.issuance, fiscal_year.budget - fiscal_year.issued);
+		assert_eq!(current.total_issuance, total_issuance + current.issuance);
+
+		for years in schedule.windows(2) {
+			let (previous, next) = (&years[0], &years[1]);
+			let inflation_parameters =
+				InflationManager::inflation_parameters_for_year(&inflation_config, next.year);
+			assert_eq!(next.year, previous.year + 1);
+			assert_eq!(next.inflation_rate, inflation_parameters.inflation_rate);
+			assert_eq!(
+				next.block_reward,
+				InflationManager::rewards_per_block_for(
+					&inflation_parameters,
+					previous.total_issuance
+				)
+			);
+			assert_eq!(
+				next.issuance,
+				InflationManager::yearly_issuance_for(
+					&inflation_parameters,
+					previous.total_issuance
+				)
+			);
+			assert_eq!(next.total_issuance, previous.total_issuance + next.issuance);
+		}
+
+		// Inflation stagnates from year 13 on
+		assert_eq!(schedule[12].year, 13);
+		assert_eq!(schedule[12].inflation_rate, Perbill::from_percent(1));
+		assert_eq!(schedule[19].inflation_rate, Perbill::from_percent(1));
+
+		assert_eq!(BlockReward::issuance_schedule(1_000).len(), 100);
+	})
+}
+
+#[test]
+pub fn block_rewards_clipped_at_max_currency_supply() {
+	ExternalityBuilder::build().execute_with(|| {
+		let block_reward: Balance = InflationManager::block_rewards();
+		let total_issuance = <TestRuntime as Config>::Currency::total_issuance();
+		let max_currency_supply = total_issuance + block_reward + block_reward / 2;
+		assert_ok!(InflationManager::set_max_currency_supply(
+			RuntimeOrigin::root(),
+			Some(max_currency_supply)
+		));
+		let issued = InflationManager::fiscal_year().unwrap().issued;
+
+		BlockReward::on_timestamp_set(0);
+		assert_eq!(
+			<TestRuntime as Config>::Currency::total_issuance(),
+			total_issuance + block_reward
+		);
+		System::assert_last_event(mock::RuntimeEvent::BlockReward(Event::BlockRewardsDistributed(
+			block_reward,
+		)));
+
+		// Minting stops in the next block
+		BlockReward::on_timestamp_set(0);
+		assert_eq!(<TestRuntime as Config>::Currency::total_issuance(), max_currency_supply);
+		System::assert_has_event(mock::RuntimeEvent::BlockReward(Event::BlockRewardsDistributed(
+			block_reward / 2,
+		)));
+		System::assert_last_event(mock::RuntimeEvent::BlockReward(
+			Event::MaxCurrencySupplyReached(max_currency_supply),
+		));
+
+		BlockReward::on_timestamp_set(0);
+		assert_eq!(<TestRuntime as Config>::Currency::total_issuance(), max_currency_supply);
+		System::assert_last_event(mock::RuntimeEvent::BlockReward(Event::BlockRewardsDistributed(
+			0,
+		)));
+
+		// Burns lower the total issuance again, but the cap is reported only once
+		drop(<TestRuntime as Config>::Currency::slash(&3, 1_000));
+		System::reset_events();
+		BlockReward::on_timestamp_set(0);
+		assert_eq!(<TestRuntime as Config>::Currency::total_issuance(), max_currency_supply);
+		System::assert_last_event(mock::RuntimeEvent::BlockReward(Event::BlockRewardsDistributed(
+			1_000,
+		)));
+		assert!(!System::events().iter().any(|record| matches!(
+			record.event,
+			mock::RuntimeEvent::BlockReward(Event::MaxCurrencySupplyReached(..))
+		)));
+
+		// Only the tokens actually issued count towards the fiscal year
+		InflationManager::on_finalize(System::block_number());
+		assert_eq!(
+			InflationManager::fiscal_year().unwrap().issued,
+			issued + block_reward + block_reward / 2 + 1_000
+		);
+	})
+}
+
 #[test]
 pub fn on_unbalanced() {
 	ExternalityBuilder::build().execute_with(|| {
@@ -257,6 +815,7 @@ struct FreeBalanceSnapshot {
 	subsidization_pool: Balance,
 	depin_staking: Balance,
 	depin_incentivization: Balance,
+	burned: Balance,
 }
 
 impl FreeBalanceSnapshot {
@@ -283,6 +842,7 @@ impl FreeBalanceSnapshot {
 			depin_incentivization: <TestRuntime as Config>::Currency::free_balance(
 				&DE_PININCENTIVIZATION_ACCOUNT.into_account_truncating(),
 			),
+			burned: BlockReward::total_burned(),
 		}
 	}
 
@@ -293,7 +853,8 @@ impl FreeBalanceSnapshot {
 			self.coretime.is_zero() &&
 			self.subsidization_pool.is_zero() &&
 			self.depin_staking.is_zero() &&
-			self.depin_incentivization.is_zero()
+			self.depin_incentivization.is_zero() &&
+			self.burned.is_zero()
 	}
 
 	/// Asserts that `post_reward_state` is as expected.
@@ -322,6 +883,7 @@ impl FreeBalanceSnapshot {
 			self.depin_incentivization + rewards.depin_incentivization_reward,
 			post_reward_state.depin_incentivization
 		);
+		assert_eq!(self.burned + rewards.burned, post_reward_state.burned);
 	}
 }
 
@@ -334,6 +896,7 @@ struct Rewards {
 	subsidization_pool_reward: Balance,
 	depin_staking_reward: Balance,
 	depin_incentivization_reward: Balance,
+	burned: Balance,
 }
 
 impl Rewards {
@@ -352,6 +915,7 @@ impl Rewards {
 		let depin_staking_reward_imbalance = reward_config.depin_staking_percent * imbalance.peek();
 		let depin_incentivization_reward_imbalance =
 			reward_config.depin_incentivization_percent * imbalance.peek();
+		let burn_imbalance = reward_config.burn_percent * imbalance.peek();
 
 		// Prepare imbalances
 		let (collator_delegator_reward, remainder) =
@@ -360,8 +924,9 @@ impl Rewards {
 		let (subsidization_pool_reward, remainder) =
 			remainder.split(subsidization_pool_reward_imbalance);
 		let (depin_staking_reward, remainder) = remainder.split(depin_staking_reward_imbalance);
-		let (depin_incentivization_reward, treasury_reward) =
+		let (depin_incentivization_reward, remainder) =
 			remainder.split(depin_incentivization_reward_imbalance);
+		let (burned, treasury_reward) = remainder.split(burn_imbalance);
 
 		Self {
 			treasury_reward: treasury_reward.peek(),
@@ -370,6 +935,7 @@ impl Rewards {
 			subsidization_pool_reward: subsidization_pool_reward.peek(),
 			depin_staking_reward: depin_staking_reward.peek(),
 			depin_incentivization_reward: depin_incentivization_reward.peek(),
+			burned: burned.peek(),
 		}
 	}
 }
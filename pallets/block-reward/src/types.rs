@@ -1,8 +1,8 @@
 //! Type and trait definitions of the crate
 
-use frame_support::{pallet_prelude::*, traits::Currency};
+use frame_support::{pallet_prelude::*, traits::Currency, PalletId};
 use sp_runtime::{traits::CheckedAdd, Perbill};
-use sp_std::vec;
+use sp_std::{vec, vec::Vec};
 
 use crate::pallet::Config as PalletConfig;
 
@@ -15,6 +15,12 @@ pub(crate) type NegativeImbalanceOf<T> = <<T as PalletConfig>::Currency as Curre
 	<T as frame_system::Config>::AccountId,
 >>::NegativeImbalance;
 
+/// List of beneficiaries used by the dynamic distribution mode.
+pub type BeneficiariesOf<T> = BoundedVec<
+	(BeneficiaryKind<<T as frame_system::Config>::AccountId>, Perbill),
+	<T as PalletConfig>::MaxBeneficiaries,
+>;
+
 /// Defines functions used to payout the beneficiaries of block rewards
 pub trait BeneficiaryPayout<Imbalance> {
 	/// Payout reward to the treasury
@@ -36,6 +42,45 @@ pub trait BeneficiaryPayout<Imbalance> {
 	fn depin_incentivization(reward: Imbalance);
 }
 
+/// After next next version, we can remove this RewardDistributionConfigV1
+/// List of configuration parameters used to calculate reward distribution portions for all the
+/// beneficiaries, before the burn portion was introduced.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct RewardDistributionConfigV1 {
+	/// Base percentage of reward that goes to treasury
+	#[codec(compact)]
+	pub treasury_percent: Perbill,
+	/// Percentage of reward that goes to collators and delegators
+	#[codec(compact)]
+	pub collators_delegators_percent: Perbill,
+	/// Percentage of reward that goes to coretime
+	#[codec(compact)]
+	pub coretime_percent: Perbill,
+	/// Percentage of reward that goes to subsidization pool
+	#[codec(compact)]
+	pub subsidization_pool_percent: Perbill,
+	/// Percentage of rewards that goes to DePIN staking
+	#[codec(compact)]
+	pub depin_staking_percent: Perbill,
+	/// Percentage of rewards that goes to DePIN incentivization
+	#[codec(compact)]
+	pub depin_incentivization_percent: Perbill,
+}
+
+impl From<RewardDistributionConfigV1> for RewardDistributionConfig {
+	fn from(config: RewardDistributionConfigV1) -> Self {
+		RewardDistributionConfig {
+			treasury_percent: config.treasury_percent,
+			collators_delegators_percent: config.collators_delegators_percent,
+			coretime_percent: config.coretime_percent,
+			subsidization_pool_percent: config.subsidization_pool_percent,
+			depin_staking_percent: config.depin_staking_percent,
+			depin_incentivization_percent: config.depin_incentivization_percent,
+			burn_percent: Perbill::zero(),
+		}
+	}
+}
+
 /// After next next version, we can remove this RewardDistributionConfigV0
 /// List of configuration parameters used to calculate reward distribution portions for all the
 /// beneficiaries.
@@ -100,6 +145,9 @@ pub struct RewardDistributionConfig {
 	/// Percentage of rewards that goes to DePIN incentivization
 	#[codec(compact)]
 	pub depin_incentivization_percent: Perbill,
+	/// Percentage of rewards that gets burned
+	#[codec(compact)]
+	pub burn_percent: Perbill,
 }
 
 impl Default for RewardDistributionConfig {
@@ -113,6 +161,7 @@ impl Default for RewardDistributionConfig {
 			subsidization_pool_percent: Perbill::from_percent(5),
 			depin_staking_percent: Perbill::from_percent(5),
 			depin_incentivization_percent: Perbill::from_percent(15),
+			burn_percent: Perbill::zero(),
 		}
 	}
 }
@@ -131,6 +180,7 @@ impl RewardDistributionConfig {
 			&self.subsidization_pool_percent,
 			&self.depin_staking_percent,
 			&self.depin_incentivization_percent,
+			&self.burn_percent,
 		];
 
 		let mut accumulator = Perbill::zero();
@@ -146,3 +196,59 @@ impl RewardDistributionConfig {
 		Perbill::one() == accumulator
 	}
 }
+
+impl RewardDistributionConfig {
+	/// Converts the configuration into a list of beneficiaries for the dynamic distribution mode.
+	///
+	/// The treasury is placed last, so it keeps receiving the rounding remainder.
+	pub fn to_beneficiaries<AccountId>(&self) -> Vec<(BeneficiaryKind<AccountId>, Perbill)> {
+		vec![
+			(BeneficiaryKind::CollatorsDelegators, self.collators_delegators_percent),
+			(BeneficiaryKind::Coretime, self.coretime_percent),
+			(BeneficiaryKind::SubsidizationPool, self.subsidization_pool_percent),
+			(BeneficiaryKind::DepinStaking, self.depin_staking_percent),
+			(BeneficiaryKind::DepinIncentivization, self.depin_incentivization_percent),
+			(BeneficiaryKind::Burn, self.burn_percent),
+			(BeneficiaryKind::Treasury, self.treasury_percent),
+		]
+	}
+}
+
+/// Recipient of a share of the distributed imbalances in the dynamic distribution mode.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum BeneficiaryKind<AccountId> {
+	/// Paid out via `BeneficiaryPayout::treasury`
+	Treasury,
+	/// Paid out via `BeneficiaryPayout::collators_delegators`
+	CollatorsDelegators,
+	/// Paid out via `BeneficiaryPayout::coretime`
+	Coretime,
+	/// Paid out via `BeneficiaryPayout::subsidization_pool`
+	SubsidizationPool,
+	/// Paid out via `BeneficiaryPayout::depin_staking`
+	DepinStaking,
+	/// Paid out via `BeneficiaryPayout::depin_incentivization`
+	DepinIncentivization,
+	/// Deposited into the account derived from the pallet id
+	Pallet(PalletId),
+	/// Deposited into the given account
+	Account(AccountId),
+	/// Burned, which reduces the total issuance
+	Burn,
+}
+
+/// `true` if the shares of all beneficiaries sum up to `one whole`, `false` otherwise.
+pub fn is_consistent_distribution<AccountId>(
+	beneficiaries: &[(BeneficiaryKind<AccountId>, Perbill)],
+) -> bool {
+	let mut accumulator = Perbill::zero();
+	for (_, share) in beneficiaries {
+		if let Some(mid_result) = accumulator.checked_add(share) {
+			accumulator = mid_result;
+		} else {
+			return false
+		}
+	}
+
+	Perbill::one() == accumulator
+}
@@ -4,4 +4,6 @@ use frame_support::weights::Weight;
 
 pub trait WeightInfo {
 	fn set_configuration() -> Weight;
+	fn set_beneficiaries(n: u32) -> Weight;
+	fn set_fee_configuration() -> Weight;
 }
@@ -33,12 +33,39 @@ pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
 	/// Storage: BlockReward RewardDistributionConfigStorage (r:0 w:1)
 	/// Proof: BlockReward RewardDistributionConfigStorage (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	/// Storage: BlockReward BeneficiaryDistribution (r:0 w:1)
+	/// Proof: BlockReward BeneficiaryDistribution (max_values: Some(1), max_size: Some(594), added: 1089, mode: MaxEncodedLen)
 	fn set_configuration() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
 		//  Estimated: `0`
-		// Minimum execution time: 14_455_000 picoseconds.
-		Weight::from_parts(14_680_000, 0)
+		// Minimum execution time: 15_312_000 picoseconds.
+		Weight::from_parts(15_604_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: BlockReward BeneficiaryDistribution (r:0 w:1)
+	/// Proof: BlockReward BeneficiaryDistribution (max_values: Some(1), max_size: Some(594), added: 1089, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 16]`.
+	fn set_beneficiaries(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 15_871_000 picoseconds.
+		Weight::from_parts(16_207_912, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			// Standard Error: 3_214
+			.saturating_add(Weight::from_parts(262_731, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: BlockReward FeeDistributionConfigStorage (r:0 w:1)
+	/// Proof: BlockReward FeeDistributionConfigStorage (max_values: Some(1), max_size: Some(24), added: 519, mode: MaxEncodedLen)
+	fn set_fee_configuration() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 14_512_000 picoseconds.
+		Weight::from_parts(14_741_000, 0)
 			.saturating_add(Weight::from_parts(0, 0))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
@@ -0,0 +1,78 @@
+//! Runtime API definition of the inflation-manager pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+// These clippy lints are disabled because the macro-generated code triggers them.
+#![allow(clippy::too_many_arguments)]
+
+use parity_scale_codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+/// Why tokens were moved into or out of the inflation pot.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub enum PotMovementReason {
+	/// Minted into the pot to top the total issuance up to the configured
+	/// total issuance.
+	TotalIssuanceTopUp,
+	/// Transferred out of the pot by governance.
+	TransferAll,
+}
+
+/// A mint into or a transfer out of the inflation pot.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct PotLedgerEntry<AccountId, Balance, BlockNumber> {
+	/// The index of the entry in the ledger.
+	pub index: u32,
+	/// The block in which the tokens were moved.
+	pub block: BlockNumber,
+	/// The amount of tokens which were moved.
+	pub amount: Balance,
+	/// The receiver of the tokens, the pot itself for mints.
+	pub destination: AccountId,
+	/// The reason of the movement.
+	pub reason: PotMovementReason,
+}
+
+/// Figures to reconcile the configured total issuance with the actual one.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct PotReconciliation<Balance> {
+	/// The total issuance configured for the TGE.
+	pub expected_total_issuance: Balance,
+	/// The actual total issuance.
+	pub total_issuance: Balance,
+	/// The free balance of the inflation pot.
+	pub pot_balance: Balance,
+	/// The sum of all tokens minted into the pot.
+	pub minted: Balance,
+	/// The sum of all tokens transferred out of the pot.
+	pub transferred: Balance,
+	/// The number of entries in the ledger.
+	pub entries: u32,
+}
+
+sp_api::decl_runtime_apis! {
+	/// The API to audit the inflation pot.
+	pub trait InflationManagerApi<AccountId, Balance, BlockNumber>
+	where
+		AccountId: Codec,
+		Balance: Codec,
+		BlockNumber: Codec,
+	{
+		/// Return up to `limit` ledger entries of the inflation pot,
+		/// starting with the entry at index `start`.
+		fn pot_ledger(start: u32, limit: u32) -> Vec<PotLedgerEntry<AccountId, Balance, BlockNumber>>;
+		/// Return the figures to reconcile the configured total issuance
+		/// with the actual total issuance.
+		fn pot_reconciliation() -> PotReconciliation<Balance>;
+	}
+}
@@ -0,0 +1,105 @@
+//! Hooks which adjust the yearly inflation parameters derived from the inflation configuration.
+
+use crate::{Config, InflationParametersT};
+use frame_support::{
+	traits::{Currency, Get},
+	weights::Weight,
+};
+use peaq_primitives_xcm::Balance;
+use sp_runtime::{traits::Saturating, Perbill};
+use sp_std::marker::PhantomData;
+
+/// Adjusts the inflation parameters of a fiscal year, after they have been derived from the
+/// disinflation curve of the inflation configuration.
+pub trait InflationAdjustment {
+	fn adjust(inflation_parameters: InflationParametersT) -> InflationParametersT;
+
+	/// Weight of a single call of `adjust`.
+	fn weight() -> Weight;
+}
+
+/// Keeps the fixed disinflation curve.
+impl InflationAdjustment for () {
+	fn adjust(inflation_parameters: InflationParametersT) -> InflationParametersT {
+		inflation_parameters
+	}
+
+	fn weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+/// Adjusts the inflation rate to the ratio of the total stake to the total issuance.
+///
+/// At the target staking ratio the rate of the disinflation curve applies. Below the target the
+/// rate rises linearly up to `MaxInflationRate` at a ratio of zero, above the target it falls
+/// linearly down to `MinInflationRate` at a ratio of 100%.
+pub struct StakingRatioAdjustment<
+	T,
+	TotalStake,
+	MinInflationRate,
+	MaxInflationRate,
+	TargetStakingRatio,
+>(PhantomData<(T, TotalStake, MinInflationRate, MaxInflationRate, TargetStakingRatio)>);
+
+impl<T, TotalStake, MinInflationRate, MaxInflationRate, TargetStakingRatio> InflationAdjustment
+	for StakingRatioAdjustment<T, TotalStake, MinInflationRate, MaxInflationRate, TargetStakingRatio>
+where
+	T: Config,
+	TotalStake: Get<Balance>,
+	MinInflationRate: Get<Perbill>,
+	MaxInflationRate: Get<Perbill>,
+	TargetStakingRatio: Get<Perbill>,
+{
+	fn adjust(inflation_parameters: InflationParametersT) -> InflationParametersT {
+		let total_issuance = T::Currency::total_issuance();
+		if total_issuance == 0 {
+			return inflation_parameters
+		}
+
+		let staking_ratio = Perbill::from_rational(TotalStake::get(), total_issuance);
+		InflationParametersT {
+			inflation_rate: staking_ratio_inflation_rate(
+				inflation_parameters.inflation_rate,
+				staking_ratio,
+				TargetStakingRatio::get(),
+				MinInflationRate::get(),
+				MaxInflationRate::get(),
+			),
+			..inflation_parameters
+		}
+	}
+
+	fn weight() -> Weight {
+		// Reads: TotalIssuance, total stake
+		T::DbWeight::get().reads(2)
+	}
+}
+
+/// Inflation rate for the given staking ratio, interpolated between `min` and `max` around the
+/// `base` rate which applies at the `target` ratio.
+pub fn staking_ratio_inflation_rate(
+	base: Perbill,
+	staking_ratio: Perbill,
+	target: Perbill,
+	min: Perbill,
+	max: Perbill,
+) -> Perbill {
+	let base = base.max(min).min(max);
+
+	if staking_ratio < target {
+		let shortfall = Perbill::from_rational(
+			target.saturating_sub(staking_ratio).deconstruct(),
+			target.deconstruct(),
+		);
+		base.saturating_add(shortfall * max.saturating_sub(base))
+	} else if target < Perbill::one() {
+		let excess = Perbill::from_rational(
+			staking_ratio.saturating_sub(target).deconstruct(),
+			Perbill::one().saturating_sub(target).deconstruct(),
+		);
+		base.saturating_sub(excess * base.saturating_sub(min))
+	} else {
+		base
+	}
+}
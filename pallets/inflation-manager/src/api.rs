@@ -0,0 +1,54 @@
+//! Helper functions for the implementation of the `InflationManagerApi` runtime API.
+
+use crate::{Config, Pallet, PotLedger, PotLedgerCount, PotMovementReason, PotTotals};
+use frame_support::traits::{Currency, Get};
+use inflation_manager_runtime_api::{
+	PotLedgerEntry as ApiPotLedgerEntry, PotMovementReason as ApiPotMovementReason,
+	PotReconciliation,
+};
+use peaq_primitives_xcm::Balance;
+use sp_runtime::traits::AccountIdConversion;
+use sp_std::vec::Vec;
+
+/// Maximum number of entries returned by `pot_ledger`.
+const MAX_LEDGER_ENTRIES: u32 = 1000;
+
+impl<T: Config> Pallet<T> {
+	/// Return up to `limit` entries of the inflation pot ledger, starting at `start`.
+	pub fn pot_ledger_entries(
+		start: u32,
+		limit: u32,
+	) -> Vec<ApiPotLedgerEntry<T::AccountId, Balance, T::BlockNumber>> {
+		let end = start
+			.saturating_add(limit.min(MAX_LEDGER_ENTRIES))
+			.min(PotLedgerCount::<T>::get());
+		(start..end)
+			.filter_map(|index| {
+				PotLedger::<T>::get(index).map(|entry| ApiPotLedgerEntry {
+					index,
+					block: entry.block,
+					amount: entry.amount,
+					destination: entry.destination,
+					reason: match entry.reason {
+						PotMovementReason::TotalIssuanceTopUp =>
+							ApiPotMovementReason::TotalIssuanceTopUp,
+						PotMovementReason::TransferAll => ApiPotMovementReason::TransferAll,
+					},
+				})
+			})
+			.collect()
+	}
+
+	/// Return the figures to reconcile `TotalIssuanceNum` with the actual total issuance.
+	pub fn pot_reconciliation() -> PotReconciliation<Balance> {
+		let totals = PotTotals::<T>::get();
+		PotReconciliation {
+			expected_total_issuance: Self::total_issuance_num(),
+			total_issuance: T::Currency::total_issuance(),
+			pot_balance: T::Currency::free_balance(&T::PotId::get().into_account_truncating()),
+			minted: totals.minted,
+			transferred: totals.transferred,
+			entries: PotLedgerCount::<T>::get(),
+		}
+	}
+}
@@ -16,6 +16,7 @@ benchmarks! {
 	}: _(RawOrigin::Root, dest.clone())
 	verify {
 		assert_eq!(CurrencyOf::<T>::free_balance(&pot_account), 0);
+		assert_eq!(PotTotals::<T>::get().transferred, 1000);
 	}
 
 	set_delayed_tge {
@@ -23,16 +24,49 @@ benchmarks! {
 		let supply = 100_000_000_000_000_000_000_000_000_000_000 as u128;
 	}: _(RawOrigin::Root, delay.into(), supply.into())
 	verify {
-		assert_eq!(DoRecalculationAt::<T>::get(), delay.into());
 		assert_eq!(DoInitializeAt::<T>::get(), delay.into());
 		assert_eq!(TotalIssuanceNum::<T>::get(), supply.into());
 	}
 
 	set_recalculation_time {
-		let delay = 1000 as u32;
-	}: _(RawOrigin::Root, delay.into())
+		if FiscalYear::<T>::get().is_none() {
+			FiscalYear::<T>::put(FiscalYearOf::<T>::default());
+		}
+		let moment = pallet_timestamp::Pallet::<T>::get() + 1000_u32.into();
+	}: _(RawOrigin::Root, moment)
+	verify {
+		assert_eq!(FiscalYear::<T>::get().unwrap().end, moment);
+	}
+
+	schedule_inflation_configuration {
+		let inflation_configuration = InflationConfigurationT::default();
+		let activation = InflationConfigurationActivation::AtBlock(1000_u32.into());
+	}: _(RawOrigin::Root, inflation_configuration.clone(), activation.clone())
+	verify {
+		assert_eq!(
+			PendingInflationConfiguration::<T>::get(),
+			Some(PendingInflationConfigurationT { inflation_configuration, activation })
+		);
+	}
+
+	cancel_inflation_configuration {
+		PendingInflationConfiguration::<T>::put(PendingInflationConfigurationT {
+			inflation_configuration: InflationConfigurationT::default(),
+			activation: InflationConfigurationActivation::NextYear,
+		});
+	}: _(RawOrigin::Root)
+	verify {
+		assert!(PendingInflationConfiguration::<T>::get().is_none());
+	}
+
+	set_max_currency_supply {
+		if FiscalYear::<T>::get().is_none() {
+			FiscalYear::<T>::put(FiscalYearOf::<T>::default());
+		}
+		let max_currency_supply = T::Currency::total_issuance() + 1_000_000;
+	}: _(RawOrigin::Root, Some(max_currency_supply))
 	verify {
-		assert_eq!(DoRecalculationAt::<T>::get(), delay.into());
+		assert_eq!(MaxCurrencySupply::<T>::get(), Some(max_currency_supply));
 	}
 }
 
@@ -42,7 +76,7 @@ mod tests {
 	use frame_support::sp_io::TestExternalities;
 
 	pub fn new_test_ext() -> TestExternalities {
-		mock::ExternalityBuilder::build()
+		mock::ExternalityBuilder::default().build()
 	}
 }
 
@@ -4,13 +4,18 @@
 use frame_support::PalletId;
 pub use pallet::*;
 
+pub mod adjustment;
+mod api;
 pub mod types;
+pub use adjustment::{InflationAdjustment, StakingRatioAdjustment};
 use frame_support::traits::ExistenceRequirement::AllowDeath;
 use frame_system::{ensure_root, pallet_prelude::OriginFor};
 use sp_runtime::traits::AccountIdConversion;
 pub use types::{
-	BalanceOf, InflationConfiguration as InflationConfigurationT,
-	InflationParameters as InflationParametersT,
+	BalanceOf, FiscalYearInfo, InflationConfiguration as InflationConfigurationT,
+	InflationConfigurationActivation, InflationParameters as InflationParametersT,
+	PendingInflationConfiguration as PendingInflationConfigurationT, PotLedgerEntry,
+	PotLedgerTotals, PotMovementReason,
 };
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -28,14 +33,29 @@ mod tests;
 
 use frame_support::{
 	pallet_prelude::*,
-	traits::{Currency, IsType},
+	traits::{Currency, EnsureOrigin, Imbalance, IsType},
 };
 use peaq_primitives_xcm::Balance;
-use sp_runtime::{traits::BlockNumberProvider, Perbill};
-use sp_std::cmp::Ordering;
+use sp_runtime::{
+	traits::{BlockNumberProvider, Zero},
+	Perbill, SaturatedConversion,
+};
 
+/// Number of blocks per year assumed by the former block based inflation years, 12s per block
 pub const BLOCKS_PER_YEAR: peaq_primitives_xcm::BlockNumber = 365 * 24 * 60 * 60 / 12_u32;
-const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+/// Length of an inflation year in milliseconds
+pub const MILLISECS_PER_YEAR: u64 = 365 * 24 * 60 * 60 * 1000;
+const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
+pub type FiscalYearOf<T> = FiscalYearInfo<
+	<T as frame_system::Config>::BlockNumber,
+	<T as pallet_timestamp::Config>::Moment,
+>;
+
+pub type PotLedgerEntryOf<T> = PotLedgerEntry<
+	<T as frame_system::Config>::AccountId,
+	<T as frame_system::Config>::BlockNumber,
+>;
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -45,7 +65,7 @@ pub mod pallet {
 	use super::*;
 
 	#[pallet::config]
-	pub trait Config: frame_system::Config {
+	pub trait Config: frame_system::Config + pallet_timestamp::Config {
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
 		/// The currency trait.
@@ -75,9 +95,20 @@ pub mod pallet {
 
 		/// BlockRewards to distribute till delayed TGE kicks in
 		type BlockRewardBeforeInitialize: Get<Balance>;
+
+		/// Target length of an inflation year, measured by the block timestamps
+		#[pallet::constant]
+		type YearLength: Get<Self::Moment>;
+
+		/// The origin which may schedule and cancel a new inflation configuration
+		type ConfigurationOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Adjusts the inflation parameters at the beginning of each year, `()` keeps the fixed
+		/// disinflation curve
+		type InflationAdjustment: InflationAdjustment;
 	}
 
-	/// Inflation kicks off with these parameters
+	/// Inflation kicks off with these parameters, later replaced by scheduled configurations
 	#[pallet::storage]
 	#[pallet::getter(fn inflation_configuration)]
 	pub type InflationConfiguration<T: Config> =
@@ -94,12 +125,23 @@ pub mod pallet {
 	#[pallet::getter(fn current_year)]
 	pub type CurrentYear<T: Config> = StorageValue<_, u128, ValueQuery>;
 
-	/// Flag indicating whether on the first possible opportunity, recalculation of the inflation
-	/// parameters should be done.
-	/// New inflation parameters kick in from the next block after the recalculation block.
+	/// Start, end and issuance progress of the current inflation year.
+	/// The inflation parameters get recalculated in the first block whose timestamp reaches the
+	/// end of the year, they kick in from the next block on.
+	#[pallet::storage]
+	#[pallet::getter(fn fiscal_year)]
+	pub type FiscalYear<T: Config> = StorageValue<_, FiscalYearOf<T>, OptionQuery>;
+
+	/// Inflation configuration which replaces the current one once it gets activated
+	#[pallet::storage]
+	#[pallet::getter(fn pending_inflation_configuration)]
+	pub type PendingInflationConfiguration<T: Config> =
+		StorageValue<_, PendingInflationConfigurationT<T::BlockNumber>, OptionQuery>;
+
+	/// Upper limit of the total issuance, block rewards are clipped once it is reached
 	#[pallet::storage]
-	#[pallet::getter(fn do_recalculation_at)]
-	pub type DoRecalculationAt<T: Config> = StorageValue<_, T::BlockNumber, ValueQuery>;
+	#[pallet::getter(fn max_currency_supply)]
+	pub type MaxCurrencySupply<T: Config> = StorageValue<_, Balance, OptionQuery>;
 
 	/// Flag The initial block of delayTGE
 	/// Setup the new inflation parameters and block rewards
@@ -117,6 +159,27 @@ pub mod pallet {
 	#[pallet::getter(fn block_rewards)]
 	pub type BlockRewards<T: Config> = StorageValue<_, Balance, ValueQuery>;
 
+	/// Block rewards issued in the current block, which might be less than `BlockRewards` once
+	/// the maximum currency supply is reached
+	#[pallet::storage]
+	pub(super) type IssuedInBlock<T: Config> = StorageValue<_, Balance, ValueQuery>;
+
+	/// Ledger of all mints into and transfers out of the inflation pot, by entry index
+	#[pallet::storage]
+	#[pallet::getter(fn pot_ledger)]
+	pub type PotLedger<T: Config> =
+		StorageMap<_, Twox64Concat, u32, PotLedgerEntryOf<T>, OptionQuery>;
+
+	/// Number of entries in the inflation pot ledger
+	#[pallet::storage]
+	#[pallet::getter(fn pot_ledger_count)]
+	pub type PotLedgerCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// Sums of the inflation pot ledger, to reconcile `TotalIssuanceNum` with the total issuance
+	#[pallet::storage]
+	#[pallet::getter(fn pot_totals)]
+	pub type PotTotals<T: Config> = StorageValue<_, PotLedgerTotals, ValueQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -130,9 +193,24 @@ pub mod pallet {
 		InflationConfigurationSet {
 			inflation_configuration: InflationConfigurationT,
 		},
+		InflationConfigurationScheduled {
+			inflation_configuration: InflationConfigurationT,
+			activation: InflationConfigurationActivation<T::BlockNumber>,
+		},
+		InflationConfigurationCancelled {
+			inflation_configuration: InflationConfigurationT,
+		},
+		MaxCurrencySupplySet {
+			max_currency_supply: Option<Balance>,
+		},
 		BlockRewardsUpdated {
 			block_rewards: Balance,
 		},
+		// Tokens were minted into or transferred out of the inflation pot
+		PotLedgerEntryRecorded {
+			index: u32,
+			entry: PotLedgerEntryOf<T>,
+		},
 	}
 
 	/// Error for evm accounts module.
@@ -142,6 +220,10 @@ pub mod pallet {
 		DelayedTGEAlreadySet,
 		WrongDelayedTGESetting,
 		WrongBlockSetting,
+		WrongTimeSetting,
+		InvalidInflationConfiguration,
+		NoPendingInflationConfiguration,
+		MaxCurrencySupplyTooLow,
 	}
 
 	#[pallet::genesis_config]
@@ -163,13 +245,13 @@ pub mod pallet {
 			DoInitializeAt::<T>::put(do_initialize_at);
 			TotalIssuanceNum::<T>::put(T::DefaultTotalIssuanceNum::get());
 
-			// if DoRecalculationAt was provided as zero,
+			// if DoInitializeAt was provided as zero,
 			// Then do TGE now and initialize inflation
 			if do_initialize_at == T::BlockNumber::from(0u32) {
 				Pallet::<T>::fund_difference_balances();
 				Pallet::<T>::initialize_inflation();
 			} else {
-				Pallet::<T>::initialize_delayed_inflation(do_initialize_at);
+				Pallet::<T>::initialize_delayed_inflation();
 			}
 		}
 	}
@@ -184,49 +266,84 @@ pub mod pallet {
 			migrations::on_runtime_upgrade::<T>()
 		}
 
-		fn on_finalize(now: T::BlockNumber) {
-			// if we're at the end of a year or initializing inflation
-			let target_block = DoRecalculationAt::<T>::get();
-			if now == target_block {
-				let current_year = CurrentYear::<T>::get();
-				let new_year = current_year + 1;
+		fn on_initialize(now: T::BlockNumber) -> Weight {
+			// Reads: PendingInflationConfiguration, CurrentYear, DoInitializeAt, FiscalYear,
+			// Timestamp, IssuedInBlock
+			// Writes: FiscalYear, BlockRewards, IssuedInBlock
+			let mut weight = T::DbWeight::get().reads_writes(6, 3);
+
+			// charge the work on_finalize is going to do on top of the rewards of the block
+			if let Some(PendingInflationConfigurationT {
+				activation: InflationConfigurationActivation::AtBlock(activate_at),
+				..
+			}) = PendingInflationConfiguration::<T>::get()
+			{
+				if activate_at <= now {
+					weight = weight.saturating_add(Self::activate_pending_configuration_weight());
+				}
+			}
 
-				let inflation_config = InflationConfiguration::<T>::get();
-				let mut inflation_parameters = InflationParameters::<T>::get();
+			if CurrentYear::<T>::get() == 0 {
+				if now == DoInitializeAt::<T>::get() {
+					weight = weight
+						.saturating_add(Self::fund_difference_balances_weight())
+						.saturating_add(Self::start_new_year_weight());
+				}
+			} else if Self::is_year_ending() {
+				weight = weight.saturating_add(Self::start_new_year_weight());
+			}
 
-				// update current year
-				CurrentYear::<T>::put(new_year);
+			weight
+		}
 
-				// if we're at DoInitializeAt, then we need to adjust total issuance for delayed TGE
+		fn on_finalize(now: T::BlockNumber) {
+			let issued = IssuedInBlock::<T>::take();
+
+			if let Some(PendingInflationConfigurationT {
+				activation: InflationConfigurationActivation::AtBlock(activate_at),
+				..
+			}) = PendingInflationConfiguration::<T>::get()
+			{
+				if activate_at <= now {
+					Self::activate_pending_configuration();
+				}
+			}
+
+			// delayed TGE kicks off the first year
+			if CurrentYear::<T>::get() == 0 {
 				if now == DoInitializeAt::<T>::get() {
 					Self::fund_difference_balances();
+					Self::start_new_year(now);
 				}
+				return
+			}
 
-				match new_year.cmp(&inflation_config.inflation_stagnation_year) {
-					Ordering::Less => {
-						inflation_parameters = Self::update_inflation_parameters(&inflation_config);
-						InflationParameters::<T>::put(inflation_parameters.clone());
-					},
-					Ordering::Equal => {
-						inflation_parameters = InflationParametersT {
-							inflation_rate: inflation_config.inflation_stagnation_rate,
-							disinflation_rate: Perbill::one(),
-						};
-						InflationParameters::<T>::put(inflation_parameters.clone());
-					},
-					Ordering::Greater => {},
-				}
+			let mut fiscal_year = match FiscalYear::<T>::get() {
+				Some(fiscal_year) => fiscal_year,
+				None => return,
+			};
+			let moment = pallet_timestamp::Pallet::<T>::get();
 
-				// set the flag to calculate inflation parameters after a year(in blocks)
-				let target_block = now + T::BlockNumber::from(BLOCKS_PER_YEAR);
-				DoRecalculationAt::<T>::put(target_block);
+			// block rewards of this block have already been issued
+			fiscal_year.issued = fiscal_year.issued.saturating_add(issued);
 
-				// calculate block rewards for new year
-				let block_rewards = Self::rewards_per_block(&inflation_parameters);
-				BlockRewards::<T>::put(block_rewards);
+			// the year was started before any timestamp was set, i.e. at genesis
+			if fiscal_year.end.is_zero() {
+				fiscal_year.start_block = now;
+				fiscal_year.start = moment;
+				fiscal_year.end = moment.saturating_add(T::YearLength::get());
+			}
 
-				// log this change
-				Self::deposit_event(Event::BlockRewardsUpdated { block_rewards });
+			// if we're at the end of a year
+			if moment >= fiscal_year.end {
+				Self::start_new_year(now);
+			} else {
+				BlockRewards::<T>::put(Self::corrected_rewards_per_block(
+					&fiscal_year,
+					now,
+					moment,
+				));
+				FiscalYear::<T>::put(fiscal_year);
 			}
 		}
 	}
@@ -242,12 +359,9 @@ pub mod pallet {
 			ensure_root(origin)?;
 
 			let account = T::PotId::get().into_account_truncating();
-			T::Currency::transfer(
-				&account,
-				&dest,
-				T::Currency::free_balance(&account),
-				AllowDeath,
-			)?;
+			let amount = T::Currency::free_balance(&account);
+			T::Currency::transfer(&account, &dest, amount, AllowDeath)?;
+			Self::record_pot_movement(amount, dest, PotMovementReason::TransferAll);
 
 			Ok(().into())
 		}
@@ -277,7 +391,6 @@ pub mod pallet {
 			ensure!(issuance > T::Currency::total_issuance(), Error::<T>::WrongDelayedTGESetting);
 
 			DoInitializeAt::<T>::put(block);
-			DoRecalculationAt::<T>::put(block);
 			TotalIssuanceNum::<T>::put(issuance);
 
 			Ok(().into())
@@ -287,16 +400,94 @@ pub mod pallet {
 		#[pallet::weight(T::WeightInfo::set_recalculation_time())]
 		pub fn set_recalculation_time(
 			origin: OriginFor<T>,
-			block: T::BlockNumber,
+			moment: T::Moment,
 		) -> DispatchResultWithPostInfo {
 			ensure_root(origin)?;
 
-			ensure!(
-				block > frame_system::Pallet::<T>::block_number(),
-				Error::<T>::WrongBlockSetting
-			);
-			ensure!(block > DoInitializeAt::<T>::get(), Error::<T>::WrongBlockSetting);
-			DoRecalculationAt::<T>::put(block);
+			let mut fiscal_year =
+				FiscalYear::<T>::get().ok_or(Error::<T>::FiscalYearUninitialized)?;
+			ensure!(moment > pallet_timestamp::Pallet::<T>::get(), Error::<T>::WrongTimeSetting);
+
+			// the remaining budget of the year gets spread until the new end
+			fiscal_year.end = moment;
+			FiscalYear::<T>::put(fiscal_year);
+
+			Ok(().into())
+		}
+
+		/// Schedule a new inflation configuration, which takes effect at the beginning of the
+		/// next fiscal year or at the given block. An already pending configuration gets
+		/// replaced.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::schedule_inflation_configuration())]
+		pub fn schedule_inflation_configuration(
+			origin: OriginFor<T>,
+			inflation_configuration: InflationConfigurationT,
+			activation: InflationConfigurationActivation<T::BlockNumber>,
+		) -> DispatchResultWithPostInfo {
+			T::ConfigurationOrigin::ensure_origin(origin)?;
+
+			ensure!(inflation_configuration.is_valid(), Error::<T>::InvalidInflationConfiguration);
+			if let InflationConfigurationActivation::AtBlock(block) = activation {
+				ensure!(
+					block > frame_system::Pallet::<T>::block_number(),
+					Error::<T>::WrongBlockSetting
+				);
+			}
+
+			PendingInflationConfiguration::<T>::put(PendingInflationConfigurationT {
+				inflation_configuration: inflation_configuration.clone(),
+				activation: activation.clone(),
+			});
+			Self::deposit_event(Event::InflationConfigurationScheduled {
+				inflation_configuration,
+				activation,
+			});
+
+			Ok(().into())
+		}
+
+		/// Cancel the pending inflation configuration
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::cancel_inflation_configuration())]
+		pub fn cancel_inflation_configuration(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			T::ConfigurationOrigin::ensure_origin(origin)?;
+
+			let pending = PendingInflationConfiguration::<T>::take()
+				.ok_or(Error::<T>::NoPendingInflationConfiguration)?;
+			Self::deposit_event(Event::InflationConfigurationCancelled {
+				inflation_configuration: pending.inflation_configuration,
+			});
+
+			Ok(().into())
+		}
+
+		/// Set the maximum currency supply, `None` removes the cap. The budget of the running
+		/// year gets reduced if it would exceed the new cap, later years honour it as well.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::set_max_currency_supply())]
+		pub fn set_max_currency_supply(
+			origin: OriginFor<T>,
+			max_currency_supply: Option<Balance>,
+		) -> DispatchResultWithPostInfo {
+			T::ConfigurationOrigin::ensure_origin(origin)?;
+
+			if let Some(max_currency_supply) = max_currency_supply {
+				ensure!(
+					max_currency_supply > T::Currency::total_issuance(),
+					Error::<T>::MaxCurrencySupplyTooLow
+				);
+			}
+			MaxCurrencySupply::<T>::set(max_currency_supply);
+
+			if let Some(mut fiscal_year) = FiscalYear::<T>::get() {
+				let remaining = fiscal_year.budget.saturating_sub(fiscal_year.issued);
+				fiscal_year.budget =
+					fiscal_year.issued.saturating_add(Self::capped_issuance(remaining));
+				FiscalYear::<T>::put(fiscal_year);
+			}
+
+			Self::deposit_event(Event::MaxCurrencySupplySet { max_currency_supply });
 
 			Ok(().into())
 		}
@@ -306,11 +497,179 @@ pub mod pallet {
 		/// calculate inflationary tokens per block
 		/// Weight Reads: 1
 		pub fn rewards_per_block(inflation_parameters: &InflationParametersT) -> Balance {
-			let total_issuance = T::Currency::total_issuance();
-			let rewards_total = inflation_parameters.inflation_rate * total_issuance;
+			Self::rewards_per_block_for(inflation_parameters, T::Currency::total_issuance())
+		}
+
+		/// calculate inflationary tokens per block for the given total issuance
+		pub fn rewards_per_block_for(
+			inflation_parameters: &InflationParametersT,
+			total_issuance: Balance,
+		) -> Balance {
+			let rewards_total = Self::yearly_issuance_for(inflation_parameters, total_issuance);
 
 			// return rewards per block
-			rewards_total / Balance::from(BLOCKS_PER_YEAR)
+			rewards_total / Self::expected_blocks_per_year()
+		}
+
+		/// Record block rewards issued in the current block, they are added to the fiscal year
+		/// at the end of the block
+		pub fn note_issued(amount: Balance) {
+			IssuedInBlock::<T>::mutate(|issued| *issued = issued.saturating_add(amount));
+		}
+
+		/// Tokens which can be issued until the maximum currency supply is reached
+		pub fn remaining_supply() -> Option<Balance> {
+			Self::remaining_supply_for(T::Currency::total_issuance())
+		}
+
+		/// Tokens which can be issued on top of the given total issuance until the maximum
+		/// currency supply is reached
+		pub fn remaining_supply_for(total_issuance: Balance) -> Option<Balance> {
+			MaxCurrencySupply::<T>::get()
+				.map(|max_currency_supply| max_currency_supply.saturating_sub(total_issuance))
+		}
+
+		/// Clip the given amount to the remaining supply
+		pub fn capped_issuance(amount: Balance) -> Balance {
+			Self::capped_issuance_for(amount, T::Currency::total_issuance())
+		}
+
+		/// Clip the given amount to the remaining supply on top of the given total issuance
+		pub fn capped_issuance_for(amount: Balance, total_issuance: Balance) -> Balance {
+			Self::remaining_supply_for(total_issuance)
+				.map_or(amount, |remaining_supply| amount.min(remaining_supply))
+		}
+
+		/// calculate inflationary tokens of a whole year for the given total issuance
+		pub fn yearly_issuance_for(
+			inflation_parameters: &InflationParametersT,
+			total_issuance: Balance,
+		) -> Balance {
+			inflation_parameters.inflation_rate * total_issuance
+		}
+
+		/// Expected block time, the slot duration which is twice the minimum period
+		pub fn expected_block_time() -> Balance {
+			let minimum_period: Balance = T::MinimumPeriod::get().saturated_into();
+			minimum_period.saturating_mul(2).max(1)
+		}
+
+		/// Number of blocks produced in a year if the expected block time is met
+		pub fn expected_blocks_per_year() -> Balance {
+			let year_length: Balance = T::YearLength::get().saturated_into();
+			(year_length / Self::expected_block_time()).max(1)
+		}
+
+		/// Spread the remaining budget of the year over the blocks expected until its end.
+		/// The expected number of blocks follows the average block time observed during the
+		/// year, so the rewards get corrected when blocks are produced faster or slower.
+		fn corrected_rewards_per_block(
+			fiscal_year: &FiscalYearOf<T>,
+			now: T::BlockNumber,
+			moment: T::Moment,
+		) -> Balance {
+			let elapsed_blocks: Balance =
+				now.saturating_sub(fiscal_year.start_block).saturated_into();
+			let elapsed: Balance = moment.saturating_sub(fiscal_year.start).saturated_into();
+			let block_time = if elapsed_blocks.is_zero() || elapsed.is_zero() {
+				Self::expected_block_time()
+			} else {
+				(elapsed / elapsed_blocks).max(1)
+			};
+
+			let remaining: Balance = fiscal_year.end.saturating_sub(moment).saturated_into();
+			let remaining_blocks = (remaining / block_time).max(1);
+
+			fiscal_year.budget.saturating_sub(fiscal_year.issued) / remaining_blocks
+		}
+
+		/// Start a new fiscal year at the given block, based on the current timestamp and the
+		/// inflation parameters of that year
+		fn new_fiscal_year(
+			now: T::BlockNumber,
+			inflation_parameters: &InflationParametersT,
+		) -> FiscalYearOf<T> {
+			let moment = pallet_timestamp::Pallet::<T>::get();
+			// without a timestamp, the year gets started with the first one in on_finalize
+			let end = if moment.is_zero() {
+				Zero::zero()
+			} else {
+				moment.saturating_add(T::YearLength::get())
+			};
+
+			FiscalYearInfo {
+				start_block: now,
+				start: moment,
+				end,
+				budget: Self::capped_issuance(Self::yearly_issuance_for(
+					inflation_parameters,
+					T::Currency::total_issuance(),
+				)),
+				issued: Zero::zero(),
+			}
+		}
+
+		/// Whether the fiscal year is expected to end in this block, i.e. the timestamp of the
+		/// previous block is less than a block time away from its end
+		fn is_year_ending() -> bool {
+			FiscalYear::<T>::get().map_or(false, |fiscal_year| {
+				let moment = pallet_timestamp::Pallet::<T>::get()
+					.saturating_add(Self::expected_block_time().saturated_into());
+				!fiscal_year.end.is_zero() && moment >= fiscal_year.end
+			})
+		}
+
+		/// Weight of `start_new_year`, including the activation of a configuration which is
+		/// scheduled for the next year
+		/// Weight Reads: CurrentYear, PendingInflationConfiguration, InflationConfiguration,
+		/// TotalIssuance, MaxCurrencySupply, Timestamp
+		/// Weight Writes: CurrentYear, InflationParameters, BlockRewards, FiscalYear
+		fn start_new_year_weight() -> Weight {
+			let weight = T::DbWeight::get()
+				.reads_writes(6, 4)
+				.saturating_add(T::InflationAdjustment::weight());
+
+			match PendingInflationConfiguration::<T>::get() {
+				Some(PendingInflationConfigurationT {
+					activation: InflationConfigurationActivation::NextYear,
+					..
+				}) => weight.saturating_add(Self::activate_pending_configuration_weight()),
+				_ => weight,
+			}
+		}
+
+		/// Recalculate the inflation parameters and the block rewards at the beginning of a year
+		fn start_new_year(now: T::BlockNumber) {
+			let new_year = CurrentYear::<T>::get() + 1;
+
+			// a configuration scheduled for the next year takes effect before the recalculation
+			if let Some(PendingInflationConfigurationT {
+				activation: InflationConfigurationActivation::NextYear,
+				..
+			}) = PendingInflationConfiguration::<T>::get()
+			{
+				Self::activate_pending_configuration();
+			}
+
+			let inflation_config = InflationConfiguration::<T>::get();
+
+			// update current year
+			CurrentYear::<T>::put(new_year);
+
+			// disinflation until the stagnation year, then the stagnation rate
+			let inflation_parameters =
+				Self::adjusted_inflation_parameters_for_year(&inflation_config, new_year);
+			InflationParameters::<T>::put(inflation_parameters.clone());
+
+			let fiscal_year = Self::new_fiscal_year(now, &inflation_parameters);
+
+			// calculate block rewards for new year, its budget honours the maximum supply
+			let block_rewards = fiscal_year.budget / Self::expected_blocks_per_year();
+			BlockRewards::<T>::put(block_rewards);
+			FiscalYear::<T>::put(fiscal_year);
+
+			// log this change
+			Self::deposit_event(Event::BlockRewardsUpdated { block_rewards });
 		}
 
 		/// We do not expect this to underflow/overflow
@@ -318,8 +677,46 @@ pub mod pallet {
 		pub fn update_inflation_parameters(
 			inflation_config: &InflationConfigurationT,
 		) -> InflationParametersT {
-			let current_year = CurrentYear::<T>::get();
+			T::InflationAdjustment::adjust(Self::disinflated_parameters(
+				inflation_config,
+				CurrentYear::<T>::get(),
+			))
+		}
+
+		/// Inflation parameters of the given year after the adjustment of the runtime, e.g. to
+		/// the current staking ratio
+		pub fn adjusted_inflation_parameters_for_year(
+			inflation_config: &InflationConfigurationT,
+			year: u128,
+		) -> InflationParametersT {
+			T::InflationAdjustment::adjust(Self::inflation_parameters_for_year(
+				inflation_config,
+				year,
+			))
+		}
 
+		/// Inflation parameters which are in effect during the given year, including the
+		/// stagnation after `inflation_stagnation_year`.
+		pub fn inflation_parameters_for_year(
+			inflation_config: &InflationConfigurationT,
+			year: u128,
+		) -> InflationParametersT {
+			if year < inflation_config.inflation_stagnation_year {
+				Self::disinflated_parameters(inflation_config, year)
+			} else {
+				InflationParametersT {
+					inflation_rate: inflation_config.inflation_stagnation_rate,
+					disinflation_rate: Perbill::one(),
+				}
+			}
+		}
+
+		/// Inflation parameters of the given year before the stagnation kicks in.
+		/// We do not expect this to underflow/overflow
+		fn disinflated_parameters(
+			inflation_config: &InflationConfigurationT,
+			current_year: u128,
+		) -> InflationParametersT {
 			// Calculate disinflation rate as disinflation rate(n) = disinflation rate(0) ^ (n-1)
 			let disinflation = Perbill::from_percent(100) -
 				inflation_config.inflation_parameters.disinflation_rate;
@@ -334,13 +731,77 @@ pub mod pallet {
 			InflationParametersT { inflation_rate, disinflation_rate }
 		}
 
+		/// Weight of `activate_pending_configuration`
+		/// Weight Reads: PendingInflationConfiguration, CurrentYear, FiscalYear, Timestamp,
+		/// TotalIssuance, MaxCurrencySupply
+		/// Weight Writes: PendingInflationConfiguration, InflationConfiguration,
+		/// InflationParameters, FiscalYear
+		fn activate_pending_configuration_weight() -> Weight {
+			T::DbWeight::get()
+				.reads_writes(6, 4)
+				.saturating_add(T::InflationAdjustment::weight())
+		}
+
+		/// Replace the inflation configuration by the pending one. Within a running fiscal year
+		/// the inflation parameters and the remaining budget of the year follow the new
+		/// configuration right away.
+		fn activate_pending_configuration() {
+			let inflation_configuration = match PendingInflationConfiguration::<T>::take() {
+				Some(pending) => pending.inflation_configuration,
+				None => return,
+			};
+			InflationConfiguration::<T>::put(inflation_configuration.clone());
+
+			let current_year = CurrentYear::<T>::get();
+			if current_year > 0 {
+				let inflation_parameters = Self::adjusted_inflation_parameters_for_year(
+					&inflation_configuration,
+					current_year,
+				);
+				InflationParameters::<T>::put(inflation_parameters.clone());
+
+				if let Some(mut fiscal_year) = FiscalYear::<T>::get() {
+					let moment = pallet_timestamp::Pallet::<T>::get();
+					let year_length: Balance =
+						fiscal_year.end.saturating_sub(fiscal_year.start).saturated_into();
+					let remaining: Balance =
+						fiscal_year.end.saturating_sub(moment).saturated_into();
+					// a year which has not started yet gets the budget of a whole year
+					let remaining_share = if year_length.is_zero() {
+						Perbill::one()
+					} else {
+						Perbill::from_rational(remaining.min(year_length), year_length)
+					};
+					let yearly_issuance = Self::yearly_issuance_for(
+						&inflation_parameters,
+						T::Currency::total_issuance(),
+					);
+					fiscal_year.budget = fiscal_year
+						.issued
+						.saturating_add(Self::capped_issuance(remaining_share * yearly_issuance));
+					FiscalYear::<T>::put(fiscal_year);
+				}
+			}
+
+			Self::deposit_event(Event::InflationConfigurationSet { inflation_configuration });
+		}
+
+		/// Weight of `fund_difference_balances`
+		/// Weight Reads: TotalIssuance, TotalIssuanceNum, System Account, PotTotals,
+		/// PotLedgerCount
+		/// Weight Writes: TotalIssuance, System Account, PotTotals, PotLedgerCount, PotLedger
+		fn fund_difference_balances_weight() -> Weight {
+			T::DbWeight::get().reads_writes(5, 5)
+		}
+
 		pub fn fund_difference_balances() {
 			let account = T::PotId::get().into_account_truncating();
 			let now_total_issuance = T::Currency::total_issuance();
 			let desired_issuance = TotalIssuanceNum::<T>::get();
 			if now_total_issuance < desired_issuance {
 				let amount = desired_issuance.saturating_sub(now_total_issuance);
-				T::Currency::deposit_creating(&account, amount);
+				let minted = T::Currency::deposit_creating(&account, amount).peek();
+				Self::record_pot_movement(minted, account, PotMovementReason::TotalIssuanceTopUp);
 				log::info!(
 					"Total issuance was increased from {:?} to {:?}, by {:?} tokens.",
 					now_total_issuance,
@@ -350,6 +811,35 @@ pub mod pallet {
 			}
 		}
 
+		/// Appends an entry to the inflation pot ledger and updates its totals
+		fn record_pot_movement(
+			amount: Balance,
+			destination: T::AccountId,
+			reason: PotMovementReason,
+		) {
+			if amount.is_zero() {
+				return
+			}
+
+			PotTotals::<T>::mutate(|totals| match reason {
+				PotMovementReason::TotalIssuanceTopUp =>
+					totals.minted = totals.minted.saturating_add(amount),
+				PotMovementReason::TransferAll =>
+					totals.transferred = totals.transferred.saturating_add(amount),
+			});
+
+			let index = PotLedgerCount::<T>::get();
+			let entry = PotLedgerEntry {
+				block: frame_system::Pallet::<T>::block_number(),
+				amount,
+				destination,
+				reason,
+			};
+			PotLedger::<T>::insert(index, &entry);
+			PotLedgerCount::<T>::put(index.saturating_add(1));
+			Self::deposit_event(Event::PotLedgerEntryRecorded { index, entry });
+		}
+
 		pub fn initialize_inflation() -> Weight {
 			let current_block = frame_system::Pallet::<T>::block_number();
 			let mut weight_reads = 1;
@@ -373,11 +863,9 @@ pub mod pallet {
 			InflationParameters::<T>::put(inflation_parameters.clone());
 			weight_writes += 1;
 
-			// set the flag to calculate inflation parameters after a year(in blocks)
-			let racalculation_target_block = current_block + T::BlockNumber::from(BLOCKS_PER_YEAR);
-
-			// Update recalculation flag
-			DoRecalculationAt::<T>::put(racalculation_target_block);
+			// start the first year, it ends after a year(in time)
+			FiscalYear::<T>::put(Self::new_fiscal_year(current_block, &inflation_parameters));
+			weight_reads += 2;
 			weight_writes += 1;
 
 			let block_rewards = Pallet::<T>::rewards_per_block(&inflation_parameters);
@@ -389,8 +877,8 @@ pub mod pallet {
 			T::DbWeight::get().reads_writes(weight_reads, weight_writes)
 		}
 
-		/// Sets DoRecalculationAt to the given block number where year 1 will kick off
-		pub fn initialize_delayed_inflation(do_recalculation_at: T::BlockNumber) -> Weight {
+		/// Prepares the delayed TGE, year 1 will kick off at DoInitializeAt
+		pub fn initialize_delayed_inflation() -> Weight {
 			let mut weight_reads = 0;
 			let mut weight_writes = 0;
 			weight_reads += 1;
@@ -404,10 +892,6 @@ pub mod pallet {
 			BlockRewards::<T>::put(T::BlockRewardBeforeInitialize::get());
 			weight_writes += 1;
 
-			// set DoRecalculationAt to trigger at delayed TGE block
-			DoRecalculationAt::<T>::put(do_recalculation_at);
-			weight_writes += 1;
-
 			// return from here as we are not initializing inflation yet
 			// leaving InflationParameters and BlockRewards uninitialized, saving some weight
 			T::DbWeight::get().reads_writes(weight_reads, weight_writes)
@@ -1,9 +1,12 @@
 use super::*;
 
 use frame_support::{pallet_prelude::*, weights::Weight};
+use sp_runtime::traits::Saturating;
 
 pub(crate) fn on_runtime_upgrade<T: Config>() -> Weight {
-	upgrade::MigrateToV0::<T>::on_runtime_upgrade()
+	let mut weight = upgrade::MigrateToV0::<T>::on_runtime_upgrade();
+	weight.saturating_accrue(upgrade::MigrateToV2::<T>::on_runtime_upgrade());
+	weight
 }
 
 mod upgrade {
@@ -24,7 +27,7 @@ mod upgrade {
 			weight_reads += 1;
 			let current = Pallet::<T>::current_storage_version();
 
-			if onchain_storage_version < current {
+			if onchain_storage_version < 1 {
 				let do_initialize_at = T::DoInitializeAt::get();
 				DoInitializeAt::<T>::put(do_initialize_at);
 				TotalIssuanceNum::<T>::put(T::DefaultTotalIssuanceNum::get());
@@ -44,7 +47,7 @@ mod upgrade {
 						"Inflation Manager storage migration completed from version {:?} to version {:?} with TGE", onchain_storage_version, current
 					);
 				} else if do_initialize_at > current_block {
-					calculated_weight = Pallet::<T>::initialize_delayed_inflation(do_initialize_at);
+					calculated_weight = Pallet::<T>::initialize_delayed_inflation();
 				}
 
 				// Update storage version
@@ -59,4 +62,66 @@ mod upgrade {
 				.saturating_add(T::DbWeight::get().reads_writes(weight_reads, weight_writes))
 		}
 	}
+
+	#[frame_support::storage_alias]
+	type DoRecalculationAt<T: Config> =
+		StorageValue<Pallet<T>, <T as frame_system::Config>::BlockNumber, ValueQuery>;
+
+	pub struct MigrateToV2<T>(sp_std::marker::PhantomData<T>);
+
+	// Inflation years used to last BLOCKS_PER_YEAR blocks and ended at DoRecalculationAt, now
+	// they last YearLength and end once the block timestamp reaches the end of the FiscalYear
+	impl<T: Config> MigrateToV2<T> {
+		pub fn on_runtime_upgrade() -> Weight {
+			let mut weight_writes = 0;
+			let mut weight_reads = 0;
+
+			let onchain_storage_version = Pallet::<T>::on_chain_storage_version();
+			weight_reads += 1;
+			let current = Pallet::<T>::current_storage_version();
+
+			if onchain_storage_version < current {
+				let now = frame_system::Pallet::<T>::current_block_number();
+				let recalculation_at = DoRecalculationAt::<T>::take();
+				weight_reads += 3;
+				weight_writes += 1;
+
+				// before the delayed TGE the first year gets started at DoInitializeAt
+				if CurrentYear::<T>::get() > 0 {
+					let moment = pallet_timestamp::Pallet::<T>::get();
+					let block_rewards = BlockRewards::<T>::get();
+					let blocks_per_year = T::BlockNumber::from(BLOCKS_PER_YEAR);
+					let year_length: Balance = T::YearLength::get().saturated_into();
+
+					// keep the end of the year at the time where the recalculation block was
+					// expected and count the rewards issued so far into the year's budget
+					let remaining_blocks: Balance =
+						recalculation_at.saturating_sub(now).saturated_into();
+					let remaining = year_length.saturating_mul(remaining_blocks) /
+						Balance::from(BLOCKS_PER_YEAR);
+					let start_block = recalculation_at.saturating_sub(blocks_per_year);
+					let elapsed_blocks: Balance = now.saturating_sub(start_block).saturated_into();
+					let end = moment.saturating_add(remaining.saturated_into());
+
+					FiscalYear::<T>::put(FiscalYearInfo {
+						start_block,
+						start: end.saturating_sub(T::YearLength::get()),
+						end,
+						budget: block_rewards.saturating_mul(BLOCKS_PER_YEAR.into()),
+						issued: block_rewards.saturating_mul(elapsed_blocks),
+					});
+					weight_reads += 2;
+					weight_writes += 1;
+				}
+
+				STORAGE_VERSION.put::<Pallet<T>>();
+				weight_writes += 1;
+
+				log::info!(
+					"Inflation Manager storage migration completed from version {:?} to version {:?}", onchain_storage_version, current
+				);
+			}
+			T::DbWeight::get().reads_writes(weight_reads, weight_writes)
+		}
+	}
 }
@@ -1,11 +1,16 @@
-use crate::{self as inflation_manager, types, weights, Perbill};
+use crate::{
+	self as inflation_manager, types, weights, InflationAdjustment, Perbill, StakingRatioAdjustment,
+};
 use frame_support::PalletId;
 
 use frame_support::{
-	construct_runtime, parameter_types, sp_io::TestExternalities, traits::GenesisBuild,
-	weights::Weight,
+	construct_runtime, parameter_types,
+	sp_io::TestExternalities,
+	traits::GenesisBuild,
+	weights::{constants::RocksDbWeight, Weight},
 };
 
+use frame_system::EnsureRoot;
 use sp_core::{ConstU32, H256};
 use sp_runtime::{
 	testing::Header,
@@ -57,7 +62,7 @@ impl frame_system::Config for TestRuntime {
 	type Header = Header;
 	type RuntimeEvent = RuntimeEvent;
 	type BlockHashCount = BlockHashCount;
-	type DbWeight = ();
+	type DbWeight = RocksDbWeight;
 	type Version = ();
 	type PalletInfo = PalletInfo;
 	type AccountData = pallet_balances::AccountData<Balance>;
@@ -114,6 +119,45 @@ parameter_types! {
 	};
 	pub const InitializeInflationAt: BlockNumber = 10;
 	pub const BlockRewardBeforeInitialize: Balance = 1000;
+	// 10_000 blocks at the expected block time of 2 * MinimumPeriod
+	pub const InflationYearLength: u64 = 60_000;
+	pub const MinInflationRate: Perbill = Perbill::from_percent(1);
+	pub const MaxInflationRate: Perbill = Perbill::from_percent(7);
+	pub const TargetStakingRatio: Perbill = Perbill::from_percent(50);
+	pub static MockTotalStake: Balance = 0;
+	pub static StakingRatioMode: bool = false;
+}
+
+/// Keeps the fixed disinflation curve unless the staking ratio mode is enabled
+pub struct MockInflationAdjustment;
+impl InflationAdjustment for MockInflationAdjustment {
+	fn adjust(inflation_parameters: types::InflationParameters) -> types::InflationParameters {
+		if StakingRatioMode::get() {
+			StakingRatioAdjustment::<
+				TestRuntime,
+				MockTotalStake,
+				MinInflationRate,
+				MaxInflationRate,
+				TargetStakingRatio,
+			>::adjust(inflation_parameters)
+		} else {
+			inflation_parameters
+		}
+	}
+
+	fn weight() -> Weight {
+		if StakingRatioMode::get() {
+			StakingRatioAdjustment::<
+				TestRuntime,
+				MockTotalStake,
+				MinInflationRate,
+				MaxInflationRate,
+				TargetStakingRatio,
+			>::weight()
+		} else {
+			Weight::zero()
+		}
+	}
 }
 
 impl inflation_manager::Config for TestRuntime {
@@ -126,6 +170,9 @@ impl inflation_manager::Config for TestRuntime {
 	type WeightInfo = weights::WeightInfo<TestRuntime>;
 	type DoInitializeAt = InitializeInflationAt;
 	type BlockRewardBeforeInitialize = BlockRewardBeforeInitialize;
+	type YearLength = InflationYearLength;
+	type ConfigurationOrigin = EnsureRoot<AccountId>;
+	type InflationAdjustment = MockInflationAdjustment;
 }
 pub struct ExternalityBuilder {
 	// endowed accounts with balances
@@ -1,26 +1,27 @@
 use super::*;
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{assert_noop, assert_ok, storage::unhashed};
 use frame_system::RawOrigin;
 use mock::*;
-use peaq_primitives_xcm::BlockNumber;
 use sp_runtime::{
 	traits::{AccountIdConversion, BadOrigin, Saturating},
 	Perbill,
 };
 
+/// Timestamp of the delayed TGE block in the tests
+const TGE_MOMENT: u64 = 60;
+
 #[test]
 fn sanity_check_genesis_delayed_tge() {
 	ExternalityBuilder::default().build().execute_with(|| {
-		let snapshot = InflationManagerSnapshot::take_snapshot_at(0);
+		let snapshot = InflationManagerSnapshot::take_snapshot_at(0, 0);
 		// Delayed TGE sets inflation parameters as their default falue
 		let expected_inflation_parameters = InflationParametersT::default();
 
 		assert_eq!(snapshot.inflation_configuration, InflationConfigurationT::default());
 		assert_eq!(snapshot.inflation_parameters, expected_inflation_parameters);
-		assert_eq!(
-			snapshot.do_recalculation_at as u64,
-			<TestRuntime as Config>::DoInitializeAt::get()
-		);
+		// the first year starts at the delayed TGE
+		assert_eq!(snapshot.year_end, None);
+		assert_eq!(InflationManager::initialize_block(), InitializeInflationAt::get());
 		// inflation has not kicked off, so current year should be 0
 		assert_eq!(snapshot.current_year, 0u128);
 		assert_eq!(
@@ -61,6 +62,61 @@ fn check_fund_enough_token_at_delayed_tge_kickoff() {
 		})
 }
 
+#[test]
+fn pot_ledger_records_mints_and_transfers() {
+	ExternalityBuilder::default()
+		.with_balances(vec![(1, 20)])
+		.build()
+		.execute_with(|| {
+			let do_initialize_at = <TestRuntime as Config>::DoInitializeAt::get();
+			let account: AccountId =
+				<TestRuntime as Config>::PotId::get().into_account_truncating();
+			let minted = DefaultTotalIssuanceNum::get() - 20;
+			assert_eq!(InflationManager::pot_ledger_count(), 0);
+
+			System::set_block_number(do_initialize_at);
+			InflationManager::on_finalize(do_initialize_at);
+
+			let mint = PotLedgerEntry {
+				block: do_initialize_at,
+				amount: minted,
+				destination: account,
+				reason: PotMovementReason::TotalIssuanceTopUp,
+			};
+			assert_eq!(InflationManager::pot_ledger(0), Some(mint.clone()));
+			System::assert_has_event(RuntimeEvent::InflationManager(
+				Event::PotLedgerEntryRecorded { index: 0, entry: mint },
+			));
+
+			System::set_block_number(do_initialize_at + 1);
+			InflationManager::transfer_all_pot(RawOrigin::Root.into(), 2).unwrap();
+			let transfer = PotLedgerEntry {
+				block: do_initialize_at + 1,
+				amount: minted,
+				destination: 2,
+				reason: PotMovementReason::TransferAll,
+			};
+			assert_eq!(InflationManager::pot_ledger(1), Some(transfer.clone()));
+			System::assert_last_event(RuntimeEvent::InflationManager(
+				Event::PotLedgerEntryRecorded { index: 1, entry: transfer },
+			));
+
+			// an empty pot does not add an entry
+			InflationManager::transfer_all_pot(RawOrigin::Root.into(), 2).unwrap();
+			assert_eq!(InflationManager::pot_ledger_count(), 2);
+			assert_eq!(InflationManager::pot_ledger_entries(1, 10).len(), 1);
+			assert_eq!(InflationManager::pot_ledger_entries(1, 10)[0].destination, 2);
+
+			let reconciliation = InflationManager::pot_reconciliation();
+			assert_eq!(reconciliation.expected_total_issuance, DefaultTotalIssuanceNum::get());
+			assert_eq!(reconciliation.total_issuance, DefaultTotalIssuanceNum::get());
+			assert_eq!(reconciliation.pot_balance, 0);
+			assert_eq!(reconciliation.minted, minted);
+			assert_eq!(reconciliation.transferred, minted);
+			assert_eq!(reconciliation.entries, 2);
+		})
+}
+
 #[test]
 fn check_not_fund_token_at_delayed_tge_kickoff() {
 	ExternalityBuilder::default()
@@ -84,12 +140,12 @@ fn check_not_fund_token_at_delayed_tge_kickoff() {
 fn sanity_check_storage_migration_for_delayed_tge() {
 	ExternalityBuilder::default().build().execute_with(|| {
 		InflationManager::on_runtime_upgrade();
-		let current_block = System::block_number() as u32;
+		let current_block = System::block_number();
 
 		let total_issuance_before_upgrade = <TestRuntime as Config>::Currency::total_issuance();
 		// Delayed TGE is set so this should have no affect
 		InflationManager::on_runtime_upgrade();
-		let snapshot = InflationManagerSnapshot::take_snapshot_at(current_block);
+		let snapshot = InflationManagerSnapshot::take_snapshot_at(current_block, 0);
 		// Delayed TGE sets inflation parameters as their default value
 		let expected_inflation_parameters = InflationParametersT::default();
 
@@ -99,10 +155,7 @@ fn sanity_check_storage_migration_for_delayed_tge() {
 		);
 		assert_eq!(snapshot.inflation_configuration, InflationConfigurationT::default());
 		assert_eq!(snapshot.inflation_parameters, expected_inflation_parameters);
-		assert_eq!(
-			snapshot.do_recalculation_at as u64,
-			<TestRuntime as Config>::DoInitializeAt::get()
-		);
+		assert_eq!(snapshot.year_end, None);
 		assert_eq!(snapshot.current_year, 0u128);
 		assert_eq!(
 			snapshot.block_rewards,
@@ -111,39 +164,44 @@ fn sanity_check_storage_migration_for_delayed_tge() {
 	})
 }
 
-// In the DoRecalculationAt block,
+// In the DoInitializeAt block,
 // Block rewards are distributed first and then block rewards are updated
 #[test]
 fn parameters_update_as_expected_at_tge() {
 	ExternalityBuilder::default().build().execute_with(|| {
-		let do_initialize_at = <TestRuntime as Config>::DoInitializeAt::get() as u32;
-		let target_block_at_genesis = do_initialize_at;
+		let target_block_at_genesis = <TestRuntime as Config>::DoInitializeAt::get();
 
 		let snapshots_before_tge = vec![
-			InflationManagerSnapshot::take_snapshot_at(target_block_at_genesis - 2),
-			InflationManagerSnapshot::take_snapshot_at(target_block_at_genesis - 1),
+			InflationManagerSnapshot::take_snapshot_at(
+				target_block_at_genesis - 2,
+				TGE_MOMENT - 12,
+			),
+			InflationManagerSnapshot::take_snapshot_at(target_block_at_genesis - 1, TGE_MOMENT - 6),
 		];
 
 		let snapshots_after_tge = vec![
-			InflationManagerSnapshot::take_snapshot_at(target_block_at_genesis),
-			InflationManagerSnapshot::take_snapshot_at(target_block_at_genesis + 1),
+			InflationManagerSnapshot::take_snapshot_at(target_block_at_genesis, TGE_MOMENT),
+			InflationManagerSnapshot::take_snapshot_at(target_block_at_genesis + 1, TGE_MOMENT + 6),
 		];
 
 		// Check that the snapshots before the TGE are consistent
 		assert_eq!(snapshots_before_tge[0], snapshots_before_tge[1]);
 
-		// check that the snapshots after the TGE are consistent
-		assert_eq!(snapshots_after_tge[0], snapshots_after_tge[1]);
+		// check that the snapshots after the TGE are consistent, block rewards get corrected
+		// from block to block
+		assert_eq!(snapshots_after_tge[0].current_year, snapshots_after_tge[1].current_year);
+		assert_eq!(
+			snapshots_after_tge[0].inflation_parameters,
+			snapshots_after_tge[1].inflation_parameters
+		);
+		assert_eq!(snapshots_after_tge[0].year_end, snapshots_after_tge[1].year_end);
 
 		// check that the snapshots before and after the new year are different
 		assert_ne!(snapshots_before_tge[1], snapshots_after_tge[0]);
 
 		// check that the snapshots after the TGE are consistent with the expected values
 		assert_eq!(snapshots_after_tge[0].current_year, 1);
-		assert_eq!(
-			snapshots_after_tge[0].do_recalculation_at,
-			snapshots_before_tge[0].do_recalculation_at + BLOCKS_PER_YEAR
-		);
+		assert_eq!(snapshots_after_tge[0].year_end, Some(TGE_MOMENT + InflationYearLength::get()));
 		assert_ne!(snapshots_after_tge[0].block_rewards, snapshots_before_tge[0].block_rewards);
 	})
 }
@@ -154,15 +212,8 @@ fn stagnation_reached_as_expected_with_delayed_tge() {
 		let inflation_configuration = InflationManager::inflation_configuration();
 		let stagnation_snapshot_year = inflation_configuration.inflation_stagnation_year as usize;
 		let last_snapshot_year = stagnation_snapshot_year + 1;
-		let do_recalculation_at = InflationManager::do_recalculation_at() as u32;
 
-		let yearly_snapshots: Vec<InflationManagerSnapshot> = (0..=last_snapshot_year)
-			.map(|i| {
-				InflationManagerSnapshot::take_snapshot_at(
-					do_recalculation_at + (BLOCKS_PER_YEAR * i as u32),
-				)
-			})
-			.collect();
+		let yearly_snapshots = take_yearly_snapshots(last_snapshot_year + 1);
 
 		// verify snapshot inflation parameters - stagnation year index is (year - 1)
 		assert_eq!(
@@ -194,25 +245,16 @@ fn inflation_parameters_correctness_as_expected() {
 			Perbill::one() - inflation_configuration.inflation_parameters.disinflation_rate;
 		let inflation = inflation_configuration.inflation_parameters.inflation_rate;
 		let mut expected_yearly_inflation_parameters: Vec<InflationParametersT> = vec![];
-		let do_recalculation_at = InflationManager::do_recalculation_at() as u32;
+		let do_initialize_at = InflationManager::initialize_block();
 
 		// verify correct parameters before TGE
 		let snapshot_before_tge =
-			InflationManagerSnapshot::take_snapshot_at(do_recalculation_at - 1);
+			InflationManagerSnapshot::take_snapshot_at(do_initialize_at - 1, TGE_MOMENT - 6);
 		assert_eq!(snapshot_before_tge.inflation_parameters, InflationParametersT::default());
 		assert_eq!(snapshot_before_tge.current_year, 0);
-		assert_eq!(
-			snapshot_before_tge.do_recalculation_at as u64,
-			<TestRuntime as Config>::DoInitializeAt::get()
-		);
+		assert_eq!(snapshot_before_tge.year_end, None);
 
-		let yearly_snapshots: Vec<InflationManagerSnapshot> = (0..last_snapshot_year)
-			.map(|i| {
-				InflationManagerSnapshot::take_snapshot_at(
-					do_recalculation_at + (BLOCKS_PER_YEAR * i as u32),
-				)
-			})
-			.collect();
+		let yearly_snapshots = take_yearly_snapshots(last_snapshot_year);
 
 		for i in 0..last_snapshot_year {
 			// calculate expected inflation parameters manually
@@ -347,32 +389,42 @@ fn check_fund_enough_token_after_delayed_tge_greater() {
 #[test]
 fn recaluclation_change() {
 	ExternalityBuilder::default().build().execute_with(|| {
-		let old_recalculation_at = InflationManager::do_recalculation_at() as u32;
-		assert_ok!(InflationManager::set_recalculation_time(RawOrigin::Root.into(), 500));
-		let new_recalculation_at = InflationManager::do_recalculation_at() as u32;
+		let do_initialize_at = InflationManager::initialize_block();
+		InflationManagerSnapshot::take_snapshot_at(do_initialize_at, TGE_MOMENT);
+		let old_year_end = InflationManager::fiscal_year().unwrap().end;
+
+		assert_ok!(InflationManager::set_recalculation_time(RawOrigin::Root.into(), 600));
+		let new_year_end = InflationManager::fiscal_year().unwrap().end;
+		assert_eq!(new_year_end, 600);
+		assert!(new_year_end < old_year_end);
 
-		let no_change_snapshots: Vec<InflationManagerSnapshot> = vec![
-			InflationManagerSnapshot::take_snapshot_at(old_recalculation_at - 1),
-			InflationManagerSnapshot::take_snapshot_at(old_recalculation_at),
-		];
 		let change_snapshots: Vec<InflationManagerSnapshot> = vec![
-			InflationManagerSnapshot::take_snapshot_at(new_recalculation_at - 1),
-			InflationManagerSnapshot::take_snapshot_at(new_recalculation_at),
+			InflationManagerSnapshot::take_snapshot_at(do_initialize_at + 1, new_year_end - 6),
+			InflationManagerSnapshot::take_snapshot_at(do_initialize_at + 2, new_year_end),
 		];
 
-		assert_eq!(no_change_snapshots[0], no_change_snapshots[1]);
-		assert_eq!(no_change_snapshots[1], change_snapshots[0]);
 		assert_ne!(change_snapshots[0], change_snapshots[1]);
 		assert_eq!(change_snapshots[0].current_year + 1, change_snapshots[1].current_year);
+		assert_eq!(change_snapshots[1].year_end, Some(new_year_end + InflationYearLength::get()));
 	})
 }
 
 #[test]
 fn recaluclation_change_fail() {
 	ExternalityBuilder::default().build().execute_with(|| {
+		// the first year has not started yet
 		assert_noop!(
-			InflationManager::set_recalculation_time(RawOrigin::Root.into(), 1),
-			Error::<TestRuntime>::WrongBlockSetting
+			InflationManager::set_recalculation_time(RawOrigin::Root.into(), 5000),
+			Error::<TestRuntime>::FiscalYearUninitialized
+		);
+
+		InflationManagerSnapshot::take_snapshot_at(
+			InflationManager::initialize_block(),
+			TGE_MOMENT,
+		);
+		assert_noop!(
+			InflationManager::set_recalculation_time(RawOrigin::Root.into(), TGE_MOMENT),
+			Error::<TestRuntime>::WrongTimeSetting
 		);
 		assert_noop!(
 			InflationManager::set_recalculation_time(RuntimeOrigin::signed(1).into(), 5000),
@@ -381,12 +433,506 @@ fn recaluclation_change_fail() {
 	})
 }
 
+#[test]
+fn block_rewards_self_correct_with_slow_blocks() {
+	ExternalityBuilder::default().build().execute_with(|| {
+		let expected_block_time = InflationManager::expected_block_time() as u64;
+		// blocks take twice as long as expected during the first half of the year
+		let (blocks, issued) = simulate_year(|elapsed| {
+			if elapsed < InflationYearLength::get() / 2 {
+				2 * expected_block_time
+			} else {
+				expected_block_time
+			}
+		});
+
+		// the year ends after its length in time, not in blocks
+		assert!(blocks < InflationManager::expected_blocks_per_year() as u64);
+		assert_eq!(InflationManager::current_year(), 2);
+		assert_yearly_budget_issued(issued);
+	})
+}
+
+#[test]
+fn block_rewards_self_correct_with_variable_block_times() {
+	ExternalityBuilder::default().build().execute_with(|| {
+		// block times alternate between faster and slower than expected
+		let (_, issued) = simulate_year(|elapsed| if (elapsed / 600) % 2 == 0 { 9 } else { 4 });
+
+		assert_eq!(InflationManager::current_year(), 2);
+		assert_yearly_budget_issued(issued);
+	})
+}
+
+#[test]
+fn block_rewards_follow_expected_block_time() {
+	ExternalityBuilder::default().build().execute_with(|| {
+		let expected_block_time = InflationManager::expected_block_time() as u64;
+		let (blocks, issued) = simulate_year(|_| expected_block_time);
+
+		assert_eq!(blocks, InflationManager::expected_blocks_per_year() as u64);
+		assert_yearly_budget_issued(issued);
+	})
+}
+
+#[test]
+fn first_year_starts_with_first_timestamp() {
+	ExternalityBuilder::default().build().execute_with(|| {
+		// TGE in a block without timestamp, e.g. at genesis
+		InflationManagerSnapshot::take_snapshot_at(InflationManager::initialize_block(), 0);
+		assert_eq!(InflationManager::fiscal_year().unwrap().end, 0);
+
+		let snapshot = InflationManagerSnapshot::take_snapshot_at(
+			InflationManager::initialize_block() + 1,
+			TGE_MOMENT,
+		);
+		let fiscal_year = InflationManager::fiscal_year().unwrap();
+		assert_eq!(fiscal_year.start, TGE_MOMENT);
+		assert_eq!(snapshot.year_end, Some(TGE_MOMENT + InflationYearLength::get()));
+		assert_eq!(snapshot.current_year, 1);
+	})
+}
+
+#[test]
+fn migration_to_timestamp_based_years() {
+	ExternalityBuilder::default().build().execute_with(|| {
+		let do_recalculation_at: BlockNumber = BLOCKS_PER_YEAR as BlockNumber + 100;
+		let now: BlockNumber = 400;
+		let block_rewards: Balance = 1_000_000;
+		let moment: u64 = 1_000_000;
+
+		// state of the block based years in the middle of the first year
+		StorageVersion::new(1).put::<InflationManager>();
+		CurrentYear::<TestRuntime>::put(1);
+		BlockRewards::<TestRuntime>::put(block_rewards);
+		let do_recalculation_at_key =
+			frame_support::storage::storage_prefix(b"InflationManager", b"DoRecalculationAt");
+		unhashed::put(&do_recalculation_at_key, &do_recalculation_at);
+		System::set_block_number(now);
+		Timestamp::set_timestamp(moment);
+
+		InflationManager::on_runtime_upgrade();
+
+		assert_eq!(InflationManager::on_chain_storage_version(), STORAGE_VERSION);
+		assert!(unhashed::get::<BlockNumber>(&do_recalculation_at_key).is_none());
+		// the remaining blocks are converted to the time of a 12s block
+		let remaining =
+			InflationYearLength::get() * (do_recalculation_at - now) / BLOCKS_PER_YEAR as u64;
+		assert_eq!(
+			InflationManager::fiscal_year(),
+			Some(FiscalYearInfo {
+				start_block: 100,
+				start: moment + remaining - InflationYearLength::get(),
+				end: moment + remaining,
+				budget: block_rewards * BLOCKS_PER_YEAR as Balance,
+				issued: block_rewards * 300,
+			})
+		);
+		// the year is still running, the storage is not touched again
+		InflationManager::on_runtime_upgrade();
+		assert_eq!(InflationManager::current_year(), 1);
+	})
+}
+
+#[test]
+fn schedule_inflation_configuration_fail() {
+	ExternalityBuilder::default().build().execute_with(|| {
+		let inflation_configuration = InflationConfigurationT::default();
+		assert_noop!(
+			InflationManager::schedule_inflation_configuration(
+				RuntimeOrigin::signed(1),
+				inflation_configuration.clone(),
+				InflationConfigurationActivation::NextYear
+			),
+			BadOrigin
+		);
+
+		// stagnation must not start at year 0
+		assert_noop!(
+			InflationManager::schedule_inflation_configuration(
+				RawOrigin::Root.into(),
+				InflationConfigurationT { inflation_stagnation_year: 0, ..Default::default() },
+				InflationConfigurationActivation::NextYear
+			),
+			Error::<TestRuntime>::InvalidInflationConfiguration
+		);
+		// stagnation must not increase the inflation rate
+		assert_noop!(
+			InflationManager::schedule_inflation_configuration(
+				RawOrigin::Root.into(),
+				InflationConfigurationT {
+					inflation_stagnation_rate: Perbill::from_percent(5),
+					..Default::default()
+				},
+				InflationConfigurationActivation::NextYear
+			),
+			Error::<TestRuntime>::InvalidInflationConfiguration
+		);
+		assert_noop!(
+			InflationManager::schedule_inflation_configuration(
+				RawOrigin::Root.into(),
+				inflation_configuration,
+				InflationConfigurationActivation::AtBlock(System::block_number())
+			),
+			Error::<TestRuntime>::WrongBlockSetting
+		);
+	})
+}
+
+#[test]
+fn scheduled_configuration_activates_at_next_year() {
+	ExternalityBuilder::default().build().execute_with(|| {
+		take_yearly_snapshots(1);
+		let old_configuration = InflationManager::inflation_configuration();
+		let new_configuration = InflationConfigurationT {
+			inflation_parameters: InflationParametersT {
+				inflation_rate: Perbill::from_percent(5),
+				disinflation_rate: Perbill::from_percent(20),
+			},
+			inflation_stagnation_rate: Perbill::from_percent(2),
+			inflation_stagnation_year: 5,
+		};
+
+		assert_ok!(InflationManager::schedule_inflation_configuration(
+			RawOrigin::Root.into(),
+			new_configuration.clone(),
+			InflationConfigurationActivation::NextYear
+		));
+		System::assert_last_event(RuntimeEvent::InflationManager(
+			Event::InflationConfigurationScheduled {
+				inflation_configuration: new_configuration.clone(),
+				activation: InflationConfigurationActivation::NextYear,
+			},
+		));
+
+		// nothing changes during the current year
+		let block_number = System::block_number() + 1;
+		let year_end = InflationManager::fiscal_year().unwrap().end;
+		InflationManagerSnapshot::take_snapshot_at(block_number, year_end - 1);
+		assert_eq!(InflationManager::inflation_configuration(), old_configuration);
+		assert!(InflationManager::pending_inflation_configuration().is_some());
+
+		let snapshot = InflationManagerSnapshot::take_snapshot_at(block_number + 1, year_end);
+		assert_eq!(snapshot.current_year, 2);
+		assert_eq!(snapshot.inflation_configuration, new_configuration);
+		assert_eq!(
+			snapshot.inflation_parameters,
+			InflationParametersT {
+				inflation_rate: Perbill::from_percent(5) * Perbill::from_percent(80),
+				disinflation_rate: Perbill::from_percent(80),
+			}
+		);
+		assert_eq!(InflationManager::pending_inflation_configuration(), None);
+		System::assert_has_event(RuntimeEvent::InflationManager(
+			Event::InflationConfigurationSet { inflation_configuration: new_configuration },
+		));
+	})
+}
+
+#[test]
+fn scheduled_configuration_with_past_stagnation_year() {
+	ExternalityBuilder::default().build().execute_with(|| {
+		take_yearly_snapshots(1);
+		let new_configuration =
+			InflationConfigurationT { inflation_stagnation_year: 1, ..Default::default() };
+		assert_ok!(InflationManager::schedule_inflation_configuration(
+			RawOrigin::Root.into(),
+			new_configuration.clone(),
+			InflationConfigurationActivation::NextYear
+		));
+
+		let year_end = InflationManager::fiscal_year().unwrap().end;
+		let snapshot =
+			InflationManagerSnapshot::take_snapshot_at(System::block_number() + 1, year_end);
+		assert_eq!(snapshot.current_year, 2);
+		assert_eq!(
+			snapshot.inflation_parameters,
+			InflationParametersT {
+				inflation_rate: new_configuration.inflation_stagnation_rate,
+				disinflation_rate: Perbill::one(),
+			}
+		);
+	})
+}
+
+#[test]
+fn scheduled_configuration_activates_at_block() {
+	ExternalityBuilder::default().build().execute_with(|| {
+		take_yearly_snapshots(1);
+		let tge_block = System::block_number();
+		let new_configuration = InflationConfigurationT {
+			inflation_parameters: InflationParametersT {
+				inflation_rate: Perbill::from_perthousand(70),
+				disinflation_rate: Perbill::from_percent(10),
+			},
+			..Default::default()
+		};
+		assert_ok!(InflationManager::schedule_inflation_configuration(
+			RawOrigin::Root.into(),
+			new_configuration.clone(),
+			InflationConfigurationActivation::AtBlock(tge_block + 2)
+		));
+
+		let mut moment = TGE_MOMENT + 6;
+		InflationManagerSnapshot::take_snapshot_at(tge_block + 1, moment);
+		let block_rewards = InflationManager::block_rewards();
+		let fiscal_year = InflationManager::fiscal_year().unwrap();
+
+		// half of the year has passed at the activation
+		moment = TGE_MOMENT + InflationYearLength::get() / 2;
+		let snapshot = InflationManagerSnapshot::take_snapshot_at(tge_block + 2, moment);
+		assert_eq!(snapshot.current_year, 1);
+		assert_eq!(snapshot.inflation_configuration, new_configuration);
+		assert_eq!(
+			snapshot.inflation_parameters,
+			InflationParametersT {
+				inflation_rate: Perbill::from_perthousand(70),
+				disinflation_rate: Perbill::one(),
+			}
+		);
+		assert_eq!(InflationManager::pending_inflation_configuration(), None);
+
+		// the rest of the year follows the doubled inflation rate
+		let total_issuance = <TestRuntime as Config>::Currency::total_issuance();
+		assert_eq!(
+			InflationManager::fiscal_year().unwrap().budget,
+			fiscal_year.issued +
+				Perbill::from_percent(50) * (Perbill::from_perthousand(70) * total_issuance)
+		);
+		assert!(snapshot.block_rewards > block_rewards);
+	})
+}
+
+#[test]
+fn cancel_inflation_configuration() {
+	ExternalityBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			InflationManager::cancel_inflation_configuration(RawOrigin::Root.into()),
+			Error::<TestRuntime>::NoPendingInflationConfiguration
+		);
+
+		let old_configuration = InflationManager::inflation_configuration();
+		let new_configuration =
+			InflationConfigurationT { inflation_stagnation_year: 5, ..Default::default() };
+		assert_ok!(InflationManager::schedule_inflation_configuration(
+			RawOrigin::Root.into(),
+			new_configuration.clone(),
+			InflationConfigurationActivation::NextYear
+		));
+		assert_noop!(
+			InflationManager::cancel_inflation_configuration(RuntimeOrigin::signed(1)),
+			BadOrigin
+		);
+		assert_ok!(InflationManager::cancel_inflation_configuration(RawOrigin::Root.into()));
+		System::assert_last_event(RuntimeEvent::InflationManager(
+			Event::InflationConfigurationCancelled { inflation_configuration: new_configuration },
+		));
+		assert_eq!(InflationManager::pending_inflation_configuration(), None);
+
+		// the delayed TGE keeps the old configuration
+		take_yearly_snapshots(1);
+		assert_eq!(InflationManager::inflation_configuration(), old_configuration);
+	})
+}
+
+#[test]
+fn set_max_currency_supply_fail() {
+	ExternalityBuilder::default().build().execute_with(|| {
+		let total_issuance = <TestRuntime as Config>::Currency::total_issuance();
+		assert_noop!(
+			InflationManager::set_max_currency_supply(
+				RuntimeOrigin::signed(1),
+				Some(total_issuance + 1)
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			InflationManager::set_max_currency_supply(RawOrigin::Root.into(), Some(total_issuance)),
+			Error::<TestRuntime>::MaxCurrencySupplyTooLow
+		);
+	})
+}
+
+#[test]
+fn max_currency_supply_caps_yearly_budget() {
+	ExternalityBuilder::default().build().execute_with(|| {
+		take_yearly_snapshots(1);
+		let total_issuance = <TestRuntime as Config>::Currency::total_issuance();
+		let max_currency_supply = total_issuance + 1_000_000;
+
+		assert_ok!(InflationManager::set_max_currency_supply(
+			RawOrigin::Root.into(),
+			Some(max_currency_supply)
+		));
+		System::assert_last_event(RuntimeEvent::InflationManager(Event::MaxCurrencySupplySet {
+			max_currency_supply: Some(max_currency_supply),
+		}));
+		assert_eq!(InflationManager::remaining_supply(), Some(1_000_000));
+		assert_eq!(InflationManager::capped_issuance(2_000_000), 1_000_000);
+		// the running year does not exceed the cap
+		assert_eq!(InflationManager::fiscal_year().unwrap().budget, 1_000_000);
+
+		// the next year honours the cap as well
+		let year_end = InflationManager::fiscal_year().unwrap().end;
+		let snapshot =
+			InflationManagerSnapshot::take_snapshot_at(System::block_number() + 1, year_end);
+		assert_eq!(snapshot.current_year, 2);
+		assert_eq!(InflationManager::fiscal_year().unwrap().budget, 1_000_000);
+		assert_eq!(
+			snapshot.block_rewards,
+			1_000_000 / InflationManager::expected_blocks_per_year()
+		);
+
+		assert_ok!(InflationManager::set_max_currency_supply(RawOrigin::Root.into(), None));
+		assert_eq!(InflationManager::remaining_supply(), None);
+		assert_eq!(InflationManager::capped_issuance(2_000_000), 2_000_000);
+	})
+}
+
+#[test]
+fn staking_ratio_inflation_rate_interpolation() {
+	let rate = |ratio: u32, base: u32| {
+		adjustment::staking_ratio_inflation_rate(
+			Perbill::from_perthousand(base),
+			Perbill::from_percent(ratio),
+			TargetStakingRatio::get(),
+			MinInflationRate::get(),
+			MaxInflationRate::get(),
+		)
+	};
+
+	// the rate of the configuration applies at the target
+	assert_eq!(rate(50, 35), Perbill::from_perthousand(35));
+	// no stake at all yields the maximum, everything staked the minimum
+	assert_eq!(rate(0, 35), MaxInflationRate::get());
+	assert_eq!(rate(100, 35), MinInflationRate::get());
+	// linear in between
+	assert_eq!(rate(25, 35), Perbill::from_parts(52_500_000));
+	assert_eq!(rate(75, 35), Perbill::from_parts(22_500_000));
+	// the rate of the configuration is kept within the bounds
+	assert_eq!(rate(50, 100), MaxInflationRate::get());
+	assert_eq!(rate(50, 5), MinInflationRate::get());
+
+	// a target at the bounds of the ratio does not divide by zero
+	let rate_with_target = |ratio: u32, target: u32| {
+		adjustment::staking_ratio_inflation_rate(
+			Perbill::from_perthousand(35),
+			Perbill::from_percent(ratio),
+			Perbill::from_percent(target),
+			MinInflationRate::get(),
+			MaxInflationRate::get(),
+		)
+	};
+	assert_eq!(rate_with_target(0, 0), Perbill::from_perthousand(35));
+	assert_eq!(rate_with_target(100, 0), MinInflationRate::get());
+	assert_eq!(rate_with_target(100, 100), Perbill::from_perthousand(35));
+	assert_eq!(rate_with_target(0, 100), MaxInflationRate::get());
+}
+
+#[test]
+fn staking_ratio_mode_adjusts_yearly_inflation() {
+	ExternalityBuilder::default().build().execute_with(|| {
+		StakingRatioMode::set(true);
+		MockTotalStake::set(0);
+
+		// nothing staked, the first year starts with the maximum rate
+		let snapshots = take_yearly_snapshots(1);
+		assert_eq!(snapshots[0].inflation_parameters.inflation_rate, MaxInflationRate::get());
+		// the disinflation of the configuration is kept
+		assert_eq!(
+			snapshots[0].inflation_parameters.disinflation_rate,
+			DefaultInflationConfiguration::get().inflation_parameters.disinflation_rate
+		);
+		let total_issuance = <TestRuntime as Config>::Currency::total_issuance();
+		assert_eq!(
+			InflationManager::fiscal_year().unwrap().budget,
+			MaxInflationRate::get() * total_issuance
+		);
+
+		// everything staked, the second year falls back to the minimum rate
+		MockTotalStake::set(<TestRuntime as Config>::Currency::total_issuance());
+		let year_end = InflationManager::fiscal_year().unwrap().end;
+		let snapshot =
+			InflationManagerSnapshot::take_snapshot_at(System::block_number() + 1, year_end);
+		assert_eq!(snapshot.current_year, 2);
+		assert_eq!(snapshot.inflation_parameters.inflation_rate, MinInflationRate::get());
+
+		// at the target ratio the disinflation curve applies
+		MockTotalStake::set(
+			TargetStakingRatio::get() * <TestRuntime as Config>::Currency::total_issuance(),
+		);
+		let year_end = InflationManager::fiscal_year().unwrap().end;
+		let snapshot =
+			InflationManagerSnapshot::take_snapshot_at(System::block_number() + 1, year_end);
+		assert_eq!(snapshot.current_year, 3);
+		assert_eq!(
+			snapshot.inflation_parameters,
+			InflationManager::inflation_parameters_for_year(
+				&InflationManager::inflation_configuration(),
+				3
+			)
+		);
+	})
+}
+
+#[test]
+fn on_initialize_charges_year_start_and_activation() {
+	ExternalityBuilder::default().build().execute_with(|| {
+		let db_weight = <TestRuntime as frame_system::Config>::DbWeight::get();
+		let no_action = db_weight.reads_writes(6, 3);
+		let start_new_year = db_weight.reads_writes(6, 4);
+		let activation = db_weight.reads_writes(6, 4);
+
+		// the delayed TGE funds the pot and starts the first year
+		let tge_block = InflationManager::initialize_block();
+		assert_eq!(InflationManager::on_initialize(tge_block - 1), no_action);
+		assert_eq!(
+			InflationManager::on_initialize(tge_block),
+			no_action + db_weight.reads_writes(5, 5) + start_new_year
+		);
+		take_yearly_snapshots(1);
+
+		InflationManagerSnapshot::take_snapshot_at(tge_block + 1, TGE_MOMENT + 6);
+		assert_eq!(InflationManager::on_initialize(tge_block + 2), no_action);
+
+		// the year ends within the next block
+		let year_end = InflationManager::fiscal_year().unwrap().end;
+		InflationManagerSnapshot::take_snapshot_at(
+			tge_block + 2,
+			year_end - InflationManager::expected_block_time() as u64,
+		);
+		assert_eq!(InflationManager::on_initialize(tge_block + 3), no_action + start_new_year);
+
+		// a configuration for the next year is activated along with the new year
+		assert_ok!(InflationManager::schedule_inflation_configuration(
+			RawOrigin::Root.into(),
+			InflationConfigurationT::default(),
+			InflationConfigurationActivation::NextYear
+		));
+		assert_eq!(
+			InflationManager::on_initialize(tge_block + 3),
+			no_action + start_new_year + activation
+		);
+
+		// a configuration activated at a block is charged on its own
+		assert_ok!(InflationManager::schedule_inflation_configuration(
+			RawOrigin::Root.into(),
+			InflationConfigurationT::default(),
+			InflationConfigurationActivation::AtBlock(tge_block + 3)
+		));
+		assert_eq!(
+			InflationManager::on_initialize(tge_block + 3),
+			no_action + activation + start_new_year
+		);
+	})
+}
+
 /// Represents inflation manager storage snapshot at current block
 #[derive(PartialEq, Eq, Clone, RuntimeDebug)]
 struct InflationManagerSnapshot {
 	inflation_configuration: InflationConfigurationT,
 	inflation_parameters: InflationParametersT,
-	do_recalculation_at: BlockNumber,
+	year_end: Option<u64>,
 	current_year: u128,
 	block_rewards: Balance,
 }
@@ -396,13 +942,61 @@ impl InflationManagerSnapshot {
 		Self {
 			inflation_configuration: InflationManager::inflation_configuration(),
 			inflation_parameters: InflationManager::inflation_parameters(),
-			do_recalculation_at: InflationManager::do_recalculation_at().try_into().unwrap(),
+			year_end: InflationManager::fiscal_year().map(|fiscal_year| fiscal_year.end),
 			current_year: InflationManager::current_year(),
 			block_rewards: InflationManager::block_rewards(),
 		}
 	}
-	fn take_snapshot_at(block_number: BlockNumber) -> Self {
-		InflationManager::on_finalize(block_number.into());
+	fn take_snapshot_at(block_number: BlockNumber, moment: u64) -> Self {
+		System::set_block_number(block_number);
+		Timestamp::set_timestamp(moment);
+		InflationManager::on_finalize(block_number);
 		Self::new()
 	}
 }
+
+/// Kicks off the delayed TGE and takes a snapshot at the beginning of each year
+fn take_yearly_snapshots(years: usize) -> Vec<InflationManagerSnapshot> {
+	let mut block_number = InflationManager::initialize_block();
+	let mut snapshots = vec![InflationManagerSnapshot::take_snapshot_at(block_number, TGE_MOMENT)];
+	while snapshots.len() < years {
+		block_number += 1;
+		let year_end = InflationManager::fiscal_year().unwrap().end;
+		snapshots.push(InflationManagerSnapshot::take_snapshot_at(block_number, year_end));
+	}
+	snapshots
+}
+
+/// Kicks off the delayed TGE and produces blocks until the first year ends, the block time
+/// depends on the time elapsed since the TGE.
+/// Returns the number of blocks and the rewards issued during the year.
+fn simulate_year(block_time: impl Fn(u64) -> u64) -> (u64, Balance) {
+	let mut block_number = InflationManager::initialize_block();
+	let mut moment = TGE_MOMENT;
+	InflationManagerSnapshot::take_snapshot_at(block_number, moment);
+
+	let (mut blocks, mut issued) = (0, 0);
+	while InflationManager::current_year() == 1 {
+		block_number += 1;
+		moment += block_time(moment - TGE_MOMENT);
+		blocks += 1;
+		issued += InflationManager::block_rewards();
+		InflationManager::note_issued(InflationManager::block_rewards());
+		InflationManagerSnapshot::take_snapshot_at(block_number, moment);
+	}
+	(blocks, issued)
+}
+
+/// Checks that the issued rewards match the budget of the first year
+fn assert_yearly_budget_issued(issued: Balance) {
+	let inflation_parameters = InflationManager::inflation_parameters_for_year(
+		&InflationManager::inflation_configuration(),
+		1,
+	);
+	let budget = InflationManager::yearly_issuance_for(
+		&inflation_parameters,
+		DefaultTotalIssuanceNum::get(),
+	);
+	assert!(issued <= budget);
+	assert!(issued >= Perbill::from_percent(99) * budget);
+}
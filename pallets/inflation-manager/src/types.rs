@@ -1,5 +1,6 @@
 use crate::pallet::Config as PalletConfig;
 use frame_support::{pallet_prelude::*, traits::Currency};
+use peaq_primitives_xcm::Balance;
 
 use scale_info::TypeInfo;
 use sp_runtime::Perbill;
@@ -41,3 +42,70 @@ impl Default for InflationConfiguration {
 		}
 	}
 }
+
+impl InflationConfiguration {
+	/// Stagnation has to kick in from year 1 on and must not increase the inflation rate
+	pub fn is_valid(&self) -> bool {
+		self.inflation_stagnation_year > 0 &&
+			self.inflation_stagnation_rate <= self.inflation_parameters.inflation_rate
+	}
+}
+
+/// When a scheduled inflation configuration takes effect
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum InflationConfigurationActivation<BlockNumber> {
+	/// At the beginning of the next fiscal year
+	NextYear,
+	/// At the given block, within the current fiscal year
+	AtBlock(BlockNumber),
+}
+
+/// Inflation configuration which is waiting for its activation
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PendingInflationConfiguration<BlockNumber> {
+	pub inflation_configuration: InflationConfiguration,
+	pub activation: InflationConfigurationActivation<BlockNumber>,
+}
+
+/// Progress of the current inflation year, which ends once the block timestamp reaches `end`.
+#[derive(PartialEq, Eq, Clone, Default, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct FiscalYearInfo<BlockNumber, Moment> {
+	/// Block at which the year started
+	pub start_block: BlockNumber,
+	/// Timestamp at which the year started, zero until the first timestamp is known
+	pub start: Moment,
+	/// Timestamp at which the year ends and the inflation parameters get recalculated
+	pub end: Moment,
+	/// Tokens to issue during the year
+	pub budget: Balance,
+	/// Tokens issued so far during the year
+	pub issued: Balance,
+}
+
+/// Why tokens were moved into or out of the inflation pot
+#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum PotMovementReason {
+	/// Minted into the pot to top the total issuance up to `TotalIssuanceNum`
+	TotalIssuanceTopUp,
+	/// Transferred out of the pot by `transfer_all_pot`
+	TransferAll,
+}
+
+/// Entry of the inflation pot ledger
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PotLedgerEntry<AccountId, BlockNumber> {
+	/// Block in which the tokens were moved
+	pub block: BlockNumber,
+	/// Amount of tokens which were moved
+	pub amount: Balance,
+	/// Receiver of the tokens, the pot itself for mints
+	pub destination: AccountId,
+	pub reason: PotMovementReason,
+}
+
+/// Sums of all tokens minted into and transferred out of the inflation pot
+#[derive(PartialEq, Eq, Clone, Default, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PotLedgerTotals {
+	pub minted: Balance,
+	pub transferred: Balance,
+}
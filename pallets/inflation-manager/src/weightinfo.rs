@@ -6,4 +6,7 @@ pub trait WeightInfo {
 	fn transfer_all_pot() -> Weight;
 	fn set_delayed_tge() -> Weight;
 	fn set_recalculation_time() -> Weight;
+	fn schedule_inflation_configuration() -> Weight;
+	fn cancel_inflation_configuration() -> Weight;
+	fn set_max_currency_supply() -> Weight;
 }
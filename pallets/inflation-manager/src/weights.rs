@@ -33,39 +33,86 @@ pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
 	/// Storage: System Account (r:2 w:2)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: InflationManager PotTotals (r:1 w:1)
+	/// Proof: InflationManager PotTotals (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: InflationManager PotLedgerCount (r:1 w:1)
+	/// Proof: InflationManager PotLedgerCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: InflationManager PotLedger (r:0 w:1)
+	/// Proof: InflationManager PotLedger (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
 	fn transfer_all_pot() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `52`
-		//  Estimated: `3593`
-		// Minimum execution time: 11_421_000 picoseconds.
-		Weight::from_parts(11_692_000, 0)
-			.saturating_add(Weight::from_parts(0, 3593))
-			.saturating_add(T::DbWeight::get().reads(1))
+		//  Estimated: `6196`
+		// Minimum execution time: 19_873_000 picoseconds.
+		Weight::from_parts(20_341_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(5))
 	}
 	/// Storage: InflationManager DoInitializeAt (r:0 w:1)
 	/// Proof: InflationManager DoInitializeAt (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
 	/// Storage: InflationManager TotalIssuanceNum (r:0 w:1)
 	/// Proof: InflationManager TotalIssuanceNum (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
-	/// Storage: InflationManager DoRecalculationAt (r:0 w:1)
-	/// Proof: InflationManager DoRecalculationAt (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
 	fn set_delayed_tge() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
 		//  Estimated: `0`
-		// Minimum execution time: 19_046_000 picoseconds.
-		Weight::from_parts(19_387_000, 0)
+		// Minimum execution time: 17_324_000 picoseconds.
+		Weight::from_parts(17_718_000, 0)
 			.saturating_add(Weight::from_parts(0, 0))
-			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
-	/// Storage: InflationManager DoRecalculationAt (r:0 w:1)
-	/// Proof: InflationManager DoRecalculationAt (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: InflationManager FiscalYear (r:1 w:1)
+	/// Proof: InflationManager FiscalYear (max_values: Some(1), max_size: Some(52), added: 547, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
 	fn set_recalculation_time() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `180`
+		//  Estimated: `1537`
+		// Minimum execution time: 15_893_000 picoseconds.
+		Weight::from_parts(16_412_000, 0)
+			.saturating_add(Weight::from_parts(0, 1537))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: InflationManager PendingInflationConfiguration (r:0 w:1)
+	/// Proof: InflationManager PendingInflationConfiguration (max_values: Some(1), max_size: Some(42), added: 537, mode: MaxEncodedLen)
+	fn schedule_inflation_configuration() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
 		//  Estimated: `0`
-		// Minimum execution time: 11_642_000 picoseconds.
-		Weight::from_parts(14_347_000, 0)
+		// Minimum execution time: 14_127_000 picoseconds.
+		Weight::from_parts(14_588_000, 0)
 			.saturating_add(Weight::from_parts(0, 0))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: InflationManager PendingInflationConfiguration (r:1 w:1)
+	/// Proof: InflationManager PendingInflationConfiguration (max_values: Some(1), max_size: Some(42), added: 537, mode: MaxEncodedLen)
+	fn cancel_inflation_configuration() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `109`
+		//  Estimated: `1527`
+		// Minimum execution time: 15_312_000 picoseconds.
+		Weight::from_parts(15_780_000, 0)
+			.saturating_add(Weight::from_parts(0, 1527))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: Balances TotalIssuance (r:1 w:0)
+	/// Proof: Balances TotalIssuance (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: InflationManager FiscalYear (r:1 w:1)
+	/// Proof: InflationManager FiscalYear (max_values: Some(1), max_size: Some(52), added: 547, mode: MaxEncodedLen)
+	/// Storage: InflationManager MaxCurrencySupply (r:0 w:1)
+	/// Proof: InflationManager MaxCurrencySupply (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	fn set_max_currency_supply() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `205`
+		//  Estimated: `1537`
+		// Minimum execution time: 17_962_000 picoseconds.
+		Weight::from_parts(18_431_000, 0)
+			.saturating_add(Weight::from_parts(0, 1537))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
 }
@@ -0,0 +1,152 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2022 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+//! RPC interface for the parachain-staking pallet.
+
+use std::sync::Arc;
+
+use jsonrpsee::{
+	core::{async_trait, RpcResult},
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use parity_scale_codec::Codec;
+use serde::{de::DeserializeOwned, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+pub use parachain_staking_runtime_api::{
+	CandidateInfo, DelegationInfo, MetadataInfo, ParachainStakingApi as ParachainStakingRuntimeApi,
+	UnstakingInfo,
+};
+
+#[rpc(client, server)]
+pub trait ParachainStakingApi<BlockHash, AccountId, Balance, BlockNumber> {
+	/// Return all collator candidates ordered by their total stake.
+	#[method(name = "parachainStaking_candidates")]
+	fn candidates(
+		&self,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<CandidateInfo<AccountId, Balance>>>;
+
+	/// Return the delegations of the given account.
+	#[method(name = "parachainStaking_delegations")]
+	fn delegations(
+		&self,
+		delegator: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<DelegationInfo<AccountId, Balance>>>;
+
+	/// Return the unstaked funds of the given account which are not
+	/// unlocked yet.
+	#[method(name = "parachainStaking_unstaking")]
+	fn unstaking(
+		&self,
+		account: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<UnstakingInfo<BlockNumber, Balance>>>;
+
+	/// Return the estimated reward of the given account for the current
+	/// session.
+	#[method(name = "parachainStaking_estimatedRewardPerSession")]
+	fn estimated_reward_per_session(
+		&self,
+		account: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Balance>;
+}
+
+/// Provides RPC methods to query the state of the parachain-staking pallet.
+pub struct ParachainStaking<C, B> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> ParachainStaking<C, B> {
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+/// Error code of failed runtime calls.
+const RUNTIME_ERROR: i32 = 1;
+
+fn runtime_error(message: &str, error: impl std::fmt::Debug) -> jsonrpsee::core::Error {
+	CallError::Custom(ErrorObject::owned(RUNTIME_ERROR, message, Some(format!("{:?}", error))))
+		.into()
+}
+
+#[async_trait]
+impl<C, Block, AccountId, Balance, BlockNumber>
+	ParachainStakingApiServer<<Block as BlockT>::Hash, AccountId, Balance, BlockNumber>
+	for ParachainStaking<C, Block>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: ParachainStakingRuntimeApi<Block, AccountId, Balance, BlockNumber>,
+	AccountId: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+	Balance: Codec + Serialize + Send + Sync + 'static,
+	BlockNumber: Codec + Serialize + Send + Sync + 'static,
+{
+	fn candidates(
+		&self,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<CandidateInfo<AccountId, Balance>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.candidates(at).map_err(|e| runtime_error("Unable to query candidates.", e))
+	}
+
+	fn delegations(
+		&self,
+		delegator: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<DelegationInfo<AccountId, Balance>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.delegations(at, delegator)
+			.map_err(|e| runtime_error("Unable to query delegations.", e))
+	}
+
+	fn unstaking(
+		&self,
+		account: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<UnstakingInfo<BlockNumber, Balance>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.unstaking(at, account)
+			.map_err(|e| runtime_error("Unable to query unstaking.", e))
+	}
+
+	fn estimated_reward_per_session(
+		&self,
+		account: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Balance> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.estimated_reward_per_session(at, account)
+			.map_err(|e| runtime_error("Unable to estimate the reward.", e))
+	}
+}
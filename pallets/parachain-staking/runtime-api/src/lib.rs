@@ -0,0 +1,108 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2022 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+#![cfg_attr(not(feature = "std"), no_std)]
+// These clippy lints are disabled because the macro-generated code triggers them.
+#![allow(clippy::too_many_arguments)]
+
+use parity_scale_codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::{Permill, RuntimeDebug};
+use sp_std::vec::Vec;
+
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+/// A collator candidate together with their stake and commission.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct CandidateInfo<AccountId, Balance> {
+	/// Account id of the candidate.
+	pub id: AccountId,
+	/// The stake that the candidate put down.
+	pub stake: Balance,
+	/// The sum of the candidate's and their delegators' stake.
+	pub total: Balance,
+	/// The number of delegators backing the candidate.
+	pub delegators: u32,
+	/// Commission of the candidate.
+	pub commission: Permill,
+	/// Whether the candidate is part of the selected collators.
+	pub is_selected: bool,
+	/// Whether the candidate has requested to leave the set of candidates.
+	pub is_leaving: bool,
+	/// The metadata the candidate has set for delegators.
+	pub metadata: Option<MetadataInfo>,
+}
+
+/// The display name, website and description of a collator candidate.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct MetadataInfo {
+	/// The name under which the candidate is displayed.
+	pub display_name: Vec<u8>,
+	/// The website of the candidate.
+	pub url: Vec<u8>,
+	/// A free-form description of the candidate.
+	pub description: Vec<u8>,
+}
+
+/// A delegation of a delegator to a collator candidate.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct DelegationInfo<AccountId, Balance> {
+	/// Account id of the delegated collator candidate.
+	pub collator: AccountId,
+	/// The delegated amount.
+	pub amount: Balance,
+}
+
+/// Unstaked funds which can be unlocked at the given block.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct UnstakingInfo<BlockNumber, Balance> {
+	/// The block from which on the funds can be unlocked.
+	pub unlock_block: BlockNumber,
+	/// The unstaked amount.
+	pub amount: Balance,
+}
+
+sp_api::decl_runtime_apis! {
+	/// The API to query the state of the parachain-staking pallet.
+	pub trait ParachainStakingApi<AccountId, Balance, BlockNumber>
+	where
+		AccountId: Codec,
+		Balance: Codec,
+		BlockNumber: Codec,
+	{
+		/// Return all collator candidates ordered by their total stake.
+		fn candidates() -> Vec<CandidateInfo<AccountId, Balance>>;
+		/// Return the delegations of the given account.
+		fn delegations(delegator: AccountId) -> Vec<DelegationInfo<AccountId, Balance>>;
+		/// Return the unstaked funds of the given account which are not
+		/// unlocked yet.
+		fn unstaking(account: AccountId) -> Vec<UnstakingInfo<BlockNumber, Balance>>;
+		/// Return the estimated reward of the given account for the current
+		/// session.
+		fn estimated_reward_per_session(account: AccountId) -> Balance;
+	}
+}
@@ -0,0 +1,142 @@
+// KILT Blockchain – https://botlabs.org
+// Copyright (C) 2019-2022 BOTLabs GmbH
+
+// The KILT Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The KILT Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@botlabs.org
+
+//! Helper functions for the implementation of the `ParachainStakingApi`
+//! runtime API.
+
+use crate::{
+	types::BalanceOf, CandidatePool, Config, DelegatorState, MetadataOf, Pallet, Round,
+	TotalPendingRewards, Unstaking,
+};
+use frame_support::traits::Currency;
+use parachain_staking_runtime_api::{CandidateInfo, DelegationInfo, MetadataInfo, UnstakingInfo};
+use sp_runtime::{
+	traits::{Saturating, Zero},
+	PerThing, Perquintill, SaturatedConversion,
+};
+use sp_std::vec::Vec;
+
+impl<T: Config> Pallet<T> {
+	/// Return all collator candidates ordered by their total stake.
+	pub fn candidates() -> Vec<CandidateInfo<T::AccountId, BalanceOf<T>>> {
+		let selected = Self::selected_candidates();
+		let mut candidates: Vec<CandidateInfo<T::AccountId, BalanceOf<T>>> =
+			CandidatePool::<T>::iter_values()
+				.map(|state| CandidateInfo {
+					is_selected: selected.contains(&state.id),
+					is_leaving: state.is_leaving(),
+					metadata: MetadataOf::<T>::get(&state.id).map(|metadata| MetadataInfo {
+						display_name: metadata.display_name.into_inner(),
+						url: metadata.url.into_inner(),
+						description: metadata.description.into_inner(),
+					}),
+					delegators: state.delegators.len().saturated_into(),
+					id: state.id,
+					stake: state.stake,
+					total: state.total,
+					commission: state.commission,
+				})
+				.collect();
+		candidates.sort_by(|a, b| b.total.cmp(&a.total));
+		candidates
+	}
+
+	/// Return the delegations of the given account.
+	pub fn delegations(
+		delegator: &T::AccountId,
+	) -> Vec<DelegationInfo<T::AccountId, BalanceOf<T>>> {
+		DelegatorState::<T>::get(delegator)
+			.map(|state| {
+				state
+					.delegations
+					.into_iter()
+					.map(|stake| DelegationInfo { collator: stake.owner, amount: stake.amount })
+					.collect()
+			})
+			.unwrap_or_default()
+	}
+
+	/// Return the unstaked funds of the given account which are not
+	/// unlocked yet.
+	pub fn unstaking_chunks(
+		account: &T::AccountId,
+	) -> Vec<UnstakingInfo<T::BlockNumber, BalanceOf<T>>> {
+		Unstaking::<T>::get(account)
+			.into_iter()
+			.map(|(unlock_block, amount)| UnstakingInfo { unlock_block, amount })
+			.collect()
+	}
+
+	/// Estimate the reward of the given account for the current session.
+	///
+	/// The estimation assumes that every selected collator authors the same
+	/// number of blocks and that the pot keeps receiving funds at the same
+	/// rate for the rest of the session.
+	pub fn estimated_reward_per_session(account: &T::AccountId) -> BalanceOf<T> {
+		let collators = Self::selected_candidates();
+		if collators.is_empty() {
+			return BalanceOf::<T>::zero()
+		}
+
+		let round = Round::<T>::get();
+		let length: u32 = round.length.saturated_into();
+		let passed: u32 = frame_system::Pallet::<T>::block_number()
+			.saturating_sub(round.first)
+			.saturated_into::<u32>()
+			.max(1);
+		let block_num = length / collators.len().saturated_into::<u32>();
+
+		let pot = Self::account_id();
+		let distributable = T::Currency::free_balance(&pot)
+			.saturating_sub(T::Currency::minimum_balance())
+			.saturating_sub(TotalPendingRewards::<T>::get());
+		let issue_number = Perquintill::from_rational(passed.min(length), length)
+			.saturating_reciprocal_mul_floor(distributable);
+
+		let states: Vec<_> = collators.into_iter().filter_map(CandidatePool::<T>::get).collect();
+		let total_staking_in_session = states.iter().fold(BalanceOf::<T>::zero(), |acc, state| {
+			acc.saturating_add(T::CurrencyBalance::from(block_num).saturating_mul(state.total))
+		});
+
+		states.iter().fold(BalanceOf::<T>::zero(), |acc, state| {
+			let reward = if &state.id == account {
+				Self::get_collator_reward_per_session(
+					state,
+					block_num,
+					total_staking_in_session,
+					issue_number,
+				)
+				.amount
+			} else if (&state.delegators).into_iter().any(|stake| &stake.owner == account) {
+				Self::get_delgators_reward_per_session(
+					state,
+					block_num,
+					total_staking_in_session,
+					issue_number,
+				)
+				.into_iter()
+				.find(|reward| &reward.owner == account)
+				.map(|reward| reward.amount)
+				.unwrap_or_else(Zero::zero)
+			} else {
+				Zero::zero()
+			};
+			acc.saturating_add(reward)
+		})
+	}
+}
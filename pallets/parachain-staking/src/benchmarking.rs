@@ -18,19 +18,23 @@
 #![cfg(feature = "runtime-benchmarks")]
 
 //! Benchmarking
-use crate::{types::RoundInfo, *};
+use crate::{
+	types::{BalanceOf, Reward, RoundInfo, RoundRewardsOf},
+	*,
+};
 use frame_benchmarking::v1::{account, benchmarks, impl_benchmark_test_suite};
 use frame_support::{
 	assert_ok,
-	traits::{Currency, Get, OnInitialize},
+	traits::{Currency, EnsureOrigin, Get, OnInitialize},
+	BoundedBTreeMap, BoundedVec,
 };
 use frame_system::{Pallet as System, RawOrigin};
 use pallet_session::Pallet as Session;
 use sp_runtime::{
-	traits::{One, SaturatedConversion, StaticLookup},
-	Permill,
+	traits::{One, SaturatedConversion, StaticLookup, Zero},
+	Perbill, Percent, Permill,
 };
-use sp_std::{convert::TryInto, vec::Vec};
+use sp_std::{convert::TryInto, vec, vec::Vec};
 
 const COLLATOR_ACCOUNT_SEED: u32 = 0;
 const DELEGATOR_ACCOUNT_SEED: u32 = 1;
@@ -94,6 +98,25 @@ fn fill_delegators<T: Config>(
 	delegators
 }
 
+/// Fills the delegators of `collator` up to `MaxDelegatorsPerCollator` and
+/// lets each of them restake all of their rewards while having a full
+/// unstaking queue, which is the worst case for paying out a reward.
+fn fill_compounding_delegators<T: Config>(collator: &T::AccountId) -> Vec<T::AccountId> {
+	let delegators = fill_delegators::<T>(T::MaxDelegatorsPerCollator::get(), collator.clone(), 0);
+	for delegator in delegators.iter() {
+		T::Currency::make_free_balance_be(delegator, T::CurrencyBalance::from(u64::MAX));
+		<AutoCompound<T>>::insert(delegator, collator, Percent::from_percent(100));
+
+		let mut unstaking = BoundedBTreeMap::new();
+		for i in 1..T::MaxUnstakeRequests::get() {
+			assert_ok!(unstaking.try_insert(T::BlockNumber::from(i), T::CurrencyBalance::one()));
+		}
+		<Unstaking<T>>::insert(delegator, unstaking);
+	}
+
+	delegators
+}
+
 // fills unstake BTreeMap by unstaked many entries of 1
 fn fill_unstaking<T: Config>(
 	collator: &T::AccountId,
@@ -573,6 +596,139 @@ benchmarks! {
 		assert_eq!(<CandidatePool<T>>::get(&candidate).unwrap().commission, commission);
 	}
 
+	claim_rewards {
+		let s in 1 .. T::MaxSessionsPerClaim::get();
+		let candidates = setup_collator_candidates::<T>(T::MaxTopCandidates::get(), None);
+		let collator = candidates[0].clone();
+		let delegators = fill_compounding_delegators::<T>(&collator);
+		let delegator = delegators[0].clone();
+		let amount = T::Currency::minimum_balance();
+
+		// the claiming delegator is the last one to be found in each session
+		let rewards = RoundRewardsOf::<T> {
+			collator: Some(Reward { owner: collator.clone(), amount }),
+			delegators: delegators
+				.iter()
+				.rev()
+				.map(|owner| Reward { owner: owner.clone(), amount })
+				.collect::<Vec<_>>()
+				.try_into()
+				.unwrap(),
+		};
+		let entries = BalanceOf::<T>::from(rewards.len().saturated_into::<u32>());
+		for session in 0..s {
+			<PendingRewards<T>>::insert(&collator, session, rewards.clone());
+		}
+		<TotalPendingRewards<T>>::put(amount * entries * BalanceOf::<T>::from(s));
+		T::Currency::make_free_balance_be(
+			&Pallet::<T>::account_id(),
+			amount * (entries * BalanceOf::<T>::from(s) + BalanceOf::<T>::one()),
+		);
+	}: _(RawOrigin::Signed(delegator.clone()), T::Lookup::unlookup(collator.clone()), 0, s)
+	verify {
+		for session in 0..s {
+			let rewards = <PendingRewards<T>>::get(&collator, session).unwrap();
+			assert!(rewards.delegators.iter().all(|x| x.owner != delegator));
+		}
+		assert_eq!(
+			<TotalPendingRewards<T>>::get(),
+			amount * (entries - BalanceOf::<T>::one()) * BalanceOf::<T>::from(s)
+		);
+	}
+
+	payout_rewards {
+		let n in 1 .. T::MaxRewardPayoutsPerBlock::get();
+		let candidates = setup_collator_candidates::<T>(T::MaxTopCandidates::get(), None);
+		let collator = candidates[0].clone();
+		let delegators = fill_compounding_delegators::<T>(&collator);
+		let amount = T::Currency::minimum_balance();
+
+		// each payout restakes the reward into a delegation of a full collator
+		let rewards: Vec<Reward<T::AccountId, BalanceOf<T>>> = (0..n)
+			.map(|i| Reward { owner: delegators[i as usize % delegators.len()].clone(), amount })
+			.collect();
+		for (session, chunk) in rewards.chunks(delegators.len()).enumerate() {
+			let rewards = RoundRewardsOf::<T> {
+				collator: None,
+				delegators: chunk.to_vec().try_into().unwrap(),
+			};
+			<PendingRewards<T>>::insert(&collator, session.saturated_into::<u32>(), rewards);
+		}
+		<TotalPendingRewards<T>>::put(amount * BalanceOf::<T>::from(n));
+		T::Currency::make_free_balance_be(
+			&Pallet::<T>::account_id(),
+			amount * BalanceOf::<T>::from(n + 1),
+		);
+		let total_before = <CandidatePool<T>>::get(&collator).unwrap().total;
+	}: {
+		Pallet::<T>::payout_pending_rewards(n);
+	}
+	verify {
+		assert_eq!(<PendingRewards<T>>::iter().count(), 0);
+		assert!(<TotalPendingRewards<T>>::get().is_zero());
+		assert_eq!(
+			<CandidatePool<T>>::get(&collator).unwrap().total,
+			total_before + amount * BalanceOf::<T>::from(n)
+		);
+	}
+
+	set_auto_compound {
+		let candidates = setup_collator_candidates::<T>(1, None);
+		let collator = candidates[0].clone();
+		let delegator = fill_delegators::<T>(1, collator.clone(), 0)[0].clone();
+		let value = Percent::from_percent(50);
+	}: _(RawOrigin::Signed(delegator.clone()), T::Lookup::unlookup(collator.clone()), value)
+	verify {
+		assert_eq!(<AutoCompound<T>>::get(&delegator, &collator), value);
+	}
+
+	cancel_slash {
+		let candidates = setup_collator_candidates::<T>(1, None);
+		let collator = candidates[0].clone();
+		<PendingSlashes<T>>::insert(1, &collator, Perbill::from_percent(1));
+		let origin = T::CancelSlashOrigin::try_successful_origin().unwrap();
+	}: _<T::RuntimeOrigin>(origin, T::Lookup::unlookup(collator.clone()), 1)
+	verify {
+		assert!(!<PendingSlashes<T>>::contains_key(1, &collator));
+	}
+
+	move_delegation {
+		// we need at least 2 collators
+		let n in 2 .. T::MaxTopCandidates::get();
+		// we need at least 1 delegator
+		let m in 1 .. T::MaxDelegatorsPerCollator::get() - 1;
+
+		let candidates = setup_collator_candidates::<T>(n, None);
+		for (i, c) in candidates.iter().enumerate() {
+			fill_delegators::<T>(m, c.clone(), i.saturated_into::<u32>());
+		}
+		let from_collator = candidates[0].clone();
+		let to_collator = candidates[1].clone();
+		let amount = T::MinDelegatorStake::get();
+
+		// make sure delegator collated to collator
+		let state = <CandidatePool<T>>::get(&from_collator).unwrap();
+		let delegator = state.delegators.into_bounded_vec()[0].owner.clone();
+		// the delegator joined in this round
+		<LastDelegation<T>>::remove(&delegator);
+	}: _(RawOrigin::Signed(delegator.clone()), T::Lookup::unlookup(from_collator.clone()), T::Lookup::unlookup(to_collator.clone()), amount)
+	verify {
+		let from_state = <CandidatePool<T>>::get(&from_collator).unwrap();
+		assert!(!from_state.delegators.into_iter().any(|x| x.owner == delegator));
+		let to_state = <CandidatePool<T>>::get(&to_collator).unwrap();
+		assert!(to_state.delegators.into_iter().any(|x| x.owner == delegator));
+		assert_eq!(<DelegatorState<T>>::get(&delegator).unwrap().total, amount);
+	}
+
+	set_candidate_metadata {
+		let candidates = setup_collator_candidates::<T>(1, None);
+		let collator = candidates[0].clone();
+		let field = vec![b'a'; T::MaxMetadataLength::get() as usize];
+	}: _(RawOrigin::Signed(collator.clone()), field.clone(), field.clone(), field)
+	verify {
+		assert!(<MetadataOf<T>>::contains_key(&collator));
+	}
+
 	// [Post-launch TODO]: Activate after increasing MaxCollatorsPerDelegator to at least 2. Expected to throw otherwise.
 	// delegate_another_candidate {
 	// 	// we need at least 2 collators
@@ -127,6 +127,16 @@
 //!   candidate's total stake by the provided amount down to `MinDelegatorStake`.
 //! - `unlock_unstaked` - Attempt to unlock previously unstaked balance from any account. Succeeds
 //!   if at least one unstake call happened at least `StakeDuration` blocks ago.
+//! - `claim_rewards` - Claim the rewards recorded for the origin in past sessions which have not
+//!   been paid out by the payout cursor yet.
+//! - `set_auto_compound` - Set the share of the rewards which is automatically restaked into a
+//!   delegation.
+//! - `cancel_slash` - Cancel a slash which has been reported for a collator candidate that did not
+//!   author any block during a session. Requires `CancelSlashOrigin`.
+//! - `move_delegation` - Move (part of) a delegation from one collator candidate to another one
+//!   without unstaking it.
+//! - `set_candidate_metadata` - Set the display name, website and description of a collator
+//!   candidate which are shown to delegators.
 //!
 //! ## Genesis config
 //!
@@ -149,6 +159,7 @@ pub(crate) mod mock;
 #[cfg(test)]
 pub(crate) mod tests;
 
+mod api;
 mod migrations;
 mod set;
 pub mod types;
@@ -161,14 +172,18 @@ pub use weightinfo::WeightInfo;
 #[frame_support::pallet]
 pub mod pallet {
 
-	use core::{marker::PhantomData, ops::Mul};
+	use core::{
+		marker::PhantomData,
+		ops::{Mul, RangeInclusive},
+	};
 	use frame_support::{
 		assert_ok,
 		pallet_prelude::*,
 		storage::bounded_btree_map::BoundedBTreeMap,
 		traits::{
-			Currency, EstimateNextSessionRotation, ExistenceRequirement::KeepAlive, Get,
-			LockIdentifier, LockableCurrency, ReservableCurrency, StorageVersion, WithdrawReasons,
+			Currency, EnsureOrigin, EstimateNextSessionRotation, ExistenceRequirement::KeepAlive,
+			Get, Imbalance, LockIdentifier, LockableCurrency, OnUnbalanced, ReservableCurrency,
+			StorageVersion, WithdrawReasons,
 		},
 		BoundedVec, PalletId,
 	};
@@ -181,7 +196,7 @@ pub mod pallet {
 			AccountIdConversion, CheckedAdd, CheckedMul, CheckedSub, Convert, One,
 			SaturatedConversion, Saturating, StaticLookup, Zero,
 		},
-		Permill,
+		Perbill, Percent, Permill,
 	};
 	use sp_staking::SessionIndex;
 	use sp_std::{convert::TryInto, fmt::Debug, prelude::*};
@@ -189,8 +204,10 @@ pub mod pallet {
 	use crate::{
 		set::OrderedSet,
 		types::{
-			BalanceOf, Candidate, CandidateOf, CandidateStatus, DelegationCounter, Delegator,
-			ReplacedDelegator, Reward, RoundInfo, Stake, StakeOf, TotalStake,
+			BalanceOf, Candidate, CandidateMetadata, CandidateMetadataOf, CandidateOf,
+			CandidateStatus, DelegationCounter, Delegator, NegativeImbalanceOf, PendingCommission,
+			ReplacedDelegator, Reward, RoundInfo, RoundRewards, RoundRewardsOf, Stake, StakeOf,
+			TotalStake,
 		},
 		weightinfo::WeightInfo,
 	};
@@ -326,6 +343,67 @@ pub mod pallet {
 		#[pallet::constant]
 		type PotId: Get<PalletId>;
 
+		/// Maximum number of recorded rewards which are paid out from the pot
+		/// at the beginning of a block.
+		///
+		/// Rewards are recorded in [PendingRewards] at the end of a session
+		/// and paid out in subsequent blocks by a cursor which transfers at
+		/// most this many rewards per block. Outstanding rewards can be claimed
+		/// earlier via `claim_rewards`.
+		///
+		/// Since each payout might restake the reward of a delegator, the
+		/// weight of a single payout is that of `delegator_stake_more`.
+		#[pallet::constant]
+		type MaxRewardPayoutsPerBlock: Get<u32>;
+
+		/// Maximum number of sessions which are inspected by a single call of
+		/// `claim_rewards`.
+		#[pallet::constant]
+		type MaxSessionsPerClaim: Get<u32>;
+
+		/// The fraction of the stake which is slashed if a selected collator
+		/// did not author any block during a session.
+		///
+		/// Setting this to zero disables slashing.
+		#[pallet::constant]
+		type SlashFraction: Get<Perbill>;
+
+		/// Whether the delegators of an offending collator are slashed by the
+		/// same fraction as the collator.
+		#[pallet::constant]
+		type SlashDelegators: Get<bool>;
+
+		/// Number of sessions after the offence at which a reported slash is
+		/// applied. Until then, it can be cancelled by `CancelSlashOrigin`.
+		#[pallet::constant]
+		type SlashDeferDuration: Get<SessionIndex>;
+
+		/// Number of consecutive offences after which a collator candidate is
+		/// chilled, i.e. forced to leave the set of candidates.
+		///
+		/// Setting this to zero disables chilling. If both this and
+		/// `SlashFraction` are zero, offences are not tracked at all.
+		#[pallet::constant]
+		type MaxOffencesBeforeChill: Get<u32>;
+
+		/// Handler for the slashed funds, e.g. the treasury.
+		type Slash: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+		/// The origin which may cancel pending slashes.
+		type CancelSlashOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Number of sessions after which a commission change requested via
+		/// `set_commission` becomes effective.
+		///
+		/// Setting this to zero applies commission changes immediately.
+		#[pallet::constant]
+		type CommissionChangeDelay: Get<SessionIndex>;
+
+		/// Maximum length in bytes of each field of the metadata of a
+		/// collator candidate.
+		#[pallet::constant]
+		type MaxMetadataLength: Get<u32>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -421,6 +499,17 @@ pub mod pallet {
 		NotACollator,
 		/// The commission is too high.
 		CommissionTooHigh,
+		/// The account does not have any recorded rewards for the given
+		/// collator which have not been paid out yet.
+		NoPendingRewards,
+		/// The slash does not exist or has already been applied.
+		SlashNotFound,
+		/// The candidate cannot leave while a slash is pending for them.
+		PendingSlash,
+		/// The delegation cannot be moved to the collator it is moved from.
+		CannotMoveToSameCollator,
+		/// A field of the candidate metadata exceeds `MaxMetadataLength`.
+		MetadataTooLong,
 	}
 
 	#[pallet::event]
@@ -508,6 +597,53 @@ pub mod pallet {
 		/// The commission for a collator has been changed.
 		/// \[collator's account, new commission\]
 		CollatorCommissionChanged(T::AccountId, Permill),
+		/// The rewards of a collator and their delegators have been recorded
+		/// for a finished session and are waiting to be paid out.
+		/// \[session index, collator's account, sum of recorded rewards\]
+		RewardsRecorded(SessionIndex, T::AccountId, BalanceOf<T>),
+		/// The auto-compound percentage of a delegation has been changed.
+		/// \[delegator's account, collator's account, new percentage\]
+		AutoCompoundSet(T::AccountId, T::AccountId, Percent),
+		/// A selected collator did not author any block during a session.
+		/// \[collator's account, number of consecutive offences\]
+		OffenceReported(T::AccountId, u32),
+		/// A slash has been reported and will be applied at the end of the
+		/// given session.
+		/// \[collator's account, session index, slash fraction\]
+		SlashReported(T::AccountId, SessionIndex, Perbill),
+		/// A pending slash has been cancelled.
+		/// \[collator's account, session index\]
+		SlashCancelled(T::AccountId, SessionIndex),
+		/// An account has been slashed.
+		/// \[slashed account, slashed amount\]
+		Slashed(T::AccountId, BalanceOf<T>),
+		/// A collator candidate has been forced to leave the set of candidates
+		/// because of repeated offences.
+		/// \[collator's account\]
+		CandidateChilled(T::AccountId),
+		/// A collator has requested a commission change which becomes
+		/// effective in a later session.
+		/// \[collator's account, new commission, session from which on the
+		/// commission is used\]
+		CollatorCommissionChangeScheduled(T::AccountId, Permill, SessionIndex),
+		/// A scheduled commission change has become effective.
+		/// \[collator's account, new commission\]
+		CollatorCommissionChangeApplied(T::AccountId, Permill),
+		/// A collator candidate has set their metadata.
+		/// \[collator's account\]
+		CandidateMetadataSet(T::AccountId),
+		/// The metadata of a collator candidate has been removed.
+		/// \[collator's account\]
+		CandidateMetadataCleared(T::AccountId),
+		/// A delegator has moved (part of) their stake from one collator
+		/// candidate to another one without unstaking it.
+		/// \[delegator's account, previous collator, new collator, moved
+		/// amount\]
+		DelegationMoved(T::AccountId, T::AccountId, T::AccountId, BalanceOf<T>),
+		/// A collator candidate could not be chilled because there are only
+		/// `MinRequiredCollators` many collator candidates left.
+		/// \[collator's account\]
+		CandidateChillSkipped(T::AccountId),
 	}
 
 	#[pallet::hooks]
@@ -529,7 +665,10 @@ pub mod pallet {
 				post_weight =
 					<T as crate::pallet::Config>::WeightInfo::on_initialize_round_update();
 			}
+
+			// pay out rewards recorded in previous sessions
 			post_weight
+				.saturating_add(Self::payout_pending_rewards(T::MaxRewardPayoutsPerBlock::get()))
 		}
 
 		fn on_runtime_upgrade() -> frame_support::weights::Weight {
@@ -570,6 +709,24 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// The share of the rewards which is restaked automatically into a
+	/// delegation.
+	///
+	/// It maps from a delegator and the delegated collator to the
+	/// auto-compound percentage. Delegations without an entry are not
+	/// compounded.
+	#[pallet::storage]
+	#[pallet::getter(fn auto_compound)]
+	pub(crate) type AutoCompound<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		T::AccountId,
+		Percent,
+		ValueQuery,
+	>;
+
 	/// The staking information for a candidate.
 	///
 	/// It maps from an account to its information.
@@ -648,6 +805,67 @@ pub mod pallet {
 	#[pallet::getter(fn new_round_forced)]
 	pub(crate) type ForceNewRound<T: Config> = StorageValue<_, bool, ValueQuery>;
 
+	/// Rewards which have been recorded at the end of a session but have not
+	/// been paid out yet.
+	///
+	/// It maps from a collator and the session in which they authored blocks
+	/// to the outstanding rewards of the collator and their delegators.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_rewards)]
+	pub(crate) type PendingRewards<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		SessionIndex,
+		RoundRewardsOf<T>,
+		OptionQuery,
+	>;
+
+	/// The sum of all rewards in [PendingRewards].
+	///
+	/// These funds are still held by the pot but must not be distributed
+	/// again when recording the rewards of the next session.
+	#[pallet::storage]
+	#[pallet::getter(fn total_pending_rewards)]
+	pub(crate) type TotalPendingRewards<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+	/// The number of consecutive sessions in which a selected collator has
+	/// not authored any block.
+	#[pallet::storage]
+	#[pallet::getter(fn offences)]
+	pub(crate) type Offences<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, u32, ValueQuery>;
+
+	/// Slashes which have been reported but not applied yet.
+	///
+	/// It maps from the session at the end of which the slash is applied and
+	/// the offending collator to the slashed fraction of the stake.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_slashes)]
+	pub(crate) type PendingSlashes<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		SessionIndex,
+		Twox64Concat,
+		T::AccountId,
+		Perbill,
+		OptionQuery,
+	>;
+
+	/// Commission changes which have been requested by collator candidates
+	/// but are not effective yet.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_commission)]
+	pub(crate) type PendingCommissions<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, PendingCommission, OptionQuery>;
+
+	/// The metadata of collator candidates which is shown to delegators.
+	#[pallet::storage]
+	#[pallet::getter(fn candidate_metadata)]
+	pub(crate) type MetadataOf<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, CandidateMetadataOf<T>, OptionQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		pub stakers: GenesisStaker<T>,
@@ -1140,6 +1358,7 @@ pub mod pallet {
 			let state = CandidatePool::<T>::get(&collator).ok_or(Error::<T>::CandidateNotFound)?;
 			ensure!(state.is_leaving(), Error::<T>::NotLeaving);
 			ensure!(state.can_exit(<Round<T>>::get().current), Error::<T>::CannotLeaveYet);
+			ensure!(!Self::has_pending_slash(&collator), Error::<T>::PendingSlash);
 
 			let num_delegators = state.delegators.len().saturated_into::<u32>();
 			let total_amount = state.total;
@@ -1183,6 +1402,12 @@ pub mod pallet {
 			let mut state =
 				CandidatePool::<T>::get(&candidate).ok_or(Error::<T>::CandidateNotFound)?;
 			ensure!(state.is_leaving(), Error::<T>::NotLeaving);
+			// a candidate which was chilled after being slashed below the minimum
+			// stake has to leave
+			ensure!(
+				state.stake >= T::MinCollatorCandidateStake::get(),
+				Error::<T>::ValStakeBelowMin
+			);
 
 			// revert leaving state
 			state.revert_leaving();
@@ -1759,50 +1984,10 @@ pub mod pallet {
 			more: BalanceOf<T>,
 		) -> DispatchResultWithPostInfo {
 			let delegator = ensure_signed(origin)?;
-			ensure!(!more.is_zero(), Error::<T>::ValStakeZero);
-
 			let candidate = T::Lookup::lookup(candidate)?;
-			let mut delegations =
-				DelegatorState::<T>::get(&delegator).ok_or(Error::<T>::DelegatorNotFound)?;
-			let mut collator =
-				CandidatePool::<T>::get(&candidate).ok_or(Error::<T>::CandidateNotFound)?;
-			ensure!(!collator.is_leaving(), Error::<T>::CannotDelegateIfLeaving);
-			let delegator_total = delegations
-				.inc_delegation(candidate.clone(), more)
-				.ok_or(Error::<T>::DelegationNotFound)?;
-
-			// *** No Fail except during increase_lock beyond this point ***
-
-			// update lock
-			let unstaking_len = Self::increase_lock(&delegator, delegator_total, more)?;
-
-			let CandidateOf::<T, _> { stake: before_stake, total: before_total, .. } = collator;
-			collator.inc_delegator(delegator.clone(), more);
-			let after = collator.total;
-
-			// update top candidates and total amount at stake
-			let n = if collator.is_active() {
-				Self::update_top_candidates(
-					candidate.clone(),
-					before_stake,
-					// safe because total >= stake
-					before_total - before_stake,
-					collator.stake,
-					collator.total - collator.stake,
-				)
-			} else {
-				0u32
-			};
 
-			CandidatePool::<T>::insert(&candidate, collator);
-			DelegatorState::<T>::insert(&delegator, delegations);
+			let (n, unstaking_len) = Self::do_delegator_stake_more(delegator, candidate, more)?;
 
-			Self::deposit_event(Event::DelegatorStakedMore(
-				delegator,
-				candidate,
-				before_total,
-				after,
-			));
 			Ok(Some(<T as crate::pallet::Config>::WeightInfo::delegator_stake_more(
 				n,
 				T::MaxDelegatorsPerCollator::get(),
@@ -1928,6 +2113,14 @@ pub mod pallet {
 		/// Set the commission of the sender collator.
 		///
 		/// The dispatch origin for this call must be _Signed_ and the sender must be a collator.
+		///
+		/// The new commission becomes effective `CommissionChangeDelay`
+		/// sessions after the current one and replaces any commission change
+		/// which is still pending. If `CommissionChangeDelay` is zero, the
+		/// commission is changed immediately.
+		///
+		/// Emits `CollatorCommissionChangeScheduled` or
+		/// `CollatorCommissionChanged` if the change is immediate.
 		#[pallet::call_index(20)]
 		#[pallet::weight(<T as crate::pallet::Config>::WeightInfo::set_commission(
 		T::MaxTopCandidates::get(),
@@ -1940,11 +2133,31 @@ pub mod pallet {
 				return Err(Error::<T>::CommissionTooHigh.into())
 			}
 
+			let delay = T::CommissionChangeDelay::get();
+			if !delay.is_zero() {
+				// pending commissions are applied at session changes, thus the round
+				// index cannot be used here
+				let effective_at =
+					pallet_session::Pallet::<T>::current_index().saturating_add(delay);
+				PendingCommissions::<T>::insert(
+					&collator,
+					PendingCommission { commission, effective_at },
+				);
+
+				Self::deposit_event(Event::CollatorCommissionChangeScheduled(
+					collator,
+					commission,
+					effective_at,
+				));
+				return Ok(())
+			}
+
 			<crate::pallet::CandidatePool<T>>::mutate(&collator, |maybe_candidate| {
 				if let Some(candidate) = maybe_candidate {
 					candidate.set_commission(commission);
 				}
 			});
+			PendingCommissions::<T>::remove(&collator);
 
 			// Emit an event that the commission was updated.
 			Self::deposit_event(crate::pallet::Event::CollatorCommissionChanged(
@@ -1952,6 +2165,347 @@ pub mod pallet {
 			));
 			Ok(())
 		}
+
+		/// Claim the rewards of the origin which have been recorded for the
+		/// given collator in past sessions but not yet paid out by the payout
+		/// cursor.
+		///
+		/// Considers the reward of the origin as the collator itself as well as
+		/// their rewards as a delegator of the collator. Since the collator is
+		/// named explicitly, rewards which have been recorded before the origin
+		/// revoked or moved their delegation can still be claimed.
+		///
+		/// The sessions `first_session .. first_session + max_sessions` are
+		/// inspected, where `max_sessions` is bounded by `MaxSessionsPerClaim`.
+		/// Rewards of later sessions can be claimed by calling this again with
+		/// a later `first_session`.
+		///
+		/// Emits `Rewarded`.
+		///
+		/// # <weight>
+		/// Weight: O(S) where S is `max_sessions` bounded by
+		/// `MaxSessionsPerClaim`.
+		/// - Reads: [Origin Account], S * PendingRewards, TotalPendingRewards
+		/// - Writes: S * PendingRewards, TotalPendingRewards
+		/// # </weight>
+		#[pallet::call_index(21)]
+		#[pallet::weight(<T as crate::pallet::Config>::WeightInfo::claim_rewards(
+			(*max_sessions).min(T::MaxSessionsPerClaim::get())
+		))]
+		pub fn claim_rewards(
+			origin: OriginFor<T>,
+			collator: <T::Lookup as StaticLookup>::Source,
+			first_session: SessionIndex,
+			max_sessions: u32,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let collator = T::Lookup::lookup(collator)?;
+			let max_sessions = max_sessions.min(T::MaxSessionsPerClaim::get());
+
+			let mut amount = BalanceOf::<T>::zero();
+			for session in first_session..first_session.saturating_add(max_sessions) {
+				let mut rewards = match PendingRewards::<T>::get(&collator, session) {
+					Some(rewards) => rewards,
+					None => continue,
+				};
+				if let Some(reward) = rewards.take(&who) {
+					amount = amount.saturating_add(reward);
+					if rewards.is_empty() {
+						PendingRewards::<T>::remove(&collator, session);
+					} else {
+						PendingRewards::<T>::insert(&collator, session, rewards);
+					}
+				}
+			}
+			ensure!(!amount.is_zero(), Error::<T>::NoPendingRewards);
+
+			// *** No Fail beyond this point ***
+
+			TotalPendingRewards::<T>::mutate(|pending| *pending = pending.saturating_sub(amount));
+			Self::do_reward(&Self::account_id(), &who, &collator, amount);
+
+			Ok(().into())
+		}
+
+		/// Set the share of the rewards which is automatically restaked into
+		/// the delegation of the origin for the given collator candidate.
+		///
+		/// Setting the percentage to zero disables auto-compounding for the
+		/// delegation.
+		///
+		/// Emits `AutoCompoundSet`.
+		///
+		/// # <weight>
+		/// Weight: O(1)
+		/// - Reads: [Origin Account], DelegatorState
+		/// - Writes: AutoCompound
+		/// # </weight>
+		#[pallet::call_index(22)]
+		#[pallet::weight(<T as crate::pallet::Config>::WeightInfo::set_auto_compound())]
+		pub fn set_auto_compound(
+			origin: OriginFor<T>,
+			candidate: <T::Lookup as StaticLookup>::Source,
+			value: Percent,
+		) -> DispatchResult {
+			let delegator = ensure_signed(origin)?;
+			let candidate = T::Lookup::lookup(candidate)?;
+			let delegations =
+				DelegatorState::<T>::get(&delegator).ok_or(Error::<T>::DelegatorNotFound)?;
+			ensure!(
+				delegations.delegations.into_iter().any(|x| x.owner == candidate),
+				Error::<T>::DelegationNotFound
+			);
+
+			// *** No Fail beyond this point ***
+
+			if value.is_zero() {
+				AutoCompound::<T>::remove(&delegator, &candidate);
+			} else {
+				AutoCompound::<T>::insert(&delegator, &candidate, value);
+			}
+
+			Self::deposit_event(Event::AutoCompoundSet(delegator, candidate, value));
+			Ok(())
+		}
+
+		/// Cancel a reported slash before it is applied.
+		///
+		/// The dispatch origin must be `CancelSlashOrigin`.
+		///
+		/// Emits `SlashCancelled`.
+		///
+		/// # <weight>
+		/// Weight: O(1)
+		/// - Reads: PendingSlashes
+		/// - Writes: PendingSlashes
+		/// # </weight>
+		#[pallet::call_index(23)]
+		#[pallet::weight(<T as crate::pallet::Config>::WeightInfo::cancel_slash())]
+		pub fn cancel_slash(
+			origin: OriginFor<T>,
+			collator: <T::Lookup as StaticLookup>::Source,
+			session: SessionIndex,
+		) -> DispatchResult {
+			T::CancelSlashOrigin::ensure_origin(origin)?;
+			let collator = T::Lookup::lookup(collator)?;
+			ensure!(
+				PendingSlashes::<T>::contains_key(session, &collator),
+				Error::<T>::SlashNotFound
+			);
+
+			// *** No Fail beyond this point ***
+
+			PendingSlashes::<T>::remove(session, &collator);
+
+			Self::deposit_event(Event::SlashCancelled(collator, session));
+			Ok(())
+		}
+
+		/// Move (part of) the stake delegated to one collator candidate to
+		/// another one without going through `StakeDuration`.
+		///
+		/// The total amount staked by the delegator and hence their lock
+		/// remain unchanged. If the whole delegation is moved, the delegation
+		/// to the previous collator is removed. The delegation can be moved
+		/// away from a leaving candidate but not to one.
+		///
+		/// The same limits as for `delegate_another_candidate` apply to the
+		/// new delegation, e.g. `MaxCollatorsPerDelegator`,
+		/// `MaxDelegatorsPerCollator` and `MaxDelegationsPerRound`. If the new
+		/// collator already has `MaxDelegatorsPerCollator` many delegators,
+		/// the one with the lowest stake is replaced if the moved amount is
+		/// larger.
+		///
+		/// Emits `DelegationMoved`.
+		/// Emits `DelegationReplaced` if another delegator is kicked from the
+		/// new collator.
+		///
+		/// # <weight>
+		/// Weight: O(N + D) where N is `MaxSelectedCandidates` bounded by
+		/// `MaxTopCandidates` and D is the number of delegators for the
+		/// collators bounded by `MaxDelegatorsPerCollator`.
+		/// - Reads: [Origin Account], DelegatorState, 2 * CandidatePool, LastDelegation, Round,
+		///   TopCandidates, MaxSelectedCandidates
+		/// - Writes: DelegatorState, 2 * CandidatePool, LastDelegation, TopCandidates,
+		///   TotalCollatorStake
+		/// # </weight>
+		#[pallet::call_index(24)]
+		#[pallet::weight(<T as crate::pallet::Config>::WeightInfo::move_delegation(
+			T::MaxTopCandidates::get(),
+			T::MaxDelegatorsPerCollator::get()
+		))]
+		pub fn move_delegation(
+			origin: OriginFor<T>,
+			from_collator: <T::Lookup as StaticLookup>::Source,
+			to_collator: <T::Lookup as StaticLookup>::Source,
+			amount: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let acc = ensure_signed(origin)?;
+			let from_collator = T::Lookup::lookup(from_collator)?;
+			let to_collator = T::Lookup::lookup(to_collator)?;
+			ensure!(!amount.is_zero(), Error::<T>::ValStakeZero);
+			ensure!(from_collator != to_collator, Error::<T>::CannotMoveToSameCollator);
+
+			let mut delegator =
+				DelegatorState::<T>::get(&acc).ok_or(Error::<T>::DelegatorNotFound)?;
+			let mut from_state =
+				CandidatePool::<T>::get(&from_collator).ok_or(Error::<T>::CandidateNotFound)?;
+			let to_state =
+				CandidatePool::<T>::get(&to_collator).ok_or(Error::<T>::CandidateNotFound)?;
+			ensure!(!to_state.is_leaving(), Error::<T>::CannotDelegateIfLeaving);
+
+			// moving counts as a delegation in this round
+			let delegation_counter = Self::get_delegation_counter(&acc)?;
+
+			// decrease or remove the delegation for the previous collator
+			let CandidateOf::<T, _> { stake: from_old_stake, total: from_old_total, .. } =
+				from_state;
+			let delegated = delegator
+				.delegations
+				.linear_search(&Stake { owner: from_collator.clone(), amount: Zero::zero() })
+				.map(|i| delegator.delegations[i].amount)
+				.map_err(|_| Error::<T>::DelegationNotFound)?;
+			ensure!(amount <= delegated, Error::<T>::Underflow);
+			let moves_all = amount == delegated;
+			if moves_all {
+				delegator.rm_delegation(&from_collator);
+				from_state.delegators.remove(&Stake {
+					owner: acc.clone(),
+					// amount is irrelevant for removal
+					amount: BalanceOf::<T>::one(),
+				});
+				from_state.total = from_state.total.saturating_sub(amount);
+			} else {
+				ensure!(
+					delegated.saturating_sub(amount) >= T::MinDelegation::get(),
+					Error::<T>::DelegationBelowMin
+				);
+				delegator.dec_delegation(from_collator.clone(), amount);
+				from_state.dec_delegator(acc.clone(), amount);
+			}
+
+			// increase the delegation for the new collator or add a new one
+			let CandidateOf::<T, _> { stake: to_old_stake, total: to_old_total, .. } = to_state;
+			let (to_state, maybe_kicked_delegator) =
+				if delegator.inc_delegation(to_collator.clone(), amount).is_some() {
+					let mut state = to_state;
+					state.inc_delegator(acc.clone(), amount);
+					(state, None)
+				} else {
+					ensure!(amount >= T::MinDelegation::get(), Error::<T>::DelegationBelowMin);
+					ensure!(
+						(delegator.delegations.len().saturated_into::<u32>()) <
+							T::MaxCollatorsPerDelegator::get(),
+						Error::<T>::MaxCollatorsPerDelegatorExceeded
+					);
+					delegator
+						.add_delegation(Stake { owner: to_collator.clone(), amount })
+						.map_err(|_| Error::<T>::MaxCollatorsPerDelegatorExceeded)?;
+
+					let mut state = to_state;
+					let num_delegations_pre_insertion: u32 =
+						state.delegators.len().saturated_into();
+					let delegation = Stake { owner: acc.clone(), amount };
+					// throws if delegation insertion exceeds bounded vec limit which we will
+					// handle below in Self::do_update_delegator
+					ensure!(
+						state.delegators.try_insert(delegation.clone()).unwrap_or(true),
+						Error::<T>::DelegatorExists
+					);
+					if num_delegations_pre_insertion == T::MaxDelegatorsPerCollator::get() {
+						Self::do_update_delegator(delegation, state)?
+					} else {
+						state.total = state.total.saturating_add(amount);
+						(state, None)
+					}
+				};
+
+			// *** No Fail beyond this point ***
+
+			// update top candidates and total amount at stake
+			let mut n = 0u32;
+			if from_state.is_active() {
+				n = Self::update_top_candidates(
+					from_collator.clone(),
+					from_old_stake,
+					// safe because total >= stake
+					from_old_total - from_old_stake,
+					from_state.stake,
+					from_state.total - from_state.stake,
+				);
+			}
+			if to_state.is_active() {
+				n = n.max(Self::update_top_candidates(
+					to_collator.clone(),
+					to_old_stake,
+					// safe because total >= stake
+					to_old_total - to_old_stake,
+					to_state.stake,
+					to_state.total - to_state.stake,
+				));
+			}
+
+			CandidatePool::<T>::insert(&from_collator, from_state);
+			CandidatePool::<T>::insert(&to_collator, to_state);
+			DelegatorState::<T>::insert(&acc, delegator);
+			<LastDelegation<T>>::insert(&acc, delegation_counter);
+			if moves_all {
+				AutoCompound::<T>::remove(&acc, &from_collator);
+			}
+
+			// update or clear storage of potentially kicked delegator
+			Self::update_kicked_delegator_storage(maybe_kicked_delegator);
+
+			Self::deposit_event(Event::DelegationMoved(acc, from_collator, to_collator, amount));
+			Ok(Some(<T as crate::pallet::Config>::WeightInfo::move_delegation(
+				n,
+				T::MaxDelegatorsPerCollator::get(),
+			))
+			.into())
+		}
+
+		/// Set the metadata of the origin which is shown to delegators, i.e.
+		/// a display name, a website and a description.
+		///
+		/// The origin must be a collator candidate. Each field may contain at
+		/// most `MaxMetadataLength` bytes. Setting all fields to empty values
+		/// removes the metadata. The metadata is removed as well once the
+		/// candidate leaves or is removed from the set of candidates.
+		///
+		/// Emits `CandidateMetadataSet` or `CandidateMetadataCleared`.
+		///
+		/// # <weight>
+		/// Weight: O(1)
+		/// - Reads: [Origin Account], CandidatePool
+		/// - Writes: MetadataOf
+		/// # </weight>
+		#[pallet::call_index(25)]
+		#[pallet::weight(<T as crate::pallet::Config>::WeightInfo::set_candidate_metadata())]
+		pub fn set_candidate_metadata(
+			origin: OriginFor<T>,
+			display_name: Vec<u8>,
+			url: Vec<u8>,
+			description: Vec<u8>,
+		) -> DispatchResult {
+			let collator = ensure_signed(origin)?;
+			ensure!(CandidatePool::<T>::contains_key(&collator), Error::<T>::CandidateNotFound);
+			let metadata = CandidateMetadata {
+				display_name: display_name.try_into().map_err(|_| Error::<T>::MetadataTooLong)?,
+				url: url.try_into().map_err(|_| Error::<T>::MetadataTooLong)?,
+				description: description.try_into().map_err(|_| Error::<T>::MetadataTooLong)?,
+			};
+
+			// *** No Fail beyond this point ***
+
+			if metadata.is_empty() {
+				MetadataOf::<T>::remove(&collator);
+				Self::deposit_event(Event::CandidateMetadataCleared(collator));
+			} else {
+				MetadataOf::<T>::insert(&collator, metadata);
+				Self::deposit_event(Event::CandidateMetadataSet(collator));
+			}
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -2253,6 +2807,7 @@ pub mod pallet {
 				);
 			}
 			CandidatePool::<T>::insert(&collator, state);
+			AutoCompound::<T>::remove(&delegator, &collator);
 
 			Self::deposit_event(Event::DelegatorLeftCollator(
 				delegator,
@@ -2280,6 +2835,7 @@ pub mod pallet {
 
 			// we don't unlock immediately
 			Self::prep_unstake(&delegation.owner, delegation.amount, true)?;
+			AutoCompound::<T>::remove(&delegation.owner, collator);
 
 			// return state if not empty for later removal after all checks have passed
 			if state.delegations.is_empty() {
@@ -2523,6 +3079,7 @@ pub mod pallet {
 				// prepare unstaking of delegator
 				Self::prep_unstake(&stake.owner, stake.amount, true)?;
 				// remove delegation from delegator state
+				AutoCompound::<T>::remove(&stake.owner, collator);
 				if let Some(mut delegator) = DelegatorState::<T>::get(&stake.owner) {
 					if let Some(remaining) = delegator.rm_delegation(collator) {
 						if remaining.is_zero() {
@@ -2556,6 +3113,12 @@ pub mod pallet {
 				.map(pallet_session::Pallet::<T>::disable_index);
 
 			CandidatePool::<T>::remove(collator);
+			Offences::<T>::remove(collator);
+			Self::clear_pending_slashes(collator);
+			PendingCommissions::<T>::remove(collator);
+			if MetadataOf::<T>::take(collator).is_some() {
+				Self::deposit_event(Event::CandidateMetadataCleared(collator.clone()));
+			}
 			Ok(())
 		}
 
@@ -2614,17 +3177,105 @@ pub mod pallet {
 
 		/// Process the coinbase rewards for the production of a new block.
 		///
+		/// If `who` is a delegator of `collator` and has enabled
+		/// auto-compounding for this delegation, the corresponding share of
+		/// the reward is restaked into the delegation.
+		///
 		/// # <weight>
-		/// Weight: O(1)
-		/// - Reads: Balance
+		/// Weight: O(N + D + U) if the reward is restaked, see
+		/// `do_delegator_stake_more`, O(1) otherwise.
+		/// - Reads: Balance, AutoCompound
 		/// - Writes: Balance
 		/// # </weight>
-		fn do_reward(pot: &T::AccountId, who: &T::AccountId, reward: BalanceOf<T>) {
+		fn do_reward(
+			pot: &T::AccountId,
+			who: &T::AccountId,
+			collator: &T::AccountId,
+			reward: BalanceOf<T>,
+		) {
 			if let Ok(_success) = T::Currency::transfer(pot, who, reward, KeepAlive) {
 				Self::deposit_event(Event::Rewarded(who.clone(), reward));
+
+				if who == collator {
+					return
+				}
+				let compound = AutoCompound::<T>::get(who, collator).mul_floor(reward);
+				if !compound.is_zero() {
+					// the delegation might have been removed or the collator might be leaving in
+					// the meantime, in which case the reward stays in the free balance
+					let _ = Self::do_delegator_stake_more(who.clone(), collator.clone(), compound);
+				}
 			}
 		}
 
+		/// Increase the stake of an existing delegation of `delegator` for
+		/// `candidate` by `more`.
+		///
+		/// Returns the number of top candidates which had to be iterated and
+		/// the length of the unstaking queue of the delegator.
+		///
+		/// Emits `DelegatorStakedMore`.
+		///
+		/// # <weight>
+		/// Weight: O(N + D + U) where N is `MaxSelectedCandidates` bounded by
+		/// `MaxTopCandidates`, D the number of delegators for this collator
+		/// bounded by `MaxDelegatorsPerCollator` and U the number of locked
+		/// unstaking requests bounded by `MaxUnstakeRequests`.
+		/// - Reads: DelegatorState, TopCandidates, MaxSelectedCandidates, CandidatePool, Unstaking,
+		///   Locks
+		/// - Writes: DelegatorState, CandidatePool, TotalCollatorStake, Unstaking, Locks
+		/// # </weight>
+		fn do_delegator_stake_more(
+			delegator: T::AccountId,
+			candidate: T::AccountId,
+			more: BalanceOf<T>,
+		) -> Result<(u32, u32), DispatchError> {
+			ensure!(!more.is_zero(), Error::<T>::ValStakeZero);
+
+			let mut delegations =
+				DelegatorState::<T>::get(&delegator).ok_or(Error::<T>::DelegatorNotFound)?;
+			let mut collator =
+				CandidatePool::<T>::get(&candidate).ok_or(Error::<T>::CandidateNotFound)?;
+			ensure!(!collator.is_leaving(), Error::<T>::CannotDelegateIfLeaving);
+			let delegator_total = delegations
+				.inc_delegation(candidate.clone(), more)
+				.ok_or(Error::<T>::DelegationNotFound)?;
+
+			// *** No Fail except during increase_lock beyond this point ***
+
+			// update lock
+			let unstaking_len = Self::increase_lock(&delegator, delegator_total, more)?;
+
+			let CandidateOf::<T, _> { stake: before_stake, total: before_total, .. } = collator;
+			collator.inc_delegator(delegator.clone(), more);
+			let after = collator.total;
+
+			// update top candidates and total amount at stake
+			let n = if collator.is_active() {
+				Self::update_top_candidates(
+					candidate.clone(),
+					before_stake,
+					// safe because total >= stake
+					before_total - before_stake,
+					collator.stake,
+					collator.total - collator.stake,
+				)
+			} else {
+				0u32
+			};
+
+			CandidatePool::<T>::insert(&candidate, collator);
+			DelegatorState::<T>::insert(&delegator, delegations);
+
+			Self::deposit_event(Event::DelegatorStakedMore(
+				delegator,
+				candidate,
+				before_total,
+				after,
+			));
+			Ok((n, unstaking_len))
+		}
+
 		/// Checks whether a delegator can still delegate in this round, e.g.,
 		/// if they have not delegated MaxDelegationsPerRound many times
 		/// already in this round.
@@ -2768,56 +3419,491 @@ pub mod pallet {
 			inner.try_into().expect("Did not extend vec q.e.d.")
 		}
 
-		fn peaq_reward_mechanism_impl() {
-			let mut reads = Weight::from_parts(0, 1);
-			let mut writes = Weight::from_parts(0, 1);
+		/// Record the rewards of all collators which authored blocks in the
+		/// ending session and of their delegators in [PendingRewards].
+		///
+		/// Only the funds of the pot which are not reserved for previously
+		/// recorded rewards are distributed.
+		///
+		/// # <weight>
+		/// Weight: O(C) where C is the number of collators which authored
+		/// blocks in the ending session.
+		/// - Reads: Pot Balance, TotalPendingRewards, C * CollatorBlock, C * CandidatePool
+		/// - Writes: C * CollatorBlock, C * PendingRewards, TotalPendingRewards
+		/// # </weight>
+		fn peaq_reward_mechanism_impl(session: SessionIndex) {
+			let mut reads = 0u64;
+			let mut writes = 0u64;
 
 			let pot = Self::account_id();
 			let issue_number = T::Currency::free_balance(&pot)
 				.checked_sub(&T::Currency::minimum_balance())
-				.unwrap_or_else(Zero::zero);
+				.unwrap_or_else(Zero::zero)
+				.saturating_sub(TotalPendingRewards::<T>::get());
+			reads = reads.saturating_add(2);
 
 			let (in_reads, total_staking_in_session) = Self::get_total_collator_staking_num();
-			reads.saturating_add(in_reads);
+			reads = reads.saturating_add(in_reads.ref_time());
 
+			let mut total_recorded = BalanceOf::<T>::zero();
 			// Here we also remove the all collator block after the iteration
 			CollatorBlock::<T>::iter().drain().for_each(|(collator, block_num)| {
 				// Get the delegator's staking number
 				if let Some(state) = CandidatePool::<T>::get(collator.clone()) {
-					let now_reward = Self::get_collator_reward_per_session(
-						&state,
-						block_num,
-						total_staking_in_session,
-						issue_number,
-					);
+					let rewards = RoundRewards {
+						collator: Some(Self::get_collator_reward_per_session(
+							&state,
+							block_num,
+							total_staking_in_session,
+							issue_number,
+						)),
+						delegators: Self::get_delgators_reward_per_session(
+							&state,
+							block_num,
+							total_staking_in_session,
+							issue_number,
+						),
+					};
+					let amount = rewards.total();
+					total_recorded = total_recorded.saturating_add(amount);
+
+					PendingRewards::<T>::insert(&collator, session, rewards);
+					Self::deposit_event(Event::RewardsRecorded(session, collator, amount));
+					reads = reads.saturating_add(1);
+					writes = writes.saturating_add(1);
+				}
+				reads = reads.saturating_add(1);
+				writes = writes.saturating_add(1);
+			});
 
-					Self::do_reward(&pot, &now_reward.owner, now_reward.amount);
-					reads = reads.saturating_add(Weight::from_parts(1_u64, 0));
-					writes = writes.saturating_add(Weight::from_parts(1_u64, 0));
+			TotalPendingRewards::<T>::mutate(|pending| {
+				*pending = pending.saturating_add(total_recorded)
+			});
+			writes = writes.saturating_add(1);
 
-					let now_rewards = Self::get_delgators_reward_per_session(
-						&state,
-						block_num,
-						total_staking_in_session,
-						issue_number,
-					);
+			frame_system::Pallet::<T>::register_extra_weight_unchecked(
+				T::DbWeight::get().reads_writes(reads, writes),
+				DispatchClass::Mandatory,
+			);
+		}
 
-					let len = now_rewards.len().saturated_into::<u64>();
-					now_rewards.into_iter().for_each(|x| {
-						Self::do_reward(&pot, &x.owner, x.amount);
-					});
-					reads = reads.saturating_add(Weight::from_parts(len, 0));
-					writes = writes.saturating_add(Weight::from_parts(len, 0));
+		/// Pay out at most `limit` many rewards recorded in [PendingRewards].
+		///
+		/// Rewards of a collator are paid out before the rewards of their
+		/// delegators. Partially paid out entries are written back and continued
+		/// in the next block.
+		///
+		/// # <weight>
+		/// Weight: O(L * (N + D + U)) where L is the given limit and each
+		/// payout might restake the reward, see `do_delegator_stake_more`.
+		/// - Reads: L * PendingRewards, TotalPendingRewards, L * Balance, L * AutoCompound
+		/// - Writes: L * PendingRewards, TotalPendingRewards, L * Balance
+		/// # </weight>
+		pub(crate) fn payout_pending_rewards(limit: u32) -> Weight {
+			if limit.is_zero() {
+				return Weight::zero()
+			}
+
+			// each entry contains at least one reward, thus we never need to read more
+			// than `limit` many entries
+			let entries: Vec<(T::AccountId, SessionIndex, RoundRewardsOf<T>)> =
+				PendingRewards::<T>::iter().take(limit.saturated_into()).collect();
+			if entries.is_empty() {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let pot = Self::account_id();
+			let mut remaining = limit;
+			let mut paid = BalanceOf::<T>::zero();
+			for (collator, session, mut rewards) in entries {
+				if remaining.is_zero() {
+					break
 				}
-				reads = reads.saturating_add(Weight::from_parts(1_u64, 0));
-			});
+				while remaining > 0 {
+					if let Some(reward) = rewards.pop() {
+						Self::do_reward(&pot, &reward.owner, &collator, reward.amount);
+						paid = paid.saturating_add(reward.amount);
+						remaining -= 1;
+					} else {
+						break
+					}
+				}
+
+				if rewards.is_empty() {
+					PendingRewards::<T>::remove(&collator, session);
+				} else {
+					PendingRewards::<T>::insert(&collator, session, rewards);
+				}
+			}
+
+			TotalPendingRewards::<T>::mutate(|pending| *pending = pending.saturating_sub(paid));
+
+			<T as crate::pallet::Config>::WeightInfo::payout_rewards(
+				limit.saturating_sub(remaining),
+			)
+		}
+
+		/// Apply all scheduled commission changes which are effective from the
+		/// given session on.
+		///
+		/// # <weight>
+		/// Weight: O(P) where P is the number of pending commission changes
+		/// bounded by `MaxTopCandidates`.
+		/// - Reads: P * PendingCommissions, P * CandidatePool
+		/// - Writes: P * PendingCommissions, P * CandidatePool
+		/// # </weight>
+		fn apply_pending_commissions(session: SessionIndex) {
+			let mut reads = 0u64;
+			let mut writes = 0u64;
+
+			let due: Vec<(T::AccountId, Permill)> = PendingCommissions::<T>::iter()
+				.inspect(|_| reads = reads.saturating_add(1))
+				.filter(|(_, pending)| pending.effective_at <= session)
+				.map(|(collator, pending)| (collator, pending.commission))
+				.collect();
+
+			for (collator, commission) in due {
+				PendingCommissions::<T>::remove(&collator);
+				CandidatePool::<T>::mutate(&collator, |maybe_candidate| {
+					if let Some(candidate) = maybe_candidate {
+						candidate.set_commission(commission);
+					}
+				});
+				reads = reads.saturating_add(1);
+				writes = writes.saturating_add(2);
+
+				Self::deposit_event(Event::CollatorCommissionChangeApplied(collator, commission));
+			}
+
+			frame_system::Pallet::<T>::register_extra_weight_unchecked(
+				T::DbWeight::get().reads_writes(reads, writes),
+				DispatchClass::Mandatory,
+			);
+		}
+
+		/// Report all collators of the ending session which did not author any
+		/// block and apply the slashes which are due in this session.
+		///
+		/// A slash is recorded in [PendingSlashes] for each offence and
+		/// applied `SlashDeferDuration` sessions later. Collators reaching
+		/// `MaxOffencesBeforeChill` consecutive offences are chilled.
+		///
+		/// # <weight>
+		/// Weight: O(N * (N + D)) where N is `MaxSelectedCandidates` bounded by
+		/// `MaxTopCandidates` and D is the number of delegators of a collator
+		/// bounded by `MaxDelegatorsPerCollator`. At most one slash per
+		/// selected collator of a past session is due in the ending session.
+		/// - Reads: Validators, TopCandidates, MaxSelectedCandidates, N * CollatorBlock, N *
+		///   Offences, N * PendingSlashes
+		/// - Writes: N * Offences, 2 * N * PendingSlashes
+		/// # </weight>
+		fn handle_offences(session: SessionIndex) {
+			let mut reads = 3u64;
+			let mut writes = 0u64;
+
+			let fraction = T::SlashFraction::get();
+			let max_offences = T::MaxOffencesBeforeChill::get();
+			// offences are only tracked if they can be punished
+			let collators = if fraction.is_zero() && max_offences.is_zero() {
+				Vec::new()
+			} else {
+				Self::selected_candidates().into_inner()
+			};
+
+			let validators = pallet_session::Pallet::<T>::validators();
+			for collator in collators {
+				// only collators which were part of the ending session can be offenders
+				let was_selected =
+					<T as pallet_session::Config>::ValidatorIdOf::convert(collator.clone())
+						.map_or(false, |id| validators.contains(&id));
+				if !was_selected {
+					continue
+				}
+
+				reads = reads.saturating_add(2);
+				if CollatorBlock::<T>::contains_key(&collator) {
+					if Offences::<T>::contains_key(&collator) {
+						Offences::<T>::remove(&collator);
+						writes = writes.saturating_add(1);
+					}
+					continue
+				}
+
+				let offences = Offences::<T>::mutate(&collator, |offences| {
+					*offences = offences.saturating_add(1);
+					*offences
+				});
+				writes = writes.saturating_add(1);
+				Self::deposit_event(Event::OffenceReported(collator.clone(), offences));
+
+				if !fraction.is_zero() {
+					let apply_at = session.saturating_add(T::SlashDeferDuration::get());
+					PendingSlashes::<T>::insert(apply_at, &collator, fraction);
+					writes = writes.saturating_add(1);
+					Self::deposit_event(Event::SlashReported(collator.clone(), apply_at, fraction));
+				}
+
+				if !max_offences.is_zero() && offences >= max_offences {
+					let (chill_reads, chill_writes) = Self::chill_candidate(&collator);
+					reads = reads.saturating_add(chill_reads);
+					writes = writes.saturating_add(chill_writes);
+				}
+			}
+
+			let due: Vec<(T::AccountId, Perbill)> =
+				PendingSlashes::<T>::drain_prefix(session).collect();
+			for (collator, fraction) in due {
+				let (slash_reads, slash_writes) = Self::apply_slash(&collator, fraction);
+				reads = reads.saturating_add(slash_reads.saturating_add(1));
+				writes = writes.saturating_add(slash_writes.saturating_add(1));
+			}
 
 			frame_system::Pallet::<T>::register_extra_weight_unchecked(
-				T::DbWeight::get().reads_writes(reads.ref_time(), writes.ref_time()),
+				T::DbWeight::get().reads_writes(reads, writes),
 				DispatchClass::Mandatory,
 			);
 		}
 
+		/// Force a collator candidate to leave the set of candidates, as if
+		/// they had called `init_leave_candidates`.
+		///
+		/// Does nothing if the candidate is already leaving. If there would be
+		/// less than `MinRequiredCollators` many candidates left, the candidate
+		/// stays and `CandidateChillSkipped` is emitted instead.
+		///
+		/// Returns the number of storage reads and writes.
+		///
+		/// # <weight>
+		/// Weight: O(N) where N is `MaxSelectedCandidates` bounded by
+		/// `MaxTopCandidates`.
+		/// - Reads: CandidatePool, TopCandidates, Round, N * CandidatePool
+		/// - Writes: CandidatePool, TopCandidates, TotalCollatorStake, Offences
+		/// # </weight>
+		fn chill_candidate(collator: &T::AccountId) -> (u64, u64) {
+			let mut state = match CandidatePool::<T>::get(collator) {
+				Some(state) if !state.is_leaving() => state,
+				_ => return (1, 0),
+			};
+			let mut candidates = TopCandidates::<T>::get();
+			if candidates.len().saturated_into::<u32>() <= T::MinRequiredCollators::get() {
+				log::warn!(
+					"collator {:?} could not be chilled, too few collator candidates left",
+					collator,
+				);
+				Self::deposit_event(Event::CandidateChillSkipped(collator.clone()));
+				return (2, 0)
+			}
+
+			let now = <Round<T>>::get().current;
+			let when = now.saturating_add(T::ExitQueueDelay::get());
+			state.leave_candidates(when);
+
+			let num_collators = if candidates
+				.remove(&Stake { owner: collator.clone(), amount: state.total })
+				.is_some()
+			{
+				TopCandidates::<T>::put(candidates);
+				Self::deposit_event(Event::LeftTopCandidates(collator.clone()));
+				Self::update_total_stake().0
+			} else {
+				0u32
+			};
+			CandidatePool::<T>::insert(collator, state);
+			Offences::<T>::remove(collator);
+
+			Self::deposit_event(Event::CandidateChilled(collator.clone()));
+			Self::deposit_event(Event::CollatorScheduledExit(now, collator.clone(), when));
+			(3u64.saturating_add(num_collators.into()), 4)
+		}
+
+		/// The sessions in which a slash reported until now can still be
+		/// applied.
+		///
+		/// Slashes are applied at the end of the session in which they have
+		/// been reported plus `SlashDeferDuration`, thus all pending slashes
+		/// are due in the current session or in one of the next
+		/// `SlashDeferDuration` many sessions.
+		fn pending_slash_sessions() -> RangeInclusive<SessionIndex> {
+			let current = pallet_session::Pallet::<T>::current_index();
+			current..=current.saturating_add(T::SlashDeferDuration::get())
+		}
+
+		/// Whether a slash of the collator candidate is pending.
+		///
+		/// # <weight>
+		/// Weight: O(S) where S is `SlashDeferDuration`.
+		/// - Reads: CurrentIndex, S * PendingSlashes
+		/// # </weight>
+		fn has_pending_slash(collator: &T::AccountId) -> bool {
+			Self::pending_slash_sessions()
+				.any(|session| PendingSlashes::<T>::contains_key(session, collator))
+		}
+
+		/// Remove all pending slashes of the collator candidate.
+		///
+		/// # <weight>
+		/// Weight: O(S) where S is `SlashDeferDuration`.
+		/// - Reads: CurrentIndex, S * PendingSlashes
+		/// - Writes: S * PendingSlashes
+		/// # </weight>
+		fn clear_pending_slashes(collator: &T::AccountId) {
+			for session in Self::pending_slash_sessions() {
+				if PendingSlashes::<T>::contains_key(session, collator) {
+					PendingSlashes::<T>::remove(session, collator);
+				}
+			}
+		}
+
+		/// Slash the given fraction of the stake of a collator candidate and,
+		/// if `SlashDelegators` is set, of the stake of their delegators.
+		///
+		/// The slashed funds are removed from the staking lock and handed to
+		/// `T::Slash`. A collator whose stake falls below
+		/// `MinCollatorCandidateStake` is chilled, unless there are only
+		/// `MinRequiredCollators` many candidates left, see `chill_candidate`.
+		/// A delegation which falls below `MinDelegation` or leaves the
+		/// delegator with less than `MinDelegatorStake` is removed from the
+		/// collator.
+		///
+		/// Returns the number of storage reads and writes.
+		///
+		/// # <weight>
+		/// Weight: O(N + D) where N is `MaxSelectedCandidates` bounded by
+		/// `MaxTopCandidates` and D is the number of delegators of the
+		/// collator bounded by `MaxDelegatorsPerCollator`.
+		/// - Reads: CandidatePool, TopCandidates, (D + 1) * Locks, (D + 1) * Balance, D *
+		///   DelegatorState, D * Unstaking
+		/// - Writes: CandidatePool, TopCandidates, TotalCollatorStake, (D + 1) * Locks, (D + 1) *
+		///   Balance, D * DelegatorState, D * Unstaking, D * AutoCompound
+		/// # </weight>
+		fn apply_slash(collator: &T::AccountId, fraction: Perbill) -> (u64, u64) {
+			let mut state = match CandidatePool::<T>::get(collator) {
+				Some(state) => state,
+				None => return (1, 0),
+			};
+			let mut reads = 1u64;
+			let mut writes = 1u64;
+			let mut imbalance = NegativeImbalanceOf::<T>::zero();
+
+			let CandidateOf::<T, _> { stake: before_stake, total: before_total, .. } = state;
+			let slashed = Self::slash_staked(collator, fraction * state.stake, &mut imbalance);
+			state.stake = state.stake.saturating_sub(slashed);
+			state.total = state.total.saturating_sub(slashed);
+			reads = reads.saturating_add(2);
+			writes = writes.saturating_add(2);
+
+			if T::SlashDelegators::get() {
+				for stake in state.delegators.clone().into_iter() {
+					let slashed =
+						Self::slash_staked(&stake.owner, fraction * stake.amount, &mut imbalance);
+					if slashed.is_zero() {
+						continue
+					}
+					state.dec_delegator(stake.owner.clone(), slashed);
+					reads = reads.saturating_add(3);
+					writes = writes.saturating_add(3);
+
+					let mut delegator = match DelegatorState::<T>::get(&stake.owner) {
+						Some(delegator) => delegator,
+						None => continue,
+					};
+					delegator.dec_delegation(collator.clone(), slashed);
+					let remaining = stake.amount.saturating_sub(slashed);
+					if remaining >= T::MinDelegation::get() &&
+						delegator.total >= T::MinDelegatorStake::get()
+					{
+						DelegatorState::<T>::insert(&stake.owner, delegator);
+						continue
+					}
+
+					// the delegation fell below the minimum and is removed as if the
+					// delegator had left the collator
+					if !remaining.is_zero() &&
+						Self::prep_unstake(&stake.owner, remaining, true).is_err()
+					{
+						DelegatorState::<T>::insert(&stake.owner, delegator);
+						continue
+					}
+					state.delegators.remove(&Stake {
+						owner: stake.owner.clone(),
+						// amount is irrelevant for removal
+						amount: BalanceOf::<T>::one(),
+					});
+					state.total = state.total.saturating_sub(remaining);
+					match delegator.rm_delegation(collator) {
+						Some(total) if !total.is_zero() =>
+							DelegatorState::<T>::insert(&stake.owner, delegator),
+						_ => DelegatorState::<T>::remove(&stake.owner),
+					}
+					AutoCompound::<T>::remove(&stake.owner, collator);
+					reads = reads.saturating_add(1);
+					writes = writes.saturating_add(2);
+
+					Self::deposit_event(Event::DelegatorLeftCollator(
+						stake.owner,
+						collator.clone(),
+						remaining,
+						state.total,
+					));
+				}
+			}
+
+			// update top candidates and total amount at stake
+			if state.is_active() {
+				let n = Self::update_top_candidates(
+					collator.clone(),
+					before_stake,
+					// safe because total >= stake
+					before_total - before_stake,
+					state.stake,
+					state.total - state.stake,
+				);
+				reads = reads.saturating_add(n.into());
+				writes = writes.saturating_add(2);
+			}
+			let below_min = state.stake < T::MinCollatorCandidateStake::get();
+			CandidatePool::<T>::insert(collator, state);
+
+			// the collator cannot remain a candidate with less than the minimum stake
+			if below_min {
+				let (chill_reads, chill_writes) = Self::chill_candidate(collator);
+				reads = reads.saturating_add(chill_reads);
+				writes = writes.saturating_add(chill_writes);
+			}
+
+			T::Slash::on_unbalanced(imbalance);
+			(reads, writes)
+		}
+
+		/// Slash up to `amount` of the staked funds of `who` and reduce the
+		/// staking lock accordingly.
+		///
+		/// Returns the slashed amount which is added to `imbalance`.
+		fn slash_staked(
+			who: &T::AccountId,
+			amount: BalanceOf<T>,
+			imbalance: &mut NegativeImbalanceOf<T>,
+		) -> BalanceOf<T> {
+			if amount.is_zero() {
+				return BalanceOf::<T>::zero()
+			}
+
+			let (slashed, _) = T::Currency::slash(who, amount);
+			let slashed_amount = slashed.peek();
+			imbalance.subsume(slashed);
+
+			let locks = Locks::<T>::get(who);
+			if let Some(BalanceLock { amount: locked, .. }) =
+				locks.iter().find(|l| l.id == STAKING_ID)
+			{
+				let locked: BalanceOf<T> = locked.saturating_sub(slashed_amount.into()).into();
+				T::Currency::set_lock(STAKING_ID, who, locked, WithdrawReasons::all());
+			}
+
+			Self::deposit_event(Event::Slashed(who.clone(), slashed_amount));
+			slashed_amount
+		}
+
 		/// Get a unique, inaccessible account id from the `PotId`.
 		pub fn account_id() -> T::AccountId {
 			T::PotId::get().into_account_truncating()
@@ -2879,11 +3965,20 @@ pub mod pallet {
 		///		1.3. Calcuate the reward:
 		///			collator reward = collator reward ratio * pot balance
 		///			delegator reward = delegator reward ratio * pot balance
-		///		1.4. Transfer the reward to the collator and delegator.
+		///		1.4. Record the reward of the collator and delegator in `PendingRewards`.
 		/// 2. we need to clean up the state of the pallet.
+		/// 3. The recorded rewards are transferred by the payout cursor in `on_initialize` or
+		///    earlier by calling `claim_rewards`.
+		/// 4. Selected collators which did not author any block are reported and slashes which are
+		///    due in this session are applied.
+		/// 5. Commission changes which become effective in the next session are applied.
 		fn end_session(end_index: SessionIndex) {
 			log::debug!("new_session: {:?}", end_index);
-			Self::peaq_reward_mechanism_impl();
+			// offences have to be handled before the authored blocks are cleared
+			Self::handle_offences(end_index);
+			Self::peaq_reward_mechanism_impl(end_index);
+			// the rewards of the ending session use the previous commission
+			Self::apply_pending_commissions(end_index.saturating_add(1));
 		}
 
 		fn start_session(_start_index: SessionIndex) {
@@ -152,6 +152,14 @@ parameter_types! {
 	pub const MinDelegatorStake: Balance = 5;
 	pub const MinDelegation: Balance = 3;
 	pub const MaxUnstakeRequests: u32 = 6;
+	pub static MaxRewardPayoutsPerBlock: u32 = 64;
+	pub const MaxSessionsPerClaim: u32 = 8;
+	pub static SlashFraction: Perbill = Perbill::zero();
+	pub static SlashDelegators: bool = false;
+	pub static SlashDeferDuration: u32 = 0;
+	pub static MaxOffencesBeforeChill: u32 = 0;
+	pub static CommissionChangeDelay: u32 = 0;
+	pub const MaxMetadataLength: u32 = 32;
 }
 
 impl Config for Test {
@@ -174,6 +182,16 @@ impl Config for Test {
 	type MinDelegation = MinDelegation;
 	type MaxUnstakeRequests = MaxUnstakeRequests;
 	type PotId = PotId;
+	type MaxRewardPayoutsPerBlock = MaxRewardPayoutsPerBlock;
+	type MaxSessionsPerClaim = MaxSessionsPerClaim;
+	type SlashFraction = SlashFraction;
+	type SlashDelegators = SlashDelegators;
+	type SlashDeferDuration = SlashDeferDuration;
+	type MaxOffencesBeforeChill = MaxOffencesBeforeChill;
+	type Slash = ();
+	type CancelSlashOrigin = frame_system::EnsureRoot<AccountId>;
+	type CommissionChangeDelay = CommissionChangeDelay;
+	type MaxMetadataLength = MaxMetadataLength;
 	type WeightInfo = crate::weights::WeightInfo<Test>;
 }
 
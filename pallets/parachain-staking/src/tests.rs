@@ -27,21 +27,27 @@ use frame_support::{
 use frame_system::RawOrigin;
 use pallet_balances::{BalanceLock, Error as BalancesError, Reasons};
 use pallet_session::{SessionManager, ShouldEndSession};
-use sp_runtime::{traits::Zero, Perbill, Permill, Perquintill, SaturatedConversion};
+use parachain_staking_runtime_api::{DelegationInfo, UnstakingInfo};
+use sp_runtime::{
+	traits::{BadOrigin, One, Zero},
+	Perbill, Percent, Permill, Perquintill, SaturatedConversion,
+};
+use sp_staking::SessionIndex;
 
 use crate::{
 	mock::{
 		almost_equal, events, last_event, roll_to, AccountId, Balance, Balances, BlockNumber,
-		ExtBuilder, RuntimeEvent as MetaEvent, RuntimeOrigin, Session, StakePallet, System, Test,
-		BLOCKS_PER_ROUND, BLOCK_REWARD_IN_GENESIS_SESSION, BLOCK_REWARD_IN_NORMAL_SESSION,
-		DECIMALS,
+		CommissionChangeDelay, ExtBuilder, MaxOffencesBeforeChill, MaxRewardPayoutsPerBlock,
+		MinCollators, RuntimeEvent as MetaEvent, RuntimeOrigin, Session, SlashDeferDuration,
+		SlashDelegators, SlashFraction, StakePallet, System, Test, BLOCKS_PER_ROUND,
+		BLOCK_REWARD_IN_GENESIS_SESSION, BLOCK_REWARD_IN_NORMAL_SESSION, DECIMALS,
 	},
 	set::OrderedSet,
 	types::{
-		BalanceOf, Candidate, CandidateStatus, DelegationCounter, Delegator, Reward, RoundInfo,
-		Stake, StakeOf, TotalStake,
+		BalanceOf, Candidate, CandidateStatus, DelegationCounter, Delegator, PendingCommission,
+		Reward, RoundInfo, Stake, StakeOf, TotalStake,
 	},
-	CandidatePool, Config, Error, Event, STAKING_ID,
+	AutoCompound, CandidatePool, Config, Error, Event, PendingRewards, Round, STAKING_ID,
 };
 
 #[test]
@@ -1280,7 +1286,7 @@ fn delegators_bond() {
 				Error::<Test>::Underflow
 			);
 			assert_noop!(
-				StakePallet::delegator_stake_less(RuntimeOrigin::signed(6), 1, 8),
+				StakePallet::delegator_stake_less(RuntimeOrigin::signed(6), 1, 0, 8),
 				Error::<Test>::DelegationBelowMin
 			);
 			assert_noop!(
@@ -3727,7 +3733,7 @@ fn delegated_funds_less_than_min_delegator_stake() {
 			assert_ok!(StakePallet::delegate_another_candidate(RuntimeOrigin::signed(6), 4, 7));
 			// reduce the 6 delegated funds of collator owned by `1`.
 			assert_noop!(
-				StakePallet::delegator_stake_less(RuntimeOrigin::signed(6), 1, 8),
+				StakePallet::delegator_stake_less(RuntimeOrigin::signed(6), 1, 0, 8),
 				Error::<Test>::DelegationBelowMin
 			);
 		});
@@ -3791,3 +3797,814 @@ fn collator_reward_per_session_with_delegator_and_commission() {
 			assert_eq!(rewards[1], Reward { owner: 3, amount: 0 });
 		});
 }
+
+#[test]
+fn rewards_are_paid_out_over_multiple_blocks() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 1000), (2, 1000), (3, 1000), (4, 1000), (5, 1000)])
+		.with_collators(vec![(1, 500), (2, 100)])
+		.with_delegators(vec![(3, 1, 600), (4, 1, 400), (5, 2, 200)])
+		.build()
+		.execute_with(|| {
+			MaxRewardPayoutsPerBlock::set(2);
+			let authors: Vec<Option<AccountId>> =
+				vec![None, Some(1u64), Some(1u64), Some(2u64), Some(2u64)];
+
+			// both collators authored two blocks in the genesis session
+			let total_staking = 2 * (500 + 600 + 400) + 2 * (100 + 200);
+			let mut expected = Vec::new();
+			for collator in [1u64, 2u64] {
+				let state = CandidatePool::<Test>::get(collator).unwrap();
+				expected.push(StakePallet::get_collator_reward_per_session(
+					&state,
+					2,
+					total_staking,
+					BLOCK_REWARD_IN_GENESIS_SESSION,
+				));
+				expected.extend(StakePallet::get_delgators_reward_per_session(
+					&state,
+					2,
+					total_staking,
+					BLOCK_REWARD_IN_GENESIS_SESSION,
+				));
+			}
+			let expected_total: Balance = expected.iter().map(|r| r.amount).sum();
+
+			// rewards are recorded and the first two are paid out immediately
+			roll_to(5, authors);
+			let recorded: Vec<Event<Test>> = events()
+				.into_iter()
+				.filter(|e| matches!(e, Event::RewardsRecorded(..)))
+				.collect();
+			assert_eq!(recorded.len(), 2);
+			let remaining: usize = PendingRewards::<Test>::iter().map(|(_, _, r)| r.len()).sum();
+			assert_eq!(remaining, expected.len() - 2);
+			let pending_total: Balance =
+				PendingRewards::<Test>::iter().map(|(_, _, r)| r.total()).sum();
+			assert_eq!(StakePallet::total_pending_rewards(), pending_total);
+			assert!(pending_total < expected_total);
+
+			// the remaining rewards are paid out in the next blocks
+			roll_to(6, vec![]);
+			let remaining: usize = PendingRewards::<Test>::iter().map(|(_, _, r)| r.len()).sum();
+			assert_eq!(remaining, expected.len() - 4);
+			roll_to(7, vec![]);
+			assert_eq!(PendingRewards::<Test>::iter().count(), 0);
+			assert_eq!(StakePallet::total_pending_rewards(), Balance::zero());
+
+			for reward in expected {
+				assert_eq!(Balances::free_balance(reward.owner), 1000 + reward.amount);
+			}
+			assert_eq!(
+				Balances::free_balance(StakePallet::account_id()),
+				BLOCK_REWARD_IN_GENESIS_SESSION + Balances::minimum_balance() - expected_total
+			);
+		});
+}
+
+#[test]
+fn claim_rewards() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 1000), (2, 1000), (3, 1000), (4, 1000), (5, 1000), (6, 1000)])
+		.with_collators(vec![(1, 500), (2, 100)])
+		.with_delegators(vec![(3, 1, 600), (4, 1, 400), (5, 2, 200)])
+		.build()
+		.execute_with(|| {
+			// disable the payout cursor
+			MaxRewardPayoutsPerBlock::set(0);
+			let authors: Vec<Option<AccountId>> =
+				vec![None, Some(1u64), Some(1u64), Some(2u64), Some(2u64)];
+			let total_staking = 2 * (500 + 600 + 400) + 2 * (100 + 200);
+			let state = CandidatePool::<Test>::get(1).unwrap();
+			let collator_reward = StakePallet::get_collator_reward_per_session(
+				&state,
+				2,
+				total_staking,
+				BLOCK_REWARD_IN_GENESIS_SESSION,
+			);
+			let delegator_rewards = StakePallet::get_delgators_reward_per_session(
+				&state,
+				2,
+				total_staking,
+				BLOCK_REWARD_IN_GENESIS_SESSION,
+			);
+			assert_eq!(delegator_rewards[0].owner, 3);
+
+			roll_to(5, authors);
+			assert_eq!(PendingRewards::<Test>::iter().count(), 2);
+			let pending_total = StakePallet::total_pending_rewards();
+			assert!(!pending_total.is_zero());
+			assert_eq!(Balances::free_balance(3), 1000);
+
+			// delegator claims their share of collator 1
+			assert_ok!(StakePallet::claim_rewards(RuntimeOrigin::signed(3), 1, 0, 8));
+			assert_eq!(Balances::free_balance(3), 1000 + delegator_rewards[0].amount);
+			assert_eq!(
+				last_event(),
+				MetaEvent::StakePallet(Event::Rewarded(3, delegator_rewards[0].amount))
+			);
+			assert_noop!(
+				StakePallet::claim_rewards(RuntimeOrigin::signed(3), 1, 0, 8),
+				Error::<Test>::NoPendingRewards
+			);
+
+			// collator claims their own reward
+			assert_ok!(StakePallet::claim_rewards(RuntimeOrigin::signed(1), 1, 0, 8));
+			assert_eq!(Balances::free_balance(1), 1000 + collator_reward.amount);
+			assert_eq!(
+				StakePallet::total_pending_rewards(),
+				pending_total - collator_reward.amount - delegator_rewards[0].amount
+			);
+			assert_eq!(StakePallet::pending_rewards(1, 0).map(|r| r.len()), Some(1));
+
+			// accounts without stake have nothing to claim
+			assert_noop!(
+				StakePallet::claim_rewards(RuntimeOrigin::signed(6), 1, 0, 8),
+				Error::<Test>::NoPendingRewards
+			);
+		});
+}
+
+#[test]
+fn claim_rewards_after_revoking_delegation() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 1000), (2, 1000), (3, 1000)])
+		.with_collators(vec![(1, 500), (2, 500)])
+		.with_delegators(vec![(3, 1, 500)])
+		.build()
+		.execute_with(|| {
+			// disable the payout cursor
+			MaxRewardPayoutsPerBlock::set(0);
+			let authors: Vec<Option<AccountId>> = (0u64..15).map(|_| Some(1u64)).collect();
+			let sessions_with_rewards_of = |who: AccountId| {
+				PendingRewards::<Test>::iter_prefix(1)
+					.filter(|(_, rewards)| rewards.delegators.iter().any(|x| x.owner == who))
+					.count()
+			};
+
+			// rewards are recorded for two sessions
+			roll_to(10, authors);
+			assert_eq!(sessions_with_rewards_of(3), 2);
+
+			assert_ok!(StakePallet::leave_delegators(RuntimeOrigin::signed(3)));
+			assert!(StakePallet::delegator_state(3).is_none());
+
+			// nothing was recorded for the delegator at collator 2
+			assert_noop!(
+				StakePallet::claim_rewards(RuntimeOrigin::signed(3), 2, 0, 8),
+				Error::<Test>::NoPendingRewards
+			);
+
+			let balance = Balances::free_balance(3);
+			assert_ok!(StakePallet::claim_rewards(RuntimeOrigin::signed(3), 1, 0, 8));
+			assert!(Balances::free_balance(3) > balance);
+			assert_eq!(sessions_with_rewards_of(3), 0);
+			assert_noop!(
+				StakePallet::claim_rewards(RuntimeOrigin::signed(3), 1, 0, 8),
+				Error::<Test>::NoPendingRewards
+			);
+		});
+}
+
+#[test]
+fn claim_rewards_of_later_sessions() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 1000), (2, 1000), (3, 1000), (4, 1000)])
+		.with_collators(vec![(1, 500), (2, 500)])
+		.with_delegators(vec![(3, 1, 500), (4, 1, 500)])
+		.build()
+		.execute_with(|| {
+			// disable the payout cursor
+			MaxRewardPayoutsPerBlock::set(0);
+			let authors: Vec<Option<AccountId>> = (0u64..25).map(|_| Some(1u64)).collect();
+
+			// rewards are recorded for several sessions, shared with delegator 4
+			roll_to(20, authors);
+			let mut sessions: Vec<SessionIndex> =
+				PendingRewards::<Test>::iter_prefix(1).map(|(session, _)| session).collect();
+			sessions.sort();
+			assert!(sessions.len() > 2);
+
+			// each call only inspects a single session, later sessions stay reachable
+			for session in sessions.iter() {
+				let balance = Balances::free_balance(3);
+				assert_ok!(StakePallet::claim_rewards(RuntimeOrigin::signed(3), 1, *session, 1));
+				assert!(Balances::free_balance(3) > balance);
+				assert_noop!(
+					StakePallet::claim_rewards(RuntimeOrigin::signed(3), 1, *session, 1),
+					Error::<Test>::NoPendingRewards
+				);
+				// the rewards of delegator 4 are left untouched
+				assert!(StakePallet::pending_rewards(1, session)
+					.unwrap()
+					.delegators
+					.iter()
+					.any(|x| x.owner == 4));
+			}
+
+			// sessions before the inspected range are not claimed
+			let balance = Balances::free_balance(4);
+			let last_session = *sessions.last().unwrap();
+			assert_ok!(StakePallet::claim_rewards(RuntimeOrigin::signed(4), 1, last_session, 1));
+			assert!(Balances::free_balance(4) > balance);
+			assert!(sessions[..sessions.len() - 1]
+				.iter()
+				.all(|session| StakePallet::pending_rewards(1, session).is_some()));
+		});
+}
+
+#[test]
+fn pending_rewards_are_not_distributed_twice() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 1000), (2, 1000), (3, 1000)])
+		.with_collators(vec![(1, 500), (2, 500)])
+		.with_delegators(vec![(3, 1, 500)])
+		.build()
+		.execute_with(|| {
+			// disable the payout cursor
+			MaxRewardPayoutsPerBlock::set(0);
+			let authors: Vec<Option<AccountId>> = (0u64..10).map(|i| Some(i % 2 + 1)).collect();
+
+			roll_to(5, authors.clone());
+			assert!(almost_equal(
+				BLOCK_REWARD_IN_GENESIS_SESSION,
+				StakePallet::total_pending_rewards(),
+				Perbill::from_perthousand(1)
+			));
+
+			// only the new block rewards are distributed in the second session
+			roll_to(10, authors);
+			assert!(almost_equal(
+				BLOCK_REWARD_IN_GENESIS_SESSION + BLOCK_REWARD_IN_NORMAL_SESSION,
+				StakePallet::total_pending_rewards(),
+				Perbill::from_perthousand(1)
+			));
+			assert!(
+				StakePallet::total_pending_rewards() <=
+					Balances::free_balance(StakePallet::account_id())
+			);
+
+			// enabling the cursor pays out everything
+			MaxRewardPayoutsPerBlock::set(64);
+			roll_to(11, vec![]);
+			assert_eq!(StakePallet::total_pending_rewards(), Balance::zero());
+			assert_eq!(PendingRewards::<Test>::iter().count(), 0);
+		});
+}
+
+#[test]
+fn set_auto_compound() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 1000), (2, 1000), (3, 1000), (4, 1000)])
+		.with_collators(vec![(1, 500), (2, 500)])
+		.with_delegators(vec![(3, 1, 500)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				StakePallet::set_auto_compound(RuntimeOrigin::signed(4), 1, Percent::one()),
+				Error::<Test>::DelegatorNotFound
+			);
+			assert_noop!(
+				StakePallet::set_auto_compound(RuntimeOrigin::signed(3), 2, Percent::one()),
+				Error::<Test>::DelegationNotFound
+			);
+
+			assert_ok!(StakePallet::set_auto_compound(
+				RuntimeOrigin::signed(3),
+				1,
+				Percent::from_percent(40)
+			));
+			assert_eq!(StakePallet::auto_compound(3, 1), Percent::from_percent(40));
+			assert_eq!(
+				last_event(),
+				MetaEvent::StakePallet(Event::AutoCompoundSet(3, 1, Percent::from_percent(40)))
+			);
+
+			// setting zero removes the entry
+			assert_ok!(StakePallet::set_auto_compound(
+				RuntimeOrigin::signed(3),
+				1,
+				Percent::zero()
+			));
+			assert!(!AutoCompound::<Test>::contains_key(3, 1));
+
+			// leaving removes the entry
+			assert_ok!(StakePallet::set_auto_compound(RuntimeOrigin::signed(3), 1, Percent::one()));
+			assert_ok!(StakePallet::leave_delegators(RuntimeOrigin::signed(3)));
+			assert!(!AutoCompound::<Test>::contains_key(3, 1));
+		});
+}
+
+#[test]
+fn auto_compound_restakes_rewards() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 1000), (2, 1000), (3, 1000), (4, 1000)])
+		.with_collators(vec![(1, 500), (2, 500)])
+		.with_delegators(vec![(3, 1, 500), (4, 2, 500)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(StakePallet::set_auto_compound(
+				RuntimeOrigin::signed(3),
+				1,
+				Percent::from_percent(50)
+			));
+			let authors: Vec<Option<AccountId>> =
+				vec![None, Some(1u64), Some(1u64), Some(2u64), Some(2u64)];
+			let state = CandidatePool::<Test>::get(1).unwrap();
+			let delegator_rewards = StakePallet::get_delgators_reward_per_session(
+				&state,
+				2,
+				2 * (500 + 500) + 2 * (500 + 500),
+				BLOCK_REWARD_IN_GENESIS_SESSION,
+			);
+			let reward = delegator_rewards[0].amount;
+			let compound = Percent::from_percent(50).mul_floor(reward);
+			assert!(!compound.is_zero());
+
+			roll_to(5, authors);
+			assert_eq!(PendingRewards::<Test>::iter().count(), 0);
+
+			// the compounded share is staked, the rest is free to use
+			assert_eq!(Balances::free_balance(3), 1000 + reward);
+			assert_eq!(Balances::usable_balance(3), 500 + reward - compound);
+			assert_eq!(StakePallet::delegator_state(3).unwrap().total, 500 + compound);
+			assert_eq!(CandidatePool::<Test>::get(1).unwrap().total, 1000 + compound);
+			assert!(events().contains(&Event::DelegatorStakedMore(3, 1, 1000, 1000 + compound)));
+
+			// collator 1 now has more stake than collator 2
+			assert_eq!(StakePallet::top_candidates().into_bounded_vec()[0].owner, 1);
+
+			// delegators without auto-compound are not affected
+			assert_eq!(StakePallet::delegator_state(4).unwrap().total, 500);
+		});
+}
+
+#[test]
+fn collator_without_blocks_is_slashed() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 1000), (2, 1000), (3, 1000), (4, 1000)])
+		.with_collators(vec![(1, 500), (2, 500)])
+		.with_delegators(vec![(3, 1, 100), (4, 2, 200)])
+		.build()
+		.execute_with(|| {
+			SlashFraction::set(Perbill::from_percent(10));
+			SlashDelegators::set(true);
+			let authors: Vec<Option<AccountId>> =
+				vec![None, Some(1u64), Some(1u64), Some(1u64), Some(1u64)];
+
+			roll_to(5, authors);
+			assert!(events().contains(&Event::OffenceReported(2, 1)));
+			assert!(events().contains(&Event::SlashReported(2, 0, Perbill::from_percent(10))));
+			assert!(events().contains(&Event::Slashed(2, 50)));
+			assert!(events().contains(&Event::Slashed(4, 20)));
+			assert_eq!(StakePallet::offences(1), 0);
+			assert_eq!(StakePallet::offences(2), 1);
+			assert!(StakePallet::pending_slashes(0, 2).is_none());
+
+			// the stake and the lock of the collator are reduced
+			let state = CandidatePool::<Test>::get(2).unwrap();
+			assert_eq!(state.stake, 450);
+			assert_eq!(state.total, 450 + 180);
+			assert_eq!(Balances::free_balance(2), 950);
+			assert_eq!(
+				Balances::locks(2),
+				vec![BalanceLock { id: STAKING_ID, amount: 450, reasons: Reasons::All }]
+			);
+
+			// so are the ones of the delegator
+			assert_eq!(StakePallet::delegator_state(4).unwrap().total, 180);
+			assert_eq!(Balances::free_balance(4), 980);
+			assert_eq!(
+				Balances::locks(4),
+				vec![BalanceLock { id: STAKING_ID, amount: 180, reasons: Reasons::All }]
+			);
+			assert_eq!(
+				StakePallet::total_collator_stake(),
+				TotalStake { collators: 950, delegators: 280 }
+			);
+
+			// the authoring collator and their delegator are not affected
+			assert_eq!(CandidatePool::<Test>::get(1).unwrap().stake, 500);
+			assert_eq!(StakePallet::delegator_state(3).unwrap().total, 100);
+		});
+}
+
+#[test]
+fn deferred_slash_can_be_cancelled() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 1000), (2, 1000)])
+		.with_collators(vec![(1, 500), (2, 500)])
+		.build()
+		.execute_with(|| {
+			SlashFraction::set(Perbill::from_percent(10));
+			SlashDeferDuration::set(2);
+			let authors: Vec<Option<AccountId>> = (0u64..10).map(|_| Some(1)).collect();
+
+			roll_to(5, authors.clone());
+			assert_eq!(StakePallet::pending_slashes(2, 2), Some(Perbill::from_percent(10)));
+			assert_eq!(CandidatePool::<Test>::get(2).unwrap().stake, 500);
+
+			// the offence of the second session is reported as well
+			roll_to(10, authors);
+			assert_eq!(StakePallet::offences(2), 2);
+			assert_eq!(StakePallet::pending_slashes(3, 2), Some(Perbill::from_percent(10)));
+
+			assert_noop!(StakePallet::cancel_slash(RuntimeOrigin::signed(1), 2, 2), BadOrigin);
+			assert_noop!(
+				StakePallet::cancel_slash(RuntimeOrigin::root(), 2, 1),
+				Error::<Test>::SlashNotFound
+			);
+			assert_ok!(StakePallet::cancel_slash(RuntimeOrigin::root(), 2, 2));
+			assert_eq!(last_event(), MetaEvent::StakePallet(Event::SlashCancelled(2, 2)));
+
+			// only the remaining slash is applied at the end of session 3
+			SlashFraction::set(Perbill::zero());
+			roll_to(20, vec![]);
+			assert!(StakePallet::pending_slashes(3, 2).is_none());
+			assert_eq!(CandidatePool::<Test>::get(2).unwrap().stake, 450);
+			assert_eq!(Balances::free_balance(2), 950);
+		});
+}
+
+#[test]
+fn collator_slashed_below_min_stake_is_chilled() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 1000), (2, 1000), (3, 1000)])
+		.with_collators(vec![(1, 500), (2, 100), (3, 20)])
+		.build()
+		.execute_with(|| {
+			SlashFraction::set(Perbill::from_percent(95));
+			let authors: Vec<Option<AccountId>> = (0u64..5).map(|_| Some(1)).collect();
+			assert_eq!(StakePallet::selected_candidates().into_inner(), vec![1, 2]);
+
+			roll_to(5, authors);
+			assert!(events().contains(&Event::Slashed(2, 95)));
+			assert!(events().contains(&Event::CandidateChilled(2)));
+			let state = CandidatePool::<Test>::get(2).unwrap();
+			assert_eq!(state.stake, 5);
+			assert!(state.is_leaving());
+			assert!(StakePallet::top_candidates().into_bounded_vec().iter().all(|c| c.owner != 2));
+
+			// the candidate cannot stay with less than the minimum stake
+			assert_noop!(
+				StakePallet::cancel_leave_candidates(RuntimeOrigin::signed(2)),
+				Error::<Test>::ValStakeBelowMin
+			);
+		});
+}
+
+#[test]
+fn collator_slashed_below_min_stake_with_min_required_collators() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 1000), (2, 1000)])
+		.with_collators(vec![(1, 500), (2, 100)])
+		.build()
+		.execute_with(|| {
+			SlashFraction::set(Perbill::from_percent(95));
+			let authors: Vec<Option<AccountId>> = (0u64..5).map(|_| Some(1)).collect();
+			assert_eq!(StakePallet::top_candidates().len() as u32, MinCollators::get());
+
+			roll_to(5, authors);
+			assert!(events().contains(&Event::Slashed(2, 95)));
+
+			// the candidate cannot be chilled without falling below `MinRequiredCollators`
+			assert!(!events().contains(&Event::CandidateChilled(2)));
+			assert!(events().contains(&Event::CandidateChillSkipped(2)));
+			let state = CandidatePool::<Test>::get(2).unwrap();
+			assert_eq!(state.stake, 5);
+			assert!(!state.is_leaving());
+			assert!(StakePallet::top_candidates().into_bounded_vec().iter().any(|c| c.owner == 2));
+		});
+}
+
+#[test]
+fn delegation_slashed_below_min_is_removed() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 1000), (2, 1000), (3, 1000), (4, 1000)])
+		.with_collators(vec![(1, 500), (2, 500)])
+		.with_delegators(vec![(3, 2, 100), (4, 2, 6)])
+		.build()
+		.execute_with(|| {
+			SlashFraction::set(Perbill::from_percent(50));
+			SlashDelegators::set(true);
+			let authors: Vec<Option<AccountId>> = (0u64..5).map(|_| Some(1)).collect();
+
+			roll_to(5, authors);
+			assert!(events().contains(&Event::Slashed(4, 3)));
+			assert!(events().contains(&Event::DelegatorLeftCollator(4, 2, 3, 250 + 50)));
+
+			// the delegation of 4 fell below `MinDelegatorStake` and was removed
+			assert!(StakePallet::delegator_state(4).is_none());
+			let state = CandidatePool::<Test>::get(2).unwrap();
+			assert_eq!(state.stake, 250);
+			assert_eq!(state.total, 250 + 50);
+			assert!(state.delegators.into_iter().all(|d| d.owner != 4));
+			assert_eq!(StakePallet::unstaking(4).values().sum::<Balance>(), 3);
+			assert_eq!(Balances::free_balance(4), 997);
+
+			// the remaining delegation is only reduced
+			assert_eq!(StakePallet::delegator_state(3).unwrap().total, 50);
+		});
+}
+
+#[test]
+fn repeat_offender_is_chilled() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 1000), (2, 1000), (3, 1000)])
+		.with_collators(vec![(1, 500), (2, 400), (3, 100)])
+		.build()
+		.execute_with(|| {
+			MaxOffencesBeforeChill::set(2);
+			let authors: Vec<Option<AccountId>> = (0u64..10).map(|_| Some(1)).collect();
+			assert_eq!(StakePallet::selected_candidates().into_inner(), vec![1, 2]);
+
+			roll_to(5, authors.clone());
+			assert_eq!(StakePallet::offences(2), 1);
+			assert!(!CandidatePool::<Test>::get(2).unwrap().is_leaving());
+
+			roll_to(10, authors);
+			assert!(events().contains(&Event::CandidateChilled(2)));
+			assert_eq!(StakePallet::offences(2), 0);
+			assert!(CandidatePool::<Test>::get(2).unwrap().is_leaving());
+			assert_eq!(StakePallet::selected_candidates().into_inner(), vec![1, 3]);
+
+			// nothing has been slashed
+			assert_eq!(CandidatePool::<Test>::get(2).unwrap().stake, 400);
+		});
+}
+
+#[test]
+fn runtime_api_queries() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 1000), (2, 1000), (3, 1000), (4, 1000)])
+		.with_collators(vec![(1, 500), (2, 600)])
+		.with_delegators(vec![(3, 1, 300)])
+		.build()
+		.execute_with(|| {
+			let candidates = StakePallet::candidates();
+			assert_eq!(
+				candidates
+					.iter()
+					.map(|c| (c.id, c.stake, c.total, c.delegators))
+					.collect::<Vec<_>>(),
+				vec![(1, 500, 800, 1), (2, 600, 600, 0)]
+			);
+			assert!(candidates.iter().all(|c| c.is_selected && !c.is_leaving));
+			assert!(candidates.iter().all(|c| c.metadata.is_none()));
+
+			assert_eq!(
+				StakePallet::delegations(&3),
+				vec![DelegationInfo { collator: 1, amount: 300 }]
+			);
+			assert!(StakePallet::delegations(&4).is_empty());
+
+			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(3), 1, 100));
+			assert_eq!(
+				StakePallet::unstaking_chunks(&3),
+				vec![UnstakingInfo { unlock_block: 3, amount: 100 }]
+			);
+			assert!(StakePallet::unstaking_chunks(&4).is_empty());
+
+			assert!(StakePallet::estimated_reward_per_session(&4).is_zero());
+		});
+}
+
+#[test]
+fn move_delegation() {
+	ExtBuilder::default()
+		.with_balances(vec![
+			(1, 100),
+			(2, 100),
+			(3, 100),
+			(4, 100),
+			(5, 100),
+			(6, 100),
+			(7, 100),
+			(8, 100),
+		])
+		.with_collators(vec![(1, 100), (2, 100), (3, 100)])
+		.with_delegators(vec![(4, 1, 50), (5, 2, 10), (6, 2, 10), (7, 2, 10), (8, 2, 10)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				StakePallet::move_delegation(RuntimeOrigin::signed(4), 1, 3, 0),
+				Error::<Test>::ValStakeZero
+			);
+			assert_noop!(
+				StakePallet::move_delegation(RuntimeOrigin::signed(4), 1, 1, 10),
+				Error::<Test>::CannotMoveToSameCollator
+			);
+			assert_noop!(
+				StakePallet::move_delegation(RuntimeOrigin::signed(4), 2, 3, 10),
+				Error::<Test>::DelegationNotFound
+			);
+			assert_noop!(
+				StakePallet::move_delegation(RuntimeOrigin::signed(4), 1, 3, 51),
+				Error::<Test>::Underflow
+			);
+			// remaining delegation would be below MinDelegation
+			assert_noop!(
+				StakePallet::move_delegation(RuntimeOrigin::signed(4), 1, 3, 49),
+				Error::<Test>::DelegationBelowMin
+			);
+			// new delegation would be below MinDelegation
+			assert_noop!(
+				StakePallet::move_delegation(RuntimeOrigin::signed(4), 1, 3, 2),
+				Error::<Test>::DelegationBelowMin
+			);
+
+			// move part of the delegation to a new collator
+			assert_ok!(StakePallet::move_delegation(RuntimeOrigin::signed(4), 1, 3, 20));
+			assert_eq!(last_event(), MetaEvent::StakePallet(Event::DelegationMoved(4, 1, 3, 20)));
+			let state = StakePallet::delegator_state(4).unwrap();
+			assert_eq!(state.total, 50);
+			assert_eq!(
+				state.delegations.into_bounded_vec().into_inner(),
+				vec![Stake { owner: 1, amount: 30 }, Stake { owner: 3, amount: 20 }]
+			);
+			assert_eq!(StakePallet::candidate_pool(1).unwrap().total, 130);
+			assert_eq!(StakePallet::candidate_pool(3).unwrap().total, 120);
+			// nothing is unstaked
+			assert!(StakePallet::unstaking(4).is_empty());
+			assert_eq!(
+				Balances::locks(4),
+				vec![BalanceLock { id: STAKING_ID, amount: 50, reasons: Reasons::All }]
+			);
+
+			// move the remaining delegation to an existing one
+			assert_ok!(StakePallet::set_auto_compound(RuntimeOrigin::signed(4), 1, Percent::one()));
+			assert_ok!(StakePallet::move_delegation(RuntimeOrigin::signed(4), 1, 3, 30));
+			let state = StakePallet::delegator_state(4).unwrap();
+			assert_eq!(
+				state.delegations.into_bounded_vec().into_inner(),
+				vec![Stake { owner: 3, amount: 50 }]
+			);
+			let collator = StakePallet::candidate_pool(1).unwrap();
+			assert!(collator.delegators.is_empty());
+			assert_eq!(collator.total, 100);
+			assert_eq!(StakePallet::candidate_pool(3).unwrap().total, 150);
+			assert!(!AutoCompound::<Test>::contains_key(4, 1));
+
+			// moving to a full collator replaces the delegator with the lowest stake
+			assert_ok!(StakePallet::move_delegation(RuntimeOrigin::signed(4), 3, 2, 50));
+			assert!(events().iter().any(|e| matches!(e, Event::DelegationReplaced(4, 50, ..))));
+			let collator = StakePallet::candidate_pool(2).unwrap();
+			assert_eq!(collator.delegators.len(), 4);
+			assert_eq!(collator.total, 180);
+			assert_eq!((5..=8).filter(|d| StakePallet::is_delegator(d)).count(), 3);
+
+			// cannot move to a leaving collator
+			assert_ok!(StakePallet::init_leave_candidates(RuntimeOrigin::signed(1)));
+			assert_noop!(
+				StakePallet::move_delegation(RuntimeOrigin::signed(4), 2, 1, 10),
+				Error::<Test>::CannotDelegateIfLeaving
+			);
+		});
+}
+
+#[test]
+fn scheduled_commission_change() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 1000), (2, 1000), (3, 1000)])
+		.with_collators(vec![(1, 500), (2, 500)])
+		.build()
+		.execute_with(|| {
+			CommissionChangeDelay::set(2);
+			assert_noop!(
+				StakePallet::set_commission(RuntimeOrigin::signed(3), Permill::from_percent(10)),
+				Error::<Test>::CandidateNotFound
+			);
+
+			assert_ok!(StakePallet::set_commission(
+				RuntimeOrigin::signed(1),
+				Permill::from_percent(20)
+			));
+			// a new request replaces the pending one
+			assert_ok!(StakePallet::set_commission(
+				RuntimeOrigin::signed(1),
+				Permill::from_percent(10)
+			));
+			assert_eq!(
+				last_event(),
+				MetaEvent::StakePallet(Event::CollatorCommissionChangeScheduled(
+					1,
+					Permill::from_percent(10),
+					2
+				))
+			);
+			assert_eq!(
+				StakePallet::pending_commission(1),
+				Some(PendingCommission { commission: Permill::from_percent(10), effective_at: 2 })
+			);
+			assert!(CandidatePool::<Test>::get(1).unwrap().commission.is_zero());
+
+			// still pending after the first session
+			roll_to(5, vec![]);
+			assert!(CandidatePool::<Test>::get(1).unwrap().commission.is_zero());
+
+			// applied before session 2 starts
+			roll_to(10, vec![]);
+			assert!(StakePallet::pending_commission(1).is_none());
+			assert_eq!(
+				CandidatePool::<Test>::get(1).unwrap().commission,
+				Permill::from_percent(10)
+			);
+			assert!(events()
+				.contains(&Event::CollatorCommissionChangeApplied(1, Permill::from_percent(10))));
+
+			// the delay is counted in sessions, independently of the round index
+			Round::<Test>::put(RoundInfo::new(7, 10, BLOCKS_PER_ROUND));
+			assert_ok!(StakePallet::set_commission(
+				RuntimeOrigin::signed(1),
+				Permill::from_percent(15)
+			));
+			assert_eq!(
+				StakePallet::pending_commission(1),
+				Some(PendingCommission {
+					commission: Permill::from_percent(15),
+					effective_at: Session::current_index() + 2
+				})
+			);
+
+			// without delay the commission is changed immediately
+			assert_ok!(StakePallet::set_commission(
+				RuntimeOrigin::signed(2),
+				Permill::from_percent(30)
+			));
+			CommissionChangeDelay::set(0);
+			assert_ok!(StakePallet::set_commission(
+				RuntimeOrigin::signed(2),
+				Permill::from_percent(5)
+			));
+			assert_eq!(CandidatePool::<Test>::get(2).unwrap().commission, Permill::from_percent(5));
+			assert!(StakePallet::pending_commission(2).is_none());
+			assert_eq!(
+				last_event(),
+				MetaEvent::StakePallet(Event::CollatorCommissionChanged(
+					2,
+					Permill::from_percent(5)
+				))
+			);
+		});
+}
+
+#[test]
+fn set_candidate_metadata() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 1000), (2, 1000), (3, 1000), (4, 1000)])
+		.with_collators(vec![(1, 500), (2, 500), (3, 500)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				StakePallet::set_candidate_metadata(
+					RuntimeOrigin::signed(4),
+					b"Alice".to_vec(),
+					vec![],
+					vec![]
+				),
+				Error::<Test>::CandidateNotFound
+			);
+			assert_noop!(
+				StakePallet::set_candidate_metadata(
+					RuntimeOrigin::signed(1),
+					b"Alice".to_vec(),
+					vec![b'a'; 33],
+					vec![]
+				),
+				Error::<Test>::MetadataTooLong
+			);
+
+			assert_ok!(StakePallet::set_candidate_metadata(
+				RuntimeOrigin::signed(1),
+				b"Alice".to_vec(),
+				b"https://alice.io".to_vec(),
+				b"alice@alice.io".to_vec()
+			));
+			assert_eq!(last_event(), MetaEvent::StakePallet(Event::CandidateMetadataSet(1)));
+			let metadata = StakePallet::candidate_metadata(1).unwrap();
+			assert_eq!(metadata.display_name.into_inner(), b"Alice".to_vec());
+			assert_eq!(metadata.url.into_inner(), b"https://alice.io".to_vec());
+			assert_eq!(metadata.description.into_inner(), b"alice@alice.io".to_vec());
+
+			// empty values remove the metadata
+			assert_ok!(StakePallet::set_candidate_metadata(
+				RuntimeOrigin::signed(1),
+				vec![],
+				vec![],
+				vec![]
+			));
+			assert!(StakePallet::candidate_metadata(1).is_none());
+			assert_eq!(last_event(), MetaEvent::StakePallet(Event::CandidateMetadataCleared(1)));
+
+			// removed together with the candidate
+			assert_ok!(StakePallet::set_candidate_metadata(
+				RuntimeOrigin::signed(3),
+				b"Charlie".to_vec(),
+				vec![],
+				vec![]
+			));
+			assert_ok!(StakePallet::force_remove_candidate(RuntimeOrigin::root(), 3));
+			assert!(StakePallet::candidate_metadata(3).is_none());
+			assert!(events().contains(&Event::CandidateMetadataCleared(3)));
+		});
+}
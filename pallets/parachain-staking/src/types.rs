@@ -16,7 +16,10 @@
 
 // If you feel like getting in touch with us, you can do so at info@botlabs.org
 
-use frame_support::traits::{Currency, Get};
+use frame_support::{
+	traits::{Currency, Get},
+	BoundedVec, CloneNoBound, DefaultNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+};
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_runtime::{
@@ -388,6 +391,15 @@ pub struct TotalStake<Balance: Default> {
 	pub delegators: Balance,
 }
 
+/// A commission change of a collator candidate which is not effective yet.
+#[derive(Clone, Encode, Decode, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct PendingCommission {
+	/// The new commission.
+	pub commission: Permill,
+	/// The session from which on the new commission is used.
+	pub effective_at: SessionIndex,
+}
+
 /// The number of delegations a delegator has done within the last session in
 /// which they delegated.
 #[derive(Default, Clone, Encode, Decode, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
@@ -398,6 +410,96 @@ pub struct DelegationCounter {
 	pub counter: u32,
 }
 
+/// The rewards of a collator and their delegators for a single round which
+/// have been recorded at the end of the round but not paid out yet.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(MaxDelegatorsPerCollator))]
+#[codec(mel_bound(AccountId: MaxEncodedLen, Balance: MaxEncodedLen))]
+pub struct RoundRewards<AccountId, Balance, MaxDelegatorsPerCollator>
+where
+	AccountId: Eq + Ord,
+	Balance: Eq + Ord,
+	MaxDelegatorsPerCollator: Get<u32>,
+{
+	/// The reward of the collator. Is `None` once it has been paid out.
+	pub collator: Option<Reward<AccountId, Balance>>,
+
+	/// The outstanding rewards of the collator's delegators.
+	pub delegators: BoundedVec<Reward<AccountId, Balance>, MaxDelegatorsPerCollator>,
+}
+
+impl<AccountId, Balance, MaxDelegatorsPerCollator>
+	RoundRewards<AccountId, Balance, MaxDelegatorsPerCollator>
+where
+	AccountId: Eq + Ord,
+	Balance: Eq + Ord + Copy + Saturating + Zero,
+	MaxDelegatorsPerCollator: Get<u32>,
+{
+	/// Returns true if all rewards of the round have been paid out.
+	pub fn is_empty(&self) -> bool {
+		self.collator.is_none() && self.delegators.is_empty()
+	}
+
+	/// The number of accounts which still have to be paid out.
+	pub fn len(&self) -> usize {
+		self.delegators.len().saturating_add(self.collator.is_some().into())
+	}
+
+	/// The sum of all outstanding rewards.
+	pub fn total(&self) -> Balance {
+		self.delegators
+			.iter()
+			.chain(self.collator.iter())
+			.fold(Balance::zero(), |acc, x| acc.saturating_add(x.amount))
+	}
+
+	/// Removes and returns the next outstanding reward, starting with the
+	/// collator.
+	pub fn pop(&mut self) -> Option<Reward<AccountId, Balance>> {
+		self.collator.take().or_else(|| self.delegators.pop())
+	}
+
+	/// Removes and returns the outstanding reward of the given account, if
+	/// there is any.
+	pub fn take(&mut self, who: &AccountId) -> Option<Balance> {
+		if self.collator.as_ref().map(|r| &r.owner == who).unwrap_or(false) {
+			return self.collator.take().map(|r| r.amount)
+		}
+		let i = self.delegators.iter().position(|r| &r.owner == who)?;
+		Some(self.delegators.remove(i).amount)
+	}
+}
+
+/// Optional information about a collator candidate which helps delegators to
+/// choose whom to delegate to.
+#[derive(
+	Encode,
+	Decode,
+	CloneNoBound,
+	PartialEqNoBound,
+	EqNoBound,
+	RuntimeDebugNoBound,
+	DefaultNoBound,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(MaxLength))]
+#[codec(mel_bound())]
+pub struct CandidateMetadata<MaxLength: Get<u32>> {
+	/// The name under which the candidate is displayed.
+	pub display_name: BoundedVec<u8, MaxLength>,
+	/// The website of the candidate.
+	pub url: BoundedVec<u8, MaxLength>,
+	/// A free-form description, e.g. how to contact the node operator.
+	pub description: BoundedVec<u8, MaxLength>,
+}
+
+impl<MaxLength: Get<u32>> CandidateMetadata<MaxLength> {
+	pub fn is_empty(&self) -> bool {
+		self.display_name.is_empty() && self.url.is_empty() && self.description.is_empty()
+	}
+}
+
 /// Internal type which is only used when a delegator is replaced by another
 /// one to delay the storage entry removal until failure cannot happen anymore.
 pub(crate) struct ReplacedDelegator<T: Config> {
@@ -410,3 +512,8 @@ pub type BalanceOf<T> = <<T as Config>::Currency as Currency<AccountIdOf<T>>>::B
 pub type CandidateOf<T, S> = Candidate<AccountIdOf<T>, BalanceOf<T>, S>;
 pub type MaxDelegatorsPerCollator<T> = <T as Config>::MaxDelegatorsPerCollator;
 pub type StakeOf<T> = Stake<AccountIdOf<T>, BalanceOf<T>>;
+pub type NegativeImbalanceOf<T> =
+	<<T as Config>::Currency as Currency<AccountIdOf<T>>>::NegativeImbalance;
+pub type CandidateMetadataOf<T> = CandidateMetadata<<T as Config>::MaxMetadataLength>;
+pub type RoundRewardsOf<T> =
+	RoundRewards<AccountIdOf<T>, BalanceOf<T>, <T as Config>::MaxDelegatorsPerCollator>;
@@ -24,4 +24,10 @@ pub trait WeightInfo {
 	fn unlock_unstaked(u: u32) -> Weight;
 	fn set_max_candidate_stake() -> Weight;
 	fn set_commission(n: u32, m: u32) -> Weight;
+	fn claim_rewards(s: u32) -> Weight;
+	fn payout_rewards(n: u32) -> Weight;
+	fn set_auto_compound() -> Weight;
+	fn cancel_slash() -> Weight;
+	fn move_delegation(n: u32, m: u32) -> Weight;
+	fn set_candidate_metadata() -> Weight;
 }
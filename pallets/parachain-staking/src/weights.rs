@@ -124,20 +124,24 @@ impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
 	/// Proof: ParachainStaking MaxSelectedCandidates (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
 	/// Storage: ParachainStaking TotalCollatorStake (r:1 w:1)
 	/// Proof: ParachainStaking TotalCollatorStake (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: Session CurrentIndex (r:1 w:0)
+	/// Proof Skipped: Session CurrentIndex (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: ParachainStaking PendingSlashes (r:13 w:0)
+	/// Proof: ParachainStaking PendingSlashes (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
 	/// The range of component `n` is `[5, 16]`.
 	/// The range of component `m` is `[0, 25]`.
 	fn force_remove_candidate(n: u32, m: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0 + m * (955 ±0) + n * (787 ±0)`
-		//  Estimated: `19935 + m * (2716 ±0) + n * (192 ±8)`
+		//  Estimated: `52838 + m * (2716 ±0) + n * (192 ±8)`
 		// Minimum execution time: 29_802_000 picoseconds.
 		Weight::from_parts(30_591_000, 0)
-			.saturating_add(Weight::from_parts(0, 19935))
+			.saturating_add(Weight::from_parts(0, 52838))
 			// Standard Error: 25_989
 			.saturating_add(Weight::from_parts(889_576, 0).saturating_mul(n.into()))
 			// Standard Error: 17_361
 			.saturating_add(Weight::from_parts(6_064_506, 0).saturating_mul(m.into()))
-			.saturating_add(T::DbWeight::get().reads(13))
+			.saturating_add(T::DbWeight::get().reads(27))
 			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(m.into())))
 			.saturating_add(T::DbWeight::get().writes(7))
 			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(m.into())))
@@ -249,20 +253,24 @@ impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
 	/// Proof Skipped: System Digest (max_values: Some(1), max_size: None, mode: Measured)
 	/// Storage: ParachainStaking CounterForCandidatePool (r:1 w:1)
 	/// Proof: ParachainStaking CounterForCandidatePool (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Session CurrentIndex (r:1 w:0)
+	/// Proof Skipped: Session CurrentIndex (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: ParachainStaking PendingSlashes (r:13 w:0)
+	/// Proof: ParachainStaking PendingSlashes (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
 	/// The range of component `n` is `[5, 15]`.
 	/// The range of component `m` is `[0, 25]`.
 	fn execute_leave_candidates(n: u32, m: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0 + m * (742 ±0) + n * (750 ±0)`
-		//  Estimated: `4779 + m * (2716 ±4) + n * (176 ±7)`
+		//  Estimated: `37682 + m * (2716 ±4) + n * (176 ±7)`
 		// Minimum execution time: 17_937_000 picoseconds.
 		Weight::from_parts(18_478_000, 0)
-			.saturating_add(Weight::from_parts(0, 4779))
+			.saturating_add(Weight::from_parts(0, 37682))
 			// Standard Error: 29_033
 			.saturating_add(Weight::from_parts(929_304, 0).saturating_mul(n.into()))
 			// Standard Error: 18_230
 			.saturating_add(Weight::from_parts(5_935_663, 0).saturating_mul(m.into()))
-			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().reads(21))
 			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(m.into())))
 			.saturating_add(T::DbWeight::get().writes(5))
 			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(m.into())))
@@ -537,4 +545,152 @@ impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: ParachainStaking PendingRewards (r:64 w:64)
+	/// Proof: ParachainStaking PendingRewards (max_values: None, max_size: Some(1305), added: 3780, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking TotalPendingRewards (r:1 w:1)
+	/// Proof: ParachainStaking TotalPendingRewards (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking AutoCompound (r:1 w:0)
+	/// Proof: ParachainStaking AutoCompound (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking DelegatorState (r:1 w:1)
+	/// Proof: ParachainStaking DelegatorState (max_values: None, max_size: Some(105), added: 2580, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	/// Proof: ParachainStaking CandidatePool (max_values: None, max_size: Some(1314), added: 3789, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking Unstaking (r:1 w:1)
+	/// Proof: ParachainStaking Unstaking (max_values: None, max_size: Some(241), added: 2716, mode: MaxEncodedLen)
+	/// Storage: Balances Locks (r:1 w:1)
+	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking TopCandidates (r:1 w:1)
+	/// Proof: ParachainStaking TopCandidates (max_values: Some(1), max_size: Some(769), added: 1264, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking MaxSelectedCandidates (r:1 w:0)
+	/// Proof: ParachainStaking MaxSelectedCandidates (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking TotalCollatorStake (r:1 w:1)
+	/// Proof: ParachainStaking TotalCollatorStake (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// The range of component `s` is `[1, 64]`.
+	fn claim_rewards(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6473 + s * (1250 ±0)`
+		//  Estimated: `10316 + s * (3780 ±0)`
+		// Minimum execution time: 98_641_000 picoseconds.
+		Weight::from_parts(86_204_913, 0)
+			.saturating_add(Weight::from_parts(0, 10316))
+			// Standard Error: 10_128
+			.saturating_add(Weight::from_parts(11_451_208, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(11))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(s.into())))
+			.saturating_add(T::DbWeight::get().writes(9))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 3780).saturating_mul(s.into()))
+	}
+	/// Storage: ParachainStaking PendingRewards (r:1 w:1)
+	/// Proof: ParachainStaking PendingRewards (max_values: None, max_size: Some(1305), added: 3780, mode: MaxEncodedLen)
+	/// Storage: System Account (r:17 w:17)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking AutoCompound (r:16 w:0)
+	/// Proof: ParachainStaking AutoCompound (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking DelegatorState (r:16 w:16)
+	/// Proof: ParachainStaking DelegatorState (max_values: None, max_size: Some(105), added: 2580, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking CandidatePool (r:16 w:16)
+	/// Proof: ParachainStaking CandidatePool (max_values: None, max_size: Some(1314), added: 3789, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking Unstaking (r:16 w:16)
+	/// Proof: ParachainStaking Unstaking (max_values: None, max_size: Some(241), added: 2716, mode: MaxEncodedLen)
+	/// Storage: Balances Locks (r:16 w:16)
+	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking TopCandidates (r:1 w:1)
+	/// Proof: ParachainStaking TopCandidates (max_values: Some(1), max_size: Some(769), added: 1264, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking MaxSelectedCandidates (r:1 w:0)
+	/// Proof: ParachainStaking MaxSelectedCandidates (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking TotalCollatorStake (r:1 w:1)
+	/// Proof: ParachainStaking TotalCollatorStake (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking TotalPendingRewards (r:1 w:1)
+	/// Proof: ParachainStaking TotalPendingRewards (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 16]`.
+	fn payout_rewards(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6395 + n * (1262 ±0)`
+		//  Estimated: `9164 + n * (18018 ±0)`
+		// Minimum execution time: 94_306_000 picoseconds.
+		Weight::from_parts(27_938_114, 0)
+			.saturating_add(Weight::from_parts(0, 9164))
+			// Standard Error: 24_870
+			.saturating_add(Weight::from_parts(68_417_309, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().reads((6_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(5))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 18018).saturating_mul(n.into()))
+	}
+	/// Storage: ParachainStaking DelegatorState (r:1 w:0)
+	/// Proof: ParachainStaking DelegatorState (max_values: None, max_size: Some(105), added: 2580, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking AutoCompound (r:0 w:1)
+	/// Proof: ParachainStaking AutoCompound (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	fn set_auto_compound() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `278`
+		//  Estimated: `3570`
+		// Minimum execution time: 11_204_000 picoseconds.
+		Weight::from_parts(11_637_000, 0)
+			.saturating_add(Weight::from_parts(0, 3570))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: ParachainStaking PendingSlashes (r:1 w:1)
+	/// Proof: ParachainStaking PendingSlashes (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	fn cancel_slash() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `220`
+		//  Estimated: `3521`
+		// Minimum execution time: 10_158_000 picoseconds.
+		Weight::from_parts(10_560_000, 0)
+			.saturating_add(Weight::from_parts(0, 3521))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: ParachainStaking DelegatorState (r:1 w:1)
+	/// Proof: ParachainStaking DelegatorState (max_values: None, max_size: Some(105), added: 2580, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking CandidatePool (r:2 w:2)
+	/// Proof: ParachainStaking CandidatePool (max_values: None, max_size: Some(1314), added: 3789, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking LastDelegation (r:1 w:1)
+	/// Proof: ParachainStaking LastDelegation (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking Round (r:1 w:0)
+	/// Proof: ParachainStaking Round (max_values: Some(1), max_size: Some(20), added: 515, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking TopCandidates (r:1 w:1)
+	/// Proof: ParachainStaking TopCandidates (max_values: Some(1), max_size: Some(769), added: 1264, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking MaxSelectedCandidates (r:1 w:0)
+	/// Proof: ParachainStaking MaxSelectedCandidates (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking TotalCollatorStake (r:1 w:1)
+	/// Proof: ParachainStaking TotalCollatorStake (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking AutoCompound (r:0 w:1)
+	/// Proof: ParachainStaking AutoCompound (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// The range of component `n` is `[2, 16]`.
+	/// The range of component `m` is `[1, 24]`.
+	fn move_delegation(n: u32, m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1327 + m * (162 ±0) + n * (122 ±0)`
+		//  Estimated: `8568`
+		// Minimum execution time: 36_104_000 picoseconds.
+		Weight::from_parts(28_915_342, 0)
+			.saturating_add(Weight::from_parts(0, 8568))
+			// Standard Error: 7_412
+			.saturating_add(Weight::from_parts(1_013_207, 0).saturating_mul(n.into()))
+			// Standard Error: 4_893
+			.saturating_add(Weight::from_parts(612_877, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(7))
+	}
+	/// Storage: ParachainStaking CandidatePool (r:1 w:0)
+	/// Proof: ParachainStaking CandidatePool (max_values: None, max_size: Some(1314), added: 3789, mode: MaxEncodedLen)
+	/// Storage: ParachainStaking MetadataOf (r:0 w:1)
+	/// Proof: ParachainStaking MetadataOf (max_values: None, max_size: Some(430), added: 2905, mode: MaxEncodedLen)
+	fn set_candidate_metadata() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `402`
+		//  Estimated: `4779`
+		// Minimum execution time: 14_315_000 picoseconds.
+		Weight::from_parts(14_892_000, 0)
+			.saturating_add(Weight::from_parts(0, 4779))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
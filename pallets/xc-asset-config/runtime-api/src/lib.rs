@@ -0,0 +1,51 @@
+//! Runtime API definition of the xc-asset-config pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+// These clippy lints are disabled because the macro-generated code triggers them.
+#![allow(clippy::too_many_arguments)]
+
+use parity_scale_codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+use xcm::VersionedMultiLocation;
+
+/// The price of local execution in units of a foreign asset.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ForeignAssetFeeRate {
+	/// Units charged per second of execution time.
+	pub units_per_second: u128,
+	/// Units charged per byte of proof size.
+	pub units_per_byte: u128,
+}
+
+/// A registered foreign asset together with its local metadata.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ForeignAssetInfo<AssetId, Balance> {
+	/// The local asset Id.
+	pub asset_id: AssetId,
+	/// The remote location of the asset.
+	pub location: VersionedMultiLocation,
+	/// The name of the local asset, empty if it has no metadata.
+	pub name: Vec<u8>,
+	/// The symbol of the local asset, empty if it has no metadata.
+	pub symbol: Vec<u8>,
+	/// The number of decimals of the local asset.
+	pub decimals: u8,
+	/// The minimum balance of the local asset.
+	pub min_balance: Balance,
+	/// The current execution fee rate, `None` if the asset can't be used for fee payment.
+	pub fee_rate: Option<ForeignAssetFeeRate>,
+}
+
+sp_api::decl_runtime_apis! {
+	/// The API to query the foreign assets of the xc-asset-config pallet.
+	pub trait XcAssetConfigApi<AssetId, Balance>
+	where
+		AssetId: Codec,
+		Balance: Codec,
+	{
+		/// Return all registered foreign assets with their metadata.
+		fn foreign_assets() -> Vec<ForeignAssetInfo<AssetId, Balance>>;
+	}
+}
@@ -0,0 +1,39 @@
+//! Helper functions for the implementation of the `XcAssetConfigApi` runtime API.
+
+use crate::{
+	AssetIdToLocation, Config, ExecutionPaymentRate, ForeignAssetMetadata, ForeignAssetRegistrar,
+	Pallet,
+};
+use sp_std::vec::Vec;
+use xc_asset_config_runtime_api::{ForeignAssetFeeRate, ForeignAssetInfo};
+use xcm::v3::MultiLocation;
+
+impl<T: Config> Pallet<T> {
+	/// Return all registered foreign assets with the metadata of their local asset.
+	pub fn foreign_assets() -> Vec<ForeignAssetInfo<T::AssetId, T::Balance>> {
+		AssetIdToLocation::<T>::iter()
+			.map(|(asset_id, location)| {
+				let metadata =
+					T::AssetRegistrar::foreign_asset_metadata(asset_id).unwrap_or_default();
+				let fee_rate = MultiLocation::try_from(location.clone())
+					.ok()
+					.and_then(Self::get_execution_rate)
+					.map(|rate| ForeignAssetFeeRate {
+						units_per_second: rate.units_per_second,
+						units_per_byte: rate.units_per_byte,
+					});
+				let ForeignAssetMetadata { name, symbol, decimals, min_balance } = metadata;
+
+				ForeignAssetInfo {
+					asset_id,
+					location,
+					name,
+					symbol,
+					decimals,
+					min_balance,
+					fee_rate,
+				}
+			})
+			.collect()
+	}
+}
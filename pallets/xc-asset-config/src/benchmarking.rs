@@ -22,9 +22,13 @@ use super::*;
 use crate::Pallet as XcAssetConfig;
 
 use frame_benchmarking::{benchmarks, impl_benchmark_test_suite};
+use frame_support::{
+	traits::{Get, Hooks},
+	BoundedVec,
+};
 use frame_system::RawOrigin;
-use sp_std::boxed::Box;
-use xcm::v3::MultiLocation;
+use sp_std::{boxed::Box, vec};
+use xcm::v3::{Junction, Junctions, MultiLocation};
 
 benchmarks! {
 
@@ -46,7 +50,7 @@ benchmarks! {
 
 	}: _(RawOrigin::Root, Box::new(asset_location.clone().into_versioned()), units)
 	verify {
-		assert_eq!(AssetLocationUnitsPerSecond::<T>::get(&asset_location.into_versioned()), Some(units));
+		assert_eq!(XcAssetConfig::<T>::get_units_per_second(asset_location), Some(units));
 	}
 
 	change_existing_asset_location {
@@ -63,7 +67,7 @@ benchmarks! {
 	verify {
 		assert!(!AssetLocationToId::<T>::contains_key(&asset_location.clone().into_versioned()));
 		assert_eq!(AssetLocationToId::<T>::get(&new_asset_location.clone().into_versioned()), Some(asset_id));
-		assert_eq!(AssetLocationUnitsPerSecond::<T>::get(&new_asset_location.into_versioned()), Some(units));
+		assert_eq!(XcAssetConfig::<T>::get_units_per_second(new_asset_location), Some(units));
 	}
 
 	remove_payment_asset {
@@ -76,7 +80,7 @@ benchmarks! {
 
 	}: _(RawOrigin::Root, Box::new(asset_location.clone().into_versioned()))
 	verify {
-		assert!(!AssetLocationUnitsPerSecond::<T>::contains_key(&asset_location.into_versioned()));
+		assert!(!AssetLocationExecutionRate::<T>::contains_key(&asset_location.into_versioned()));
 	}
 
 	remove_asset {
@@ -91,7 +95,110 @@ benchmarks! {
 	verify {
 		assert!(!AssetLocationToId::<T>::contains_key(&asset_location.clone().into_versioned()));
 		assert!(!AssetIdToLocation::<T>::contains_key(asset_id));
-		assert!(!AssetLocationUnitsPerSecond::<T>::contains_key(&asset_location.into_versioned()));
+		assert!(!AssetLocationExecutionRate::<T>::contains_key(&asset_location.into_versioned()));
+	}
+
+	set_asset_execution_rate {
+		let asset_location = MultiLocation::parent();
+		let asset_id = T::AssetId::default();
+		let units_per_second = 123;
+		let units_per_byte = 45;
+
+		XcAssetConfig::<T>::register_asset_location(RawOrigin::Root.into(), Box::new(asset_location.clone().into_versioned()), asset_id)?;
+
+	}: _(RawOrigin::Root, Box::new(asset_location.clone().into_versioned()), units_per_second, units_per_byte)
+	verify {
+		assert_eq!(
+			AssetLocationExecutionRate::<T>::get(&asset_location.into_versioned()),
+			Some(ExecutionRate { units_per_second, units_per_byte })
+		);
+	}
+
+	set_dynamic_execution_rate {
+		let asset_location = MultiLocation::parent();
+		let asset_id = T::AssetId::default();
+		let params = DynamicRateParams {
+			min_units_per_second: 1,
+			max_units_per_second: 1_000_000,
+			smoothing_window: 10,
+		};
+
+		XcAssetConfig::<T>::register_asset_location(RawOrigin::Root.into(), Box::new(asset_location.clone().into_versioned()), asset_id)?;
+
+	}: _(RawOrigin::Root, Box::new(asset_location.clone().into_versioned()), params.min_units_per_second, params.max_units_per_second, params.smoothing_window)
+	verify {
+		assert_eq!(AssetLocationDynamicRate::<T>::get(&asset_location.into_versioned()), Some(params));
+	}
+
+	remove_dynamic_execution_rate {
+		let asset_location = MultiLocation::parent();
+		let asset_id = T::AssetId::default();
+
+		XcAssetConfig::<T>::register_asset_location(RawOrigin::Root.into(), Box::new(asset_location.clone().into_versioned()), asset_id)?;
+		XcAssetConfig::<T>::set_dynamic_execution_rate(RawOrigin::Root.into(), Box::new(asset_location.clone().into_versioned()), 1, 1_000_000, 10)?;
+
+	}: _(RawOrigin::Root, Box::new(asset_location.clone().into_versioned()))
+	verify {
+		assert!(!AssetLocationDynamicRate::<T>::contains_key(&asset_location.into_versioned()));
+	}
+
+	update_dynamic_rates {
+		let n in 0 .. T::MaxDynamicRateAssets::get();
+
+		for i in 0 .. n {
+			let asset_location = MultiLocation {
+				parents: 1,
+				interior: Junctions::X1(Junction::Parachain(i)),
+			}.into_versioned();
+			AssetLocationToId::<T>::insert(&asset_location, T::AssetId::default());
+			AssetLocationDynamicRate::<T>::insert(&asset_location, DynamicRateParams {
+				min_units_per_second: 1,
+				max_units_per_second: 1_000_000,
+				smoothing_window: 10,
+			});
+		}
+
+	}: {
+		XcAssetConfig::<T>::on_initialize(1u32.into());
+	}
+	verify {
+		assert_eq!(AssetLocationDynamicRate::<T>::count(), n);
+	}
+
+	register_foreign_asset {
+		let asset_location = MultiLocation::parent();
+		let asset_id = T::AssetId::default();
+		let name = BoundedVec::try_from(vec![b'n'; T::StringLimit::get() as usize]).unwrap();
+		let symbol = BoundedVec::try_from(vec![b's'; T::StringLimit::get() as usize]).unwrap();
+		let rate = ExecutionRate { units_per_second: 123, units_per_byte: 45 };
+
+	}: _(RawOrigin::Root, Box::new(asset_location.clone().into_versioned()), asset_id, name, symbol, 12, T::Balance::default(), true, Some(rate))
+	verify {
+		assert_eq!(AssetIdToLocation::<T>::get(&asset_id), Some(asset_location.into_versioned()));
+		assert_eq!(AssetLocationExecutionRate::<T>::get(&asset_location.into_versioned()), Some(rate));
+	}
+
+	pause_asset {
+		let asset_location = MultiLocation::parent();
+		let asset_id = T::AssetId::default();
+
+		XcAssetConfig::<T>::register_asset_location(RawOrigin::Root.into(), Box::new(asset_location.into_versioned()), asset_id)?;
+
+	}: _(RawOrigin::Root, asset_id)
+	verify {
+		assert!(XcAssetConfig::<T>::is_asset_paused(asset_id));
+	}
+
+	resume_asset {
+		let asset_location = MultiLocation::parent();
+		let asset_id = T::AssetId::default();
+
+		XcAssetConfig::<T>::register_asset_location(RawOrigin::Root.into(), Box::new(asset_location.into_versioned()), asset_id)?;
+		XcAssetConfig::<T>::pause_asset(RawOrigin::Root.into(), asset_id)?;
+
+	}: _(RawOrigin::Root, asset_id)
+	verify {
+		assert!(!XcAssetConfig::<T>::is_asset_paused(asset_id));
 	}
 
 }
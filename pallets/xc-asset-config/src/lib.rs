@@ -36,24 +36,44 @@
 //!
 //! - `register_asset_location` - used to register mapping between local asset Id and remote asset
 //!   location
+//! - `register_foreign_asset` - creates the local asset with its metadata, registers its remote
+//!   location and optionally its payment rate, all at once
+//! - `pause_asset` - stops accepting the asset via XCM, keeping its configuration
+//! - `resume_asset` - accepts the paused asset via XCM again
 //! - `set_asset_units_per_second` - registers asset as payment currency and sets the desired
 //!   payment per second of execution time
+//! - `set_asset_execution_rate` - registers asset as payment currency and sets the desired payment
+//!   per second of execution time and per byte of proof size
 //! - `change_existing_asset_location` - changes the remote location of an existing local asset Id
 //! - `remove_payment_asset` - removes asset from the set of supported payment assets
 //! - `remove_asset` - removes all information related to this asset
+//! - `set_dynamic_execution_rate` - derives the payment per second of execution time of an asset
+//!   from its DEX price against the native token, within the given bounds
+//! - `remove_dynamic_execution_rate` - goes back to the statically configured payment rate
 //!
 //! User is encouraged to refer to specific function implementations for more comprehensive
 //! documentation.
 //!
+//! ### Dynamic execution rates
+//!
+//! Assets with a dynamic execution rate have their `units per second` derived each block from the
+//! pool reserves reported by `PriceOracle`, priced against `NativeUnitsPerSecond`. The derived
+//! value is smoothed with an exponential moving average over the configured number of blocks and
+//! kept within the configured bounds. While no pool exists, the static rate is used instead.
+//!
 //! ### Other
 //!
 //! `AssetLocationGetter` interface for mapping asset Id to asset location and vice versa
 //! - `get_xc_asset_location`
 //! - `get_asset_id`
 //!
-//! `ExecutionPaymentRate` interface for fetching `units per second` if asset is supported payment
-//! asset
+//! `ExecutionPaymentRate` interface for fetching `units per second` and `units per byte` if asset
+//! is supported payment asset
+//! - `get_execution_rate`
 //! - `get_units_per_second`
+//!
+//! `ForeignAssetRegistrar` interface for creating the local asset backing a foreign asset and
+//! reading its metadata
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -71,6 +91,7 @@ pub mod mock;
 #[cfg(test)]
 pub mod tests;
 
+pub mod api;
 pub mod migrations;
 
 pub mod weights;
@@ -84,10 +105,11 @@ pub mod pallet {
 	use frame_support::{pallet_prelude::*, traits::EnsureOrigin};
 	use frame_system::pallet_prelude::*;
 	// use parity_scale_codec::HasCompact;
-	use sp_std::boxed::Box;
+	use sp_runtime::{helpers_128bit::multiply_by_rational_with_rounding, Rounding};
+	use sp_std::{boxed::Box, cmp::Ordering, vec::Vec};
 	use xcm::{v3::MultiLocation, VersionedMultiLocation};
 
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -103,12 +125,82 @@ pub mod pallet {
 		fn get_asset_id(xc_asset_location: MultiLocation) -> Option<AssetId>;
 	}
 
-	/// Used to fetch `units per second` if cross-chain asset is applicable for local execution
+	/// The price of local execution in units of an asset.
+	#[derive(
+		Encode, Decode, Clone, Copy, Default, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen,
+	)]
+	pub struct ExecutionRate {
+		/// Units charged per second of execution time (ref time).
+		pub units_per_second: u128,
+		/// Units charged per byte of proof size.
+		pub units_per_byte: u128,
+	}
+
+	/// Bounds and smoothing used to derive the units per second of an asset from its DEX price.
+	#[derive(
+		Encode, Decode, Clone, Copy, Default, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen,
+	)]
+	pub struct DynamicRateParams {
+		/// Lower bound of the derived units per second.
+		pub min_units_per_second: u128,
+		/// Upper bound of the derived units per second.
+		pub max_units_per_second: u128,
+		/// Number of blocks the derived units per second are smoothed over. `0` or `1` means no
+		/// smoothing.
+		pub smoothing_window: u32,
+	}
+
+	/// Metadata of the local asset backing a foreign asset.
+	#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	pub struct ForeignAssetMetadata<Balance> {
+		/// Name of the asset.
+		pub name: Vec<u8>,
+		/// Symbol of the asset.
+		pub symbol: Vec<u8>,
+		/// Number of decimals of the asset.
+		pub decimals: u8,
+		/// Minimum balance an account must hold.
+		pub min_balance: Balance,
+	}
+
+	/// Used to create the local asset backing a foreign asset.
+	pub trait ForeignAssetRegistrar<AssetId, Balance> {
+		/// Creates the asset and sets its metadata.
+		fn create_foreign_asset(
+			asset_id: AssetId,
+			is_sufficient: bool,
+			metadata: ForeignAssetMetadata<Balance>,
+		) -> DispatchResult;
+
+		/// returns the metadata of the asset or `None` if it doesn't exist.
+		fn foreign_asset_metadata(asset_id: AssetId) -> Option<ForeignAssetMetadata<Balance>>;
+	}
+
+	/// Used to fetch the DEX pool reserves of an asset paired with the native token.
+	pub trait NativePriceOracle<AssetId> {
+		/// returns the `(asset reserve, native reserve)` of the pool or `None` if no such pool
+		/// exists.
+		fn native_reserves(asset_id: AssetId) -> Option<(u128, u128)>;
+	}
+
+	impl<AssetId> NativePriceOracle<AssetId> for () {
+		fn native_reserves(_asset_id: AssetId) -> Option<(u128, u128)> {
+			None
+		}
+	}
+
+	/// Used to fetch the execution rate if cross-chain asset is applicable for local execution
 	/// payment.
 	pub trait ExecutionPaymentRate {
+		/// returns the execution rate from asset type or `None` if asset type isn't a supported
+		/// payment asset.
+		fn get_execution_rate(asset_location: MultiLocation) -> Option<ExecutionRate>;
+
 		/// returns units per second from asset type or `None` if asset type isn't a supported
 		/// payment asset.
-		fn get_units_per_second(asset_location: MultiLocation) -> Option<u128>;
+		fn get_units_per_second(asset_location: MultiLocation) -> Option<u128> {
+			Self::get_execution_rate(asset_location).map(|rate| rate.units_per_second)
+		}
 	}
 
 	impl<T: Config> XcAssetLocation<T::AssetId> for Pallet<T> {
@@ -128,8 +220,21 @@ pub mod pallet {
 	}
 
 	impl<T: Config> ExecutionPaymentRate for Pallet<T> {
-		fn get_units_per_second(asset_location: MultiLocation) -> Option<u128> {
-			AssetLocationUnitsPerSecond::<T>::get(asset_location.into_versioned())
+		fn get_execution_rate(asset_location: MultiLocation) -> Option<ExecutionRate> {
+			let asset_location = asset_location.into_versioned();
+			// Paused assets aren't accepted as payment, even if they reached the holding register
+			if AssetLocationToId::<T>::get(&asset_location).map_or(false, Self::is_asset_paused) {
+				return None
+			}
+			let rate = AssetLocationExecutionRate::<T>::get(&asset_location);
+
+			match DerivedUnitsPerSecond::<T>::get(&asset_location) {
+				Some(units_per_second) => Some(ExecutionRate {
+					units_per_second,
+					units_per_byte: rate.map(|r| r.units_per_byte).unwrap_or_default(),
+				}),
+				None => rate,
+			}
 		}
 	}
 
@@ -152,6 +257,26 @@ pub mod pallet {
 		/// Should most likely be root.
 		type ManagerOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
 
+		/// Balance of the local assets backing foreign assets
+		type Balance: Member + Parameter + Default + Copy + MaxEncodedLen;
+
+		/// Creates the local assets backing foreign assets
+		type AssetRegistrar: ForeignAssetRegistrar<Self::AssetId, Self::Balance>;
+
+		/// Maximum length of the name and symbol of a foreign asset
+		#[pallet::constant]
+		type StringLimit: Get<u32>;
+
+		/// DEX price source for assets with a dynamic execution rate
+		type PriceOracle: NativePriceOracle<Self::AssetId>;
+
+		/// Units of the native token charged per second of execution time
+		type NativeUnitsPerSecond: Get<u128>;
+
+		/// Maximum number of assets with a dynamic execution rate
+		#[pallet::constant]
+		type MaxDynamicRateAssets: Get<u32>;
+
 		type WeightInfo: WeightInfo;
 	}
 
@@ -165,6 +290,16 @@ pub mod pallet {
 		MultiLocationNotSupported,
 		/// Asset is not supported as payment currency.
 		NativeAssetRelated,
+		/// Minimum units per second are above the maximum.
+		InvalidRateBounds,
+		/// Too many assets with a dynamic execution rate.
+		TooManyDynamicRateAssets,
+		/// Asset is already paused.
+		AssetAlreadyPaused,
+		/// Asset is not paused.
+		AssetNotPaused,
+		/// Asset has no dynamic execution rate.
+		DynamicRateNotSet,
 	}
 
 	#[allow(clippy::large_enum_variant)]
@@ -185,6 +320,33 @@ pub mod pallet {
 		SupportedAssetRemoved { asset_location: VersionedMultiLocation },
 		/// Removed all information related to an asset Id
 		AssetRemoved { asset_location: VersionedMultiLocation, asset_id: T::AssetId },
+		/// Changed the amount of units we are charging per execution second and per byte of
+		/// proof size for an asset
+		ExecutionRateChanged {
+			asset_location: VersionedMultiLocation,
+			units_per_second: u128,
+			units_per_byte: u128,
+		},
+		/// Units per second of an asset are now derived from its DEX price
+		DynamicExecutionRateSet {
+			asset_location: VersionedMultiLocation,
+			min_units_per_second: u128,
+			max_units_per_second: u128,
+			smoothing_window: u32,
+		},
+		/// Units per second of an asset are no longer derived from its DEX price
+		DynamicExecutionRateRemoved { asset_location: VersionedMultiLocation },
+		/// Created the local asset of a foreign asset and registered its location
+		ForeignAssetRegistered {
+			asset_location: VersionedMultiLocation,
+			asset_id: T::AssetId,
+			is_sufficient: bool,
+			min_balance: T::Balance,
+		},
+		/// Stopped accepting an asset via XCM
+		AssetPaused { asset_id: T::AssetId },
+		/// Accepting a paused asset via XCM again
+		AssetResumed { asset_id: T::AssetId },
 	}
 
 	/// Mapping from an asset id to asset type.
@@ -203,16 +365,50 @@ pub mod pallet {
 	pub type AssetLocationToId<T: Config> =
 		StorageMap<_, Twox64Concat, VersionedMultiLocation, T::AssetId>;
 
-	/// Stores the units per second for local execution for a AssetLocation.
-	/// This is used to know how to charge for XCM execution in a particular asset.
+	/// Stores the units per second and per byte of proof size for local execution for a
+	/// AssetLocation. This is used to know how to charge for XCM execution in a particular asset.
 	///
 	/// Not all asset types are supported for payment. If value exists here, it means it is
 	/// supported.
 	#[pallet::storage]
-	#[pallet::getter(fn asset_location_units_per_second)]
-	pub type AssetLocationUnitsPerSecond<T: Config> =
+	#[pallet::getter(fn asset_location_execution_rate)]
+	pub type AssetLocationExecutionRate<T: Config> =
+		StorageMap<_, Twox64Concat, VersionedMultiLocation, ExecutionRate>;
+
+	/// Stores the parameters of the AssetLocations whose units per second are derived from their
+	/// DEX price.
+	#[pallet::storage]
+	#[pallet::getter(fn asset_location_dynamic_rate)]
+	pub type AssetLocationDynamicRate<T: Config> =
+		CountedStorageMap<_, Twox64Concat, VersionedMultiLocation, DynamicRateParams>;
+
+	/// Stores the smoothed units per second derived from the DEX price of an AssetLocation.
+	///
+	/// If value exists here, it takes precedence over the static units per second.
+	#[pallet::storage]
+	#[pallet::getter(fn derived_units_per_second)]
+	pub type DerivedUnitsPerSecond<T: Config> =
 		StorageMap<_, Twox64Concat, VersionedMultiLocation, u128>;
 
+	/// Assets which are not accepted via XCM for the time being.
+	///
+	/// Deposits of these assets are rejected and their location can't be converted into an asset
+	/// Id.
+	#[pallet::storage]
+	pub type PausedAssets<T: Config> = StorageMap<_, Twox64Concat, T::AssetId, ()>;
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+			let mut assets = 0u32;
+			for (asset_location, params) in AssetLocationDynamicRate::<T>::iter() {
+				Self::update_derived_rate(&asset_location, &params);
+				assets += 1;
+			}
+			T::WeightInfo::update_dynamic_rates(assets)
+		}
+	}
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Register new asset location to asset Id mapping.
@@ -227,25 +423,7 @@ pub mod pallet {
 		) -> DispatchResult {
 			T::ManagerOrigin::ensure_origin(origin)?;
 
-			ensure!(asset_id != T::NativeAssetId::get(), Error::<T>::NativeAssetRelated);
-
-			// Ensure such an assetId does not exist
-			ensure!(
-				!AssetIdToLocation::<T>::contains_key(asset_id),
-				Error::<T>::AssetAlreadyRegistered
-			);
-
-			let v3_asset_loc = MultiLocation::try_from(*asset_location)
-				.map_err(|_| Error::<T>::MultiLocationNotSupported)?;
-			let asset_location = VersionedMultiLocation::V3(v3_asset_loc);
-
-			ensure!(
-				asset_location != T::NativeAssetLocation::get().into_versioned(),
-				Error::<T>::NativeAssetRelated
-			);
-
-			AssetIdToLocation::<T>::insert(asset_id, asset_location.clone());
-			AssetLocationToId::<T>::insert(&asset_location, asset_id);
+			let asset_location = Self::do_register_asset_location(*asset_location, asset_id)?;
 
 			Self::deposit_event(Event::AssetRegistered { asset_location, asset_id });
 			Ok(())
@@ -253,6 +431,8 @@ pub mod pallet {
 
 		/// Change the amount of units we are charging per execution second
 		/// for a given AssetLocation.
+		///
+		/// The amount charged per byte of proof size is kept.
 		#[pallet::call_index(1)]
 		#[pallet::weight(T::WeightInfo::set_asset_units_per_second())]
 		pub fn set_asset_units_per_second(
@@ -276,7 +456,9 @@ pub mod pallet {
 				Error::<T>::AssetDoesNotExist
 			);
 
-			AssetLocationUnitsPerSecond::<T>::insert(&asset_location, units_per_second);
+			AssetLocationExecutionRate::<T>::mutate(&asset_location, |rate| {
+				rate.get_or_insert_with(Default::default).units_per_second = units_per_second
+			});
 
 			Self::deposit_event(Event::UnitsPerSecondChanged { asset_location, units_per_second });
 			Ok(())
@@ -314,9 +496,17 @@ pub mod pallet {
 			// Remove previous asset type info
 			AssetLocationToId::<T>::remove(&previous_asset_location);
 
-			// Change AssetLocationUnitsPerSecond
-			if let Some(units) = AssetLocationUnitsPerSecond::<T>::take(&previous_asset_location) {
-				AssetLocationUnitsPerSecond::<T>::insert(&new_asset_location, units);
+			// Change AssetLocationExecutionRate
+			if let Some(rate) = AssetLocationExecutionRate::<T>::take(&previous_asset_location) {
+				AssetLocationExecutionRate::<T>::insert(&new_asset_location, rate);
+			}
+
+			// Change AssetLocationDynamicRate and DerivedUnitsPerSecond
+			if let Some(params) = AssetLocationDynamicRate::<T>::take(&previous_asset_location) {
+				AssetLocationDynamicRate::<T>::insert(&new_asset_location, params);
+			}
+			if let Some(units) = DerivedUnitsPerSecond::<T>::take(&previous_asset_location) {
+				DerivedUnitsPerSecond::<T>::insert(&new_asset_location, units);
 			}
 
 			Self::deposit_event(Event::AssetLocationChanged {
@@ -348,7 +538,9 @@ pub mod pallet {
 				Error::<T>::NativeAssetRelated
 			);
 
-			AssetLocationUnitsPerSecond::<T>::remove(&asset_location);
+			AssetLocationExecutionRate::<T>::remove(&asset_location);
+			AssetLocationDynamicRate::<T>::remove(&asset_location);
+			DerivedUnitsPerSecond::<T>::remove(&asset_location);
 
 			Self::deposit_event(Event::SupportedAssetRemoved { asset_location });
 			Ok(())
@@ -367,14 +559,307 @@ pub mod pallet {
 
 			AssetIdToLocation::<T>::remove(asset_id);
 			AssetLocationToId::<T>::remove(&asset_location);
-			AssetLocationUnitsPerSecond::<T>::remove(&asset_location);
+			AssetLocationExecutionRate::<T>::remove(&asset_location);
+			AssetLocationDynamicRate::<T>::remove(&asset_location);
+			DerivedUnitsPerSecond::<T>::remove(&asset_location);
+			PausedAssets::<T>::remove(asset_id);
 
 			Self::deposit_event(Event::AssetRemoved { asset_id, asset_location });
 			Ok(())
 		}
+
+		/// Change the amount of units we are charging per execution second and per byte of
+		/// proof size for a given AssetLocation.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::set_asset_execution_rate())]
+		pub fn set_asset_execution_rate(
+			origin: OriginFor<T>,
+			asset_location: Box<VersionedMultiLocation>,
+			#[pallet::compact] units_per_second: u128,
+			#[pallet::compact] units_per_byte: u128,
+		) -> DispatchResult {
+			T::ManagerOrigin::ensure_origin(origin)?;
+
+			let v3_asset_loc = MultiLocation::try_from(*asset_location)
+				.map_err(|_| Error::<T>::MultiLocationNotSupported)?;
+			let asset_location = VersionedMultiLocation::V3(v3_asset_loc);
+
+			ensure!(
+				asset_location != T::NativeAssetLocation::get().into_versioned(),
+				Error::<T>::NativeAssetRelated
+			);
+
+			ensure!(
+				AssetLocationToId::<T>::contains_key(&asset_location),
+				Error::<T>::AssetDoesNotExist
+			);
+
+			AssetLocationExecutionRate::<T>::insert(
+				&asset_location,
+				ExecutionRate { units_per_second, units_per_byte },
+			);
+
+			Self::deposit_event(Event::ExecutionRateChanged {
+				asset_location,
+				units_per_second,
+				units_per_byte,
+			});
+			Ok(())
+		}
+
+		/// Derive the amount of units we are charging per execution second for a given
+		/// AssetLocation from its DEX price against the native token.
+		///
+		/// The derived value is kept within `min_units_per_second` and `max_units_per_second` and
+		/// smoothed over `smoothing_window` blocks. It is updated right away and then at the start
+		/// of every block. The static `units per second` are used while no pool exists.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::set_dynamic_execution_rate())]
+		pub fn set_dynamic_execution_rate(
+			origin: OriginFor<T>,
+			asset_location: Box<VersionedMultiLocation>,
+			#[pallet::compact] min_units_per_second: u128,
+			#[pallet::compact] max_units_per_second: u128,
+			smoothing_window: u32,
+		) -> DispatchResult {
+			T::ManagerOrigin::ensure_origin(origin)?;
+
+			let v3_asset_loc = MultiLocation::try_from(*asset_location)
+				.map_err(|_| Error::<T>::MultiLocationNotSupported)?;
+			let asset_location = VersionedMultiLocation::V3(v3_asset_loc);
+
+			ensure!(
+				asset_location != T::NativeAssetLocation::get().into_versioned(),
+				Error::<T>::NativeAssetRelated
+			);
+
+			ensure!(
+				AssetLocationToId::<T>::contains_key(&asset_location),
+				Error::<T>::AssetDoesNotExist
+			);
+
+			ensure!(min_units_per_second <= max_units_per_second, Error::<T>::InvalidRateBounds);
+
+			ensure!(
+				AssetLocationDynamicRate::<T>::contains_key(&asset_location) ||
+					AssetLocationDynamicRate::<T>::count() < T::MaxDynamicRateAssets::get(),
+				Error::<T>::TooManyDynamicRateAssets
+			);
+
+			let params =
+				DynamicRateParams { min_units_per_second, max_units_per_second, smoothing_window };
+			AssetLocationDynamicRate::<T>::insert(&asset_location, params);
+			Self::update_derived_rate(&asset_location, &params);
+
+			Self::deposit_event(Event::DynamicExecutionRateSet {
+				asset_location,
+				min_units_per_second,
+				max_units_per_second,
+				smoothing_window,
+			});
+			Ok(())
+		}
+
+		/// Stop deriving the units per second of a given AssetLocation from its DEX price.
+		///
+		/// The static `units per second`, if any, are used again.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::remove_dynamic_execution_rate())]
+		pub fn remove_dynamic_execution_rate(
+			origin: OriginFor<T>,
+			asset_location: Box<VersionedMultiLocation>,
+		) -> DispatchResult {
+			T::ManagerOrigin::ensure_origin(origin)?;
+
+			let v3_asset_loc = MultiLocation::try_from(*asset_location)
+				.map_err(|_| Error::<T>::MultiLocationNotSupported)?;
+			let asset_location = VersionedMultiLocation::V3(v3_asset_loc);
+
+			ensure!(
+				AssetLocationDynamicRate::<T>::take(&asset_location).is_some(),
+				Error::<T>::DynamicRateNotSet
+			);
+			DerivedUnitsPerSecond::<T>::remove(&asset_location);
+
+			Self::deposit_event(Event::DynamicExecutionRateRemoved { asset_location });
+			Ok(())
+		}
+
+		/// Create the local asset of a foreign asset, set its metadata and register its
+		/// location.
+		///
+		/// If `execution_rate` is given, the asset is also registered as payment currency. Either
+		/// all of it succeeds or nothing is changed.
+		#[allow(clippy::too_many_arguments)]
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::register_foreign_asset())]
+		pub fn register_foreign_asset(
+			origin: OriginFor<T>,
+			asset_location: Box<VersionedMultiLocation>,
+			asset_id: T::AssetId,
+			name: BoundedVec<u8, T::StringLimit>,
+			symbol: BoundedVec<u8, T::StringLimit>,
+			decimals: u8,
+			min_balance: T::Balance,
+			is_sufficient: bool,
+			execution_rate: Option<ExecutionRate>,
+		) -> DispatchResult {
+			T::ManagerOrigin::ensure_origin(origin)?;
+
+			let asset_location = Self::do_register_asset_location(*asset_location, asset_id)?;
+
+			T::AssetRegistrar::create_foreign_asset(
+				asset_id,
+				is_sufficient,
+				ForeignAssetMetadata {
+					name: name.into_inner(),
+					symbol: symbol.into_inner(),
+					decimals,
+					min_balance,
+				},
+			)?;
+
+			if let Some(ExecutionRate { units_per_second, units_per_byte }) = execution_rate {
+				AssetLocationExecutionRate::<T>::insert(
+					&asset_location,
+					ExecutionRate { units_per_second, units_per_byte },
+				);
+				Self::deposit_event(Event::ExecutionRateChanged {
+					asset_location: asset_location.clone(),
+					units_per_second,
+					units_per_byte,
+				});
+			}
+
+			Self::deposit_event(Event::ForeignAssetRegistered {
+				asset_location,
+				asset_id,
+				is_sufficient,
+				min_balance,
+			});
+			Ok(())
+		}
+
+		/// Stop accepting the asset via XCM, neither as deposit nor as execution payment.
+		///
+		/// Its location mapping and payment rate are kept, so it can be resumed later on.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::pause_asset())]
+		pub fn pause_asset(origin: OriginFor<T>, asset_id: T::AssetId) -> DispatchResult {
+			T::ManagerOrigin::ensure_origin(origin)?;
+
+			ensure!(AssetIdToLocation::<T>::contains_key(asset_id), Error::<T>::AssetDoesNotExist);
+			ensure!(!PausedAssets::<T>::contains_key(asset_id), Error::<T>::AssetAlreadyPaused);
+
+			PausedAssets::<T>::insert(asset_id, ());
+
+			Self::deposit_event(Event::AssetPaused { asset_id });
+			Ok(())
+		}
+
+		/// Accept the paused asset via XCM again.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::resume_asset())]
+		pub fn resume_asset(origin: OriginFor<T>, asset_id: T::AssetId) -> DispatchResult {
+			T::ManagerOrigin::ensure_origin(origin)?;
+
+			ensure!(PausedAssets::<T>::take(asset_id).is_some(), Error::<T>::AssetNotPaused);
+
+			Self::deposit_event(Event::AssetResumed { asset_id });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Whether the asset is currently not accepted via XCM.
+		pub fn is_asset_paused(asset_id: T::AssetId) -> bool {
+			PausedAssets::<T>::contains_key(asset_id)
+		}
+
+		/// Registers the mapping between `asset_id` and `asset_location`, returning the stored
+		/// location.
+		fn do_register_asset_location(
+			asset_location: VersionedMultiLocation,
+			asset_id: T::AssetId,
+		) -> Result<VersionedMultiLocation, DispatchError> {
+			ensure!(asset_id != T::NativeAssetId::get(), Error::<T>::NativeAssetRelated);
+
+			// Ensure such an assetId does not exist
+			ensure!(
+				!AssetIdToLocation::<T>::contains_key(asset_id),
+				Error::<T>::AssetAlreadyRegistered
+			);
+
+			let v3_asset_loc = MultiLocation::try_from(asset_location)
+				.map_err(|_| Error::<T>::MultiLocationNotSupported)?;
+			let asset_location = VersionedMultiLocation::V3(v3_asset_loc);
+
+			ensure!(
+				asset_location != T::NativeAssetLocation::get().into_versioned(),
+				Error::<T>::NativeAssetRelated
+			);
+
+			AssetIdToLocation::<T>::insert(asset_id, asset_location.clone());
+			AssetLocationToId::<T>::insert(&asset_location, asset_id);
+
+			Ok(asset_location)
+		}
+
+		/// Samples the DEX price of the asset and folds it into its derived units per second.
+		///
+		/// The derived value is removed if there is no pool to sample.
+		pub(crate) fn update_derived_rate(
+			asset_location: &VersionedMultiLocation,
+			params: &DynamicRateParams,
+		) {
+			let spot = AssetLocationToId::<T>::get(asset_location)
+				.and_then(T::PriceOracle::native_reserves)
+				.and_then(|(asset_reserve, native_reserve)| {
+					Self::spot_units_per_second(asset_reserve, native_reserve)
+				});
+
+			DerivedUnitsPerSecond::<T>::mutate(asset_location, |derived| {
+				*derived = match (spot, *derived) {
+					(None, _) => None,
+					(Some(spot), Some(previous)) if params.smoothing_window > 1 => {
+						// Move by at least one unit, otherwise price changes smaller than the
+						// window would never be picked up
+						let step = |diff: u128| (diff / u128::from(params.smoothing_window)).max(1);
+						let smoothed = match spot.cmp(&previous) {
+							Ordering::Greater => previous.saturating_add(step(spot - previous)),
+							Ordering::Less => previous.saturating_sub(step(previous - spot)),
+							Ordering::Equal => previous,
+						};
+						Some(smoothed)
+					},
+					(Some(spot), _) => Some(spot),
+				}
+				.map(|units| units.clamp(params.min_units_per_second, params.max_units_per_second));
+			});
+		}
+
+		/// Native units per second converted into the asset at the pool price, or `None` if
+		/// either reserve is empty.
+		fn spot_units_per_second(asset_reserve: u128, native_reserve: u128) -> Option<u128> {
+			if asset_reserve == 0 || native_reserve == 0 {
+				return None
+			}
+			Some(
+				multiply_by_rational_with_rounding(
+					T::NativeUnitsPerSecond::get(),
+					asset_reserve,
+					native_reserve,
+					Rounding::Up,
+				)
+				.unwrap_or(u128::MAX),
+			)
+		}
 	}
 }
 
+/// Converts between asset locations and local asset Ids.
+///
+/// Locations of paused assets aren't converted, so XCM deposits of them are rejected.
 pub struct MultiLocationToAssetId<T: Config>(PhantomData<T>);
 
 impl<T: Config> XCMConvert<MultiLocation, T::AssetId> for MultiLocationToAssetId<T>
@@ -384,10 +869,9 @@ where
 {
 	fn convert_ref(location: impl Borrow<MultiLocation>) -> Result<T::AssetId, ()> {
 		let location = *location.borrow();
-		if let Some(asset_id) = Pallet::<T>::get_asset_id(location) {
-			Ok(asset_id)
-		} else {
-			Err(())
+		match Pallet::<T>::get_asset_id(location) {
+			Some(asset_id) if !Pallet::<T>::is_asset_paused(asset_id) => Ok(asset_id),
+			_ => Err(()),
 		}
 	}
 
@@ -21,10 +21,22 @@ use frame_support::{
 	dispatch::GetStorageVersion,
 	log,
 	pallet_prelude::*,
+	storage_alias,
 	traits::{Get, OnRuntimeUpgrade},
 };
 use sp_std::{marker::PhantomData, vec::Vec};
-use xcm::IntoVersion;
+use xcm::{IntoVersion, VersionedMultiLocation};
+
+/// Storages in the layout used before execution rates were introduced
+pub mod v2 {
+	use super::*;
+
+	/// Stores the units per second for local execution for a AssetLocation.
+	#[storage_alias]
+	pub type AssetLocationUnitsPerSecond<T: Config> =
+		StorageMap<Pallet<T>, Twox64Concat, VersionedMultiLocation, u128>;
+}
+use v2::AssetLocationUnitsPerSecond;
 
 pub struct MigrationXcmV3<T: Config>(PhantomData<T>);
 impl<T: Config> OnRuntimeUpgrade for MigrationXcmV3<T> {
@@ -101,7 +113,6 @@ impl<T: Config> OnRuntimeUpgrade for MigrationXcmV3<T> {
 	fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
 		assert_eq!(Pallet::<T>::on_chain_storage_version(), 2);
 
-		use xcm::VersionedMultiLocation;
 		let legacy_id_to_location_entries: Vec<(T::AssetId, VersionedMultiLocation)> =
 			Decode::decode(&mut state.as_ref())
 				.map_err(|_| "Cannot decode data from pre_upgrade")?;
@@ -121,3 +132,56 @@ impl<T: Config> OnRuntimeUpgrade for MigrationXcmV3<T> {
 		Ok(())
 	}
 }
+
+/// Moves the units per second of `AssetLocationUnitsPerSecond` into `AssetLocationExecutionRate`,
+/// without charging for proof size.
+pub struct MigrationExecutionRates<T: Config>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for MigrationExecutionRates<T> {
+	fn on_runtime_upgrade() -> Weight {
+		let version = Pallet::<T>::on_chain_storage_version();
+		let mut consumed_weight = T::DbWeight::get().reads(1);
+		if version != 2 {
+			return consumed_weight
+		}
+
+		for (location, units_per_second) in AssetLocationUnitsPerSecond::<T>::drain() {
+			consumed_weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+
+			AssetLocationExecutionRate::<T>::insert(
+				location,
+				ExecutionRate { units_per_second, units_per_byte: 0 },
+			);
+		}
+
+		StorageVersion::new(3).put::<Pallet<T>>();
+		consumed_weight.saturating_accrue(T::DbWeight::get().writes(1));
+
+		consumed_weight
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+		let units_per_second_entries: Vec<_> = AssetLocationUnitsPerSecond::<T>::iter().collect();
+
+		Ok(units_per_second_entries.encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+		assert_eq!(Pallet::<T>::on_chain_storage_version(), 3);
+
+		let units_per_second_entries: Vec<(VersionedMultiLocation, u128)> =
+			Decode::decode(&mut state.as_ref())
+				.map_err(|_| "Cannot decode data from pre_upgrade")?;
+
+		assert_eq!(AssetLocationUnitsPerSecond::<T>::iter().count(), 0);
+		for (location, units_per_second) in units_per_second_entries {
+			assert_eq!(
+				AssetLocationExecutionRate::<T>::get(&location),
+				Some(ExecutionRate { units_per_second, units_per_byte: 0 })
+			);
+		}
+
+		Ok(())
+	}
+}
@@ -16,17 +16,25 @@
 // You should have received a copy of the GNU General Public License
 // along with Astar. If not, see <http://www.gnu.org/licenses/>.
 
-use crate::{self as pallet_xc_asset_config};
+use crate::{
+	self as pallet_xc_asset_config, ForeignAssetMetadata, ForeignAssetRegistrar, NativePriceOracle,
+};
 use xcm::latest::prelude::{Junction::GeneralKey, MultiLocation, X1};
 
-use frame_support::{construct_runtime, parameter_types, weights::Weight};
+use frame_support::{
+	construct_runtime, parameter_types,
+	traits::{ConstU128, ConstU32},
+	weights::Weight,
+};
 use sp_core::H256;
+use sp_std::{cell::RefCell, collections::btree_map::BTreeMap};
 
 use frame_system::EnsureRoot;
 use sp_io::TestExternalities;
 use sp_runtime::{
 	testing::Header,
 	traits::{BlakeTwo256, IdentityLookup},
+	DispatchError, DispatchResult,
 };
 
 type BlockNumber = u64;
@@ -120,9 +128,40 @@ impl pallet_xc_asset_config::Config for Test {
 	type NativeAssetId = GetNativeAssetId;
 	type NativeAssetLocation = SelfReserveLocation;
 	type ManagerOrigin = EnsureRoot<AccountId>;
+	type Balance = Balance;
+	type AssetRegistrar = MockAssetRegistrar;
+	type StringLimit = ConstU32<8>;
+	type PriceOracle = MockPriceOracle;
+	type NativeUnitsPerSecond = ConstU128<1_000>;
+	type MaxDynamicRateAssets = ConstU32<2>;
 	type WeightInfo = crate::weights::SubstrateWeight<Self>;
 }
 
+thread_local! {
+	static FOREIGN_ASSETS: RefCell<BTreeMap<AssetId, (bool, ForeignAssetMetadata<Balance>)>> =
+		RefCell::new(BTreeMap::new());
+	static POOLS: RefCell<BTreeMap<AssetId, (u128, u128)>> = RefCell::new(BTreeMap::new());
+}
+
+/// Price oracle backed by pools set up in tests.
+pub struct MockPriceOracle;
+
+impl MockPriceOracle {
+	/// Sets the `(asset reserve, native reserve)` of the pool of `asset_id`, `None` removes it.
+	pub fn set_pool(asset_id: AssetId, reserves: Option<(u128, u128)>) {
+		POOLS.with(|pools| match reserves {
+			Some(reserves) => pools.borrow_mut().insert(asset_id, reserves),
+			None => pools.borrow_mut().remove(&asset_id),
+		});
+	}
+}
+
+impl NativePriceOracle<AssetId> for MockPriceOracle {
+	fn native_reserves(asset_id: AssetId) -> Option<(u128, u128)> {
+		POOLS.with(|pools| pools.borrow().get(&asset_id).copied())
+	}
+}
+
 pub struct ExternalityBuilder;
 
 impl ExternalityBuilder {
@@ -134,3 +173,35 @@ impl ExternalityBuilder {
 		ext
 	}
 }
+
+/// Asset registrar keeping the created assets in memory.
+pub struct MockAssetRegistrar;
+
+impl MockAssetRegistrar {
+	/// Returns whether the asset was created as sufficient, `None` if it doesn't exist.
+	pub fn is_sufficient(asset_id: AssetId) -> Option<bool> {
+		FOREIGN_ASSETS
+			.with(|assets| assets.borrow().get(&asset_id).map(|(sufficient, _)| *sufficient))
+	}
+}
+
+impl ForeignAssetRegistrar<AssetId, Balance> for MockAssetRegistrar {
+	fn create_foreign_asset(
+		asset_id: AssetId,
+		is_sufficient: bool,
+		metadata: ForeignAssetMetadata<Balance>,
+	) -> DispatchResult {
+		FOREIGN_ASSETS.with(|assets| {
+			let mut assets = assets.borrow_mut();
+			if assets.contains_key(&asset_id) {
+				return Err(DispatchError::Other("asset already exists"))
+			}
+			assets.insert(asset_id, (is_sufficient, metadata));
+			Ok(())
+		})
+	}
+
+	fn foreign_asset_metadata(asset_id: AssetId) -> Option<ForeignAssetMetadata<Balance>> {
+		FOREIGN_ASSETS.with(|assets| assets.borrow().get(&asset_id).map(|(_, m)| m.clone()))
+	}
+}
@@ -20,9 +20,16 @@ use super::{
 	pallet::{Error, Event},
 	*,
 };
-use frame_support::{assert_noop, assert_ok, WeakBoundedVec};
+use frame_support::{
+	assert_noop, assert_ok,
+	dispatch::GetStorageVersion,
+	pallet_prelude::StorageVersion,
+	traits::{Get, Hooks, OnRuntimeUpgrade},
+	WeakBoundedVec,
+};
 use mock::*;
-use sp_runtime::traits::BadOrigin;
+use sp_runtime::{traits::BadOrigin, DispatchError};
+use xc_asset_config_runtime_api::{ForeignAssetFeeRate, ForeignAssetInfo};
 use xcm::latest::prelude::*;
 
 use xcm::{v3::MultiLocation, VersionedMultiLocation};
@@ -100,9 +107,7 @@ fn register_asset_location_and_units_per_sec_is_ok() {
 			AssetLocationToId::<Test>::get(asset_location.into_versioned()).unwrap(),
 			asset_id
 		);
-		assert!(!AssetLocationUnitsPerSecond::<Test>::contains_key(
-			asset_location.into_versioned()
-		));
+		assert!(!AssetLocationExecutionRate::<Test>::contains_key(asset_location.into_versioned()));
 
 		// Register unit per second rate and verify storage
 		let units: u128 = 7 * 11 * 13 * 17 * 29;
@@ -118,8 +123,8 @@ fn register_asset_location_and_units_per_sec_is_ok() {
 			},
 		));
 		assert_eq!(
-			AssetLocationUnitsPerSecond::<Test>::get(asset_location.into_versioned()).unwrap(),
-			units
+			AssetLocationExecutionRate::<Test>::get(asset_location.into_versioned()).unwrap(),
+			ExecutionRate { units_per_second: units, units_per_byte: 0 }
 		);
 	})
 }
@@ -197,11 +202,11 @@ fn change_asset_location_is_ok() {
 		);
 
 		// This should have been deleted
-		assert!(!AssetLocationUnitsPerSecond::<Test>::contains_key(
-			asset_location.into_versioned()
-		));
+		assert!(!AssetLocationExecutionRate::<Test>::contains_key(asset_location.into_versioned()));
 		assert_eq!(
-			AssetLocationUnitsPerSecond::<Test>::get(new_asset_location.into_versioned()).unwrap(),
+			AssetLocationExecutionRate::<Test>::get(new_asset_location.into_versioned())
+				.unwrap()
+				.units_per_second,
 			units
 		);
 	})
@@ -235,9 +240,7 @@ fn remove_payment_asset_is_ok() {
 		System::assert_last_event(mock::RuntimeEvent::XcAssetConfig(
 			Event::SupportedAssetRemoved { asset_location: asset_location.into_versioned() },
 		));
-		assert!(!AssetLocationUnitsPerSecond::<Test>::contains_key(
-			asset_location.into_versioned()
-		));
+		assert!(!AssetLocationExecutionRate::<Test>::contains_key(asset_location.into_versioned()));
 
 		// Repeated calls don't do anything
 		assert_ok!(XcAssetConfig::remove_payment_asset(
@@ -277,9 +280,7 @@ fn remove_asset_is_ok() {
 		// Assert that storage is empty after successful removal
 		assert!(!AssetIdToLocation::<Test>::contains_key(asset_id));
 		assert!(!AssetLocationToId::<Test>::contains_key(asset_location.into_versioned()));
-		assert!(!AssetLocationUnitsPerSecond::<Test>::contains_key(
-			asset_location.into_versioned()
-		));
+		assert!(!AssetLocationExecutionRate::<Test>::contains_key(asset_location.into_versioned()));
 	})
 }
 
@@ -417,3 +418,545 @@ fn incompatible_versioned_multilocations_are_not_ok() {
 		);
 	})
 }
+
+#[test]
+fn execution_rate_is_ok() {
+	ExternalityBuilder::build().execute_with(|| {
+		let asset_location = MultiLocation::parent();
+		let asset_id = 17;
+
+		assert_noop!(
+			XcAssetConfig::set_asset_execution_rate(
+				RuntimeOrigin::signed(1),
+				Box::new(asset_location.into_versioned()),
+				10,
+				2
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			XcAssetConfig::set_asset_execution_rate(
+				RuntimeOrigin::root(),
+				Box::new(asset_location.into_versioned()),
+				10,
+				2
+			),
+			Error::<Test>::AssetDoesNotExist
+		);
+
+		assert_ok!(XcAssetConfig::register_asset_location(
+			RuntimeOrigin::root(),
+			Box::new(asset_location.into_versioned()),
+			asset_id
+		));
+		assert_ok!(XcAssetConfig::set_asset_execution_rate(
+			RuntimeOrigin::root(),
+			Box::new(asset_location.into_versioned()),
+			10,
+			2
+		));
+		System::assert_last_event(mock::RuntimeEvent::XcAssetConfig(Event::ExecutionRateChanged {
+			asset_location: asset_location.into_versioned(),
+			units_per_second: 10,
+			units_per_byte: 2,
+		}));
+		assert_eq!(
+			XcAssetConfig::get_execution_rate(asset_location),
+			Some(ExecutionRate { units_per_second: 10, units_per_byte: 2 })
+		);
+
+		// Changing the units per second keeps the units per byte
+		assert_ok!(XcAssetConfig::set_asset_units_per_second(
+			RuntimeOrigin::root(),
+			Box::new(asset_location.into_versioned()),
+			20
+		));
+		assert_eq!(
+			XcAssetConfig::get_execution_rate(asset_location),
+			Some(ExecutionRate { units_per_second: 20, units_per_byte: 2 })
+		);
+		assert_eq!(XcAssetConfig::get_units_per_second(asset_location), Some(20));
+	})
+}
+
+#[test]
+fn execution_rate_migration_is_ok() {
+	ExternalityBuilder::build().execute_with(|| {
+		let asset_location = MultiLocation::parent().into_versioned();
+		StorageVersion::new(2).put::<XcAssetConfig>();
+		migrations::v2::AssetLocationUnitsPerSecond::<Test>::insert(&asset_location, 1_234);
+
+		migrations::MigrationExecutionRates::<Test>::on_runtime_upgrade();
+
+		assert_eq!(XcAssetConfig::on_chain_storage_version(), StorageVersion::new(3));
+		assert!(!migrations::v2::AssetLocationUnitsPerSecond::<Test>::contains_key(
+			&asset_location
+		));
+		assert_eq!(
+			AssetLocationExecutionRate::<Test>::get(&asset_location),
+			Some(ExecutionRate { units_per_second: 1_234, units_per_byte: 0 })
+		);
+
+		// Running it again is a no-op
+		migrations::v2::AssetLocationUnitsPerSecond::<Test>::insert(&asset_location, 1);
+		migrations::MigrationExecutionRates::<Test>::on_runtime_upgrade();
+		assert_eq!(
+			AssetLocationExecutionRate::<Test>::get(&asset_location),
+			Some(ExecutionRate { units_per_second: 1_234, units_per_byte: 0 })
+		);
+	})
+}
+
+#[test]
+fn dynamic_execution_rate_is_ok() {
+	ExternalityBuilder::build().execute_with(|| {
+		let asset_location = MultiLocation::parent();
+		let asset_id = 5;
+		assert_ok!(XcAssetConfig::register_asset_location(
+			RuntimeOrigin::root(),
+			Box::new(asset_location.into_versioned()),
+			asset_id
+		));
+		assert_ok!(XcAssetConfig::set_asset_execution_rate(
+			RuntimeOrigin::root(),
+			Box::new(asset_location.into_versioned()),
+			10,
+			2
+		));
+
+		// 1 native token is worth 2 units of the asset
+		MockPriceOracle::set_pool(asset_id, Some((2_000, 1_000)));
+		assert_ok!(XcAssetConfig::set_dynamic_execution_rate(
+			RuntimeOrigin::root(),
+			Box::new(asset_location.into_versioned()),
+			100,
+			10_000,
+			0
+		));
+		System::assert_last_event(mock::RuntimeEvent::XcAssetConfig(
+			Event::DynamicExecutionRateSet {
+				asset_location: asset_location.into_versioned(),
+				min_units_per_second: 100,
+				max_units_per_second: 10_000,
+				smoothing_window: 0,
+			},
+		));
+
+		// Units per second are derived right away, units per byte are kept
+		assert_eq!(
+			XcAssetConfig::get_execution_rate(asset_location),
+			Some(ExecutionRate { units_per_second: 2_000, units_per_byte: 2 })
+		);
+
+		// Price moves are picked up at the start of the block, within the bounds
+		MockPriceOracle::set_pool(asset_id, Some((5_000, 1_000)));
+		XcAssetConfig::on_initialize(2);
+		assert_eq!(XcAssetConfig::get_units_per_second(asset_location), Some(5_000));
+
+		MockPriceOracle::set_pool(asset_id, Some((20_000, 1_000)));
+		XcAssetConfig::on_initialize(3);
+		assert_eq!(XcAssetConfig::get_units_per_second(asset_location), Some(10_000));
+
+		MockPriceOracle::set_pool(asset_id, Some((1, 1_000)));
+		XcAssetConfig::on_initialize(4);
+		assert_eq!(XcAssetConfig::get_units_per_second(asset_location), Some(100));
+
+		// Static rate is used while there is no pool
+		MockPriceOracle::set_pool(asset_id, None);
+		XcAssetConfig::on_initialize(5);
+		assert_eq!(
+			XcAssetConfig::get_execution_rate(asset_location),
+			Some(ExecutionRate { units_per_second: 10, units_per_byte: 2 })
+		);
+
+		// Removing the dynamic rate goes back to the static rate for good
+		MockPriceOracle::set_pool(asset_id, Some((2_000, 1_000)));
+		XcAssetConfig::on_initialize(6);
+		assert_eq!(XcAssetConfig::get_units_per_second(asset_location), Some(2_000));
+		assert_ok!(XcAssetConfig::remove_dynamic_execution_rate(
+			RuntimeOrigin::root(),
+			Box::new(asset_location.into_versioned()),
+		));
+		System::assert_last_event(mock::RuntimeEvent::XcAssetConfig(
+			Event::DynamicExecutionRateRemoved { asset_location: asset_location.into_versioned() },
+		));
+		XcAssetConfig::on_initialize(7);
+		assert_eq!(XcAssetConfig::get_units_per_second(asset_location), Some(10));
+		assert!(!DerivedUnitsPerSecond::<Test>::contains_key(asset_location.into_versioned()));
+	})
+}
+
+#[test]
+fn dynamic_execution_rate_is_smoothed() {
+	ExternalityBuilder::build().execute_with(|| {
+		let asset_location = MultiLocation::parent();
+		let asset_id = 5;
+		assert_ok!(XcAssetConfig::register_asset_location(
+			RuntimeOrigin::root(),
+			Box::new(asset_location.into_versioned()),
+			asset_id
+		));
+
+		// Without a static rate, the asset is a payment asset only while the pool exists
+		assert_ok!(XcAssetConfig::set_dynamic_execution_rate(
+			RuntimeOrigin::root(),
+			Box::new(asset_location.into_versioned()),
+			0,
+			u128::MAX,
+			4
+		));
+		assert!(XcAssetConfig::get_execution_rate(asset_location).is_none());
+
+		// First sample is taken as is
+		MockPriceOracle::set_pool(asset_id, Some((2_000, 1_000)));
+		XcAssetConfig::on_initialize(2);
+		assert_eq!(
+			XcAssetConfig::get_execution_rate(asset_location),
+			Some(ExecutionRate { units_per_second: 2_000, units_per_byte: 0 })
+		);
+
+		// Later samples move the rate by a quarter of the difference
+		MockPriceOracle::set_pool(asset_id, Some((6_000, 1_000)));
+		XcAssetConfig::on_initialize(3);
+		assert_eq!(XcAssetConfig::get_units_per_second(asset_location), Some(3_000));
+		XcAssetConfig::on_initialize(4);
+		assert_eq!(XcAssetConfig::get_units_per_second(asset_location), Some(3_750));
+
+		MockPriceOracle::set_pool(asset_id, Some((1_000, 1_000)));
+		XcAssetConfig::on_initialize(5);
+		assert_eq!(XcAssetConfig::get_units_per_second(asset_location), Some(3_063));
+
+		// Differences smaller than the window still move the rate, by one unit per block
+		MockPriceOracle::set_pool(asset_id, Some((3_061, 1_000)));
+		XcAssetConfig::on_initialize(6);
+		assert_eq!(XcAssetConfig::get_units_per_second(asset_location), Some(3_062));
+		XcAssetConfig::on_initialize(7);
+		assert_eq!(XcAssetConfig::get_units_per_second(asset_location), Some(3_061));
+		XcAssetConfig::on_initialize(8);
+		assert_eq!(XcAssetConfig::get_units_per_second(asset_location), Some(3_061));
+
+		// Removing the payment asset removes the dynamic rate as well
+		assert_ok!(XcAssetConfig::remove_payment_asset(
+			RuntimeOrigin::root(),
+			Box::new(asset_location.into_versioned()),
+		));
+		assert!(XcAssetConfig::get_execution_rate(asset_location).is_none());
+		assert!(!AssetLocationDynamicRate::<Test>::contains_key(asset_location.into_versioned()));
+	})
+}
+
+#[test]
+fn dynamic_execution_rate_is_not_ok() {
+	ExternalityBuilder::build().execute_with(|| {
+		let asset_location = MultiLocation::parent();
+
+		assert_noop!(
+			XcAssetConfig::set_dynamic_execution_rate(
+				RuntimeOrigin::signed(1),
+				Box::new(asset_location.into_versioned()),
+				0,
+				1,
+				0
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			XcAssetConfig::set_dynamic_execution_rate(
+				RuntimeOrigin::root(),
+				Box::new(asset_location.into_versioned()),
+				0,
+				1,
+				0
+			),
+			Error::<Test>::AssetDoesNotExist
+		);
+
+		let locations: Vec<_> =
+			(1..=3).map(|id| MultiLocation::new(1, X1(Parachain(id)))).collect();
+		for (asset_id, location) in locations.iter().enumerate() {
+			assert_ok!(XcAssetConfig::register_asset_location(
+				RuntimeOrigin::root(),
+				Box::new(location.into_versioned()),
+				asset_id as u128 + 1
+			));
+		}
+
+		assert_noop!(
+			XcAssetConfig::set_dynamic_execution_rate(
+				RuntimeOrigin::root(),
+				Box::new(locations[0].into_versioned()),
+				2,
+				1,
+				0
+			),
+			Error::<Test>::InvalidRateBounds
+		);
+		assert_noop!(
+			XcAssetConfig::remove_dynamic_execution_rate(
+				RuntimeOrigin::root(),
+				Box::new(locations[0].into_versioned()),
+			),
+			Error::<Test>::DynamicRateNotSet
+		);
+
+		// Only `MaxDynamicRateAssets` assets can have a dynamic rate, updates are still possible
+		for location in &locations[..2] {
+			assert_ok!(XcAssetConfig::set_dynamic_execution_rate(
+				RuntimeOrigin::root(),
+				Box::new(location.into_versioned()),
+				0,
+				1,
+				0
+			));
+		}
+		assert_noop!(
+			XcAssetConfig::set_dynamic_execution_rate(
+				RuntimeOrigin::root(),
+				Box::new(locations[2].into_versioned()),
+				0,
+				1,
+				0
+			),
+			Error::<Test>::TooManyDynamicRateAssets
+		);
+		assert_ok!(XcAssetConfig::set_dynamic_execution_rate(
+			RuntimeOrigin::root(),
+			Box::new(locations[1].into_versioned()),
+			0,
+			2,
+			0
+		));
+
+		// Removing the asset frees up a slot
+		assert_ok!(XcAssetConfig::remove_asset(RuntimeOrigin::root(), 1));
+		assert_ok!(XcAssetConfig::set_dynamic_execution_rate(
+			RuntimeOrigin::root(),
+			Box::new(locations[2].into_versioned()),
+			0,
+			1,
+			0
+		));
+	})
+}
+
+#[test]
+fn register_foreign_asset_is_ok() {
+	ExternalityBuilder::build().execute_with(|| {
+		let asset_location = MultiLocation::parent();
+		let asset_id = 7;
+
+		assert_ok!(XcAssetConfig::register_foreign_asset(
+			RuntimeOrigin::root(),
+			Box::new(asset_location.into_versioned()),
+			asset_id,
+			b"Polkadot".to_vec().try_into().unwrap(),
+			b"DOT".to_vec().try_into().unwrap(),
+			10,
+			1_000,
+			true,
+			Some(ExecutionRate { units_per_second: 10, units_per_byte: 2 }),
+		));
+		System::assert_has_event(mock::RuntimeEvent::XcAssetConfig(Event::ExecutionRateChanged {
+			asset_location: asset_location.into_versioned(),
+			units_per_second: 10,
+			units_per_byte: 2,
+		}));
+		System::assert_last_event(mock::RuntimeEvent::XcAssetConfig(
+			Event::ForeignAssetRegistered {
+				asset_location: asset_location.into_versioned(),
+				asset_id,
+				is_sufficient: true,
+				min_balance: 1_000,
+			},
+		));
+
+		assert_eq!(XcAssetConfig::get_xc_asset_location(asset_id), Some(asset_location));
+		assert_eq!(XcAssetConfig::get_asset_id(asset_location), Some(asset_id));
+		assert_eq!(
+			XcAssetConfig::get_execution_rate(asset_location),
+			Some(ExecutionRate { units_per_second: 10, units_per_byte: 2 })
+		);
+		assert_eq!(MockAssetRegistrar::is_sufficient(asset_id), Some(true));
+
+		// Without a rate, the asset is not a payment asset
+		let other_location = MultiLocation::new(1, X1(Parachain(1000)));
+		assert_ok!(XcAssetConfig::register_foreign_asset(
+			RuntimeOrigin::root(),
+			Box::new(other_location.into_versioned()),
+			8,
+			b"Other".to_vec().try_into().unwrap(),
+			b"OTH".to_vec().try_into().unwrap(),
+			12,
+			1,
+			false,
+			None,
+		));
+		assert!(XcAssetConfig::get_execution_rate(other_location).is_none());
+		assert_eq!(MockAssetRegistrar::is_sufficient(8), Some(false));
+
+		let mut foreign_assets = XcAssetConfig::foreign_assets();
+		foreign_assets.sort_by_key(|info| info.asset_id);
+		assert_eq!(
+			foreign_assets,
+			vec![
+				ForeignAssetInfo {
+					asset_id,
+					location: asset_location.into_versioned(),
+					name: b"Polkadot".to_vec(),
+					symbol: b"DOT".to_vec(),
+					decimals: 10,
+					min_balance: 1_000,
+					fee_rate: Some(ForeignAssetFeeRate { units_per_second: 10, units_per_byte: 2 }),
+				},
+				ForeignAssetInfo {
+					asset_id: 8,
+					location: other_location.into_versioned(),
+					name: b"Other".to_vec(),
+					symbol: b"OTH".to_vec(),
+					decimals: 12,
+					min_balance: 1,
+					fee_rate: None,
+				},
+			]
+		);
+	})
+}
+
+#[test]
+fn register_foreign_asset_is_not_ok() {
+	ExternalityBuilder::build().execute_with(|| {
+		let asset_location = MultiLocation::parent();
+		let asset_id = 7;
+		let register = |origin, asset_id| {
+			XcAssetConfig::register_foreign_asset(
+				origin,
+				Box::new(asset_location.into_versioned()),
+				asset_id,
+				b"Polkadot".to_vec().try_into().unwrap(),
+				b"DOT".to_vec().try_into().unwrap(),
+				10,
+				1_000,
+				true,
+				Some(ExecutionRate { units_per_second: 10, units_per_byte: 2 }),
+			)
+		};
+
+		assert_noop!(register(RuntimeOrigin::signed(1), asset_id), BadOrigin);
+		assert_noop!(
+			register(RuntimeOrigin::root(), GetNativeAssetId::get()),
+			Error::<Test>::NativeAssetRelated
+		);
+
+		// If the local asset can't be created, the location isn't registered either
+		assert_ok!(MockAssetRegistrar::create_foreign_asset(asset_id, false, Default::default()));
+		assert_noop!(
+			register(RuntimeOrigin::root(), asset_id),
+			DispatchError::Other("asset already exists")
+		);
+		assert!(XcAssetConfig::get_asset_id(asset_location).is_none());
+		assert!(XcAssetConfig::get_execution_rate(asset_location).is_none());
+
+		assert_ok!(register(RuntimeOrigin::root(), asset_id + 1));
+		assert_noop!(
+			register(RuntimeOrigin::root(), asset_id + 1),
+			Error::<Test>::AssetAlreadyRegistered
+		);
+	})
+}
+
+#[test]
+fn pause_asset_is_ok() {
+	ExternalityBuilder::build().execute_with(|| {
+		let asset_location = MultiLocation::parent();
+		let asset_id = 17;
+		assert_ok!(XcAssetConfig::register_asset_location(
+			RuntimeOrigin::root(),
+			Box::new(asset_location.into_versioned()),
+			asset_id
+		));
+		assert_eq!(MultiLocationToAssetId::<Test>::convert_ref(asset_location), Ok(asset_id));
+
+		// Paused asset can't be converted from its location, its mapping is kept
+		assert_ok!(XcAssetConfig::pause_asset(RuntimeOrigin::root(), asset_id));
+		System::assert_last_event(mock::RuntimeEvent::XcAssetConfig(Event::AssetPaused {
+			asset_id,
+		}));
+		assert!(XcAssetConfig::is_asset_paused(asset_id));
+		assert_eq!(MultiLocationToAssetId::<Test>::convert_ref(asset_location), Err(()));
+		assert_eq!(MultiLocationToAssetId::<Test>::reverse_ref(asset_id), Ok(asset_location));
+		assert_eq!(XcAssetConfig::get_asset_id(asset_location), Some(asset_id));
+
+		assert_ok!(XcAssetConfig::resume_asset(RuntimeOrigin::root(), asset_id));
+		System::assert_last_event(mock::RuntimeEvent::XcAssetConfig(Event::AssetResumed {
+			asset_id,
+		}));
+		assert!(!XcAssetConfig::is_asset_paused(asset_id));
+		assert_eq!(MultiLocationToAssetId::<Test>::convert_ref(asset_location), Ok(asset_id));
+
+		// Removing the asset clears its paused flag
+		assert_ok!(XcAssetConfig::pause_asset(RuntimeOrigin::root(), asset_id));
+		assert_ok!(XcAssetConfig::remove_asset(RuntimeOrigin::root(), asset_id));
+		assert!(!XcAssetConfig::is_asset_paused(asset_id));
+	})
+}
+
+#[test]
+fn paused_asset_cannot_pay_for_execution() {
+	ExternalityBuilder::build().execute_with(|| {
+		let asset_location = MultiLocation::parent();
+		let asset_id = 17;
+		assert_ok!(XcAssetConfig::register_asset_location(
+			RuntimeOrigin::root(),
+			Box::new(asset_location.into_versioned()),
+			asset_id
+		));
+		assert_ok!(XcAssetConfig::set_asset_execution_rate(
+			RuntimeOrigin::root(),
+			Box::new(asset_location.into_versioned()),
+			10,
+			2
+		));
+
+		// Without a rate the weight trader rejects the asset as payment
+		assert_ok!(XcAssetConfig::pause_asset(RuntimeOrigin::root(), asset_id));
+		assert!(XcAssetConfig::get_execution_rate(asset_location).is_none());
+		assert!(XcAssetConfig::get_units_per_second(asset_location).is_none());
+
+		assert_ok!(XcAssetConfig::resume_asset(RuntimeOrigin::root(), asset_id));
+		assert_eq!(
+			XcAssetConfig::get_execution_rate(asset_location),
+			Some(ExecutionRate { units_per_second: 10, units_per_byte: 2 })
+		);
+	})
+}
+
+#[test]
+fn pause_asset_is_not_ok() {
+	ExternalityBuilder::build().execute_with(|| {
+		let asset_location = MultiLocation::parent();
+		let asset_id = 17;
+
+		assert_noop!(XcAssetConfig::pause_asset(RuntimeOrigin::signed(1), asset_id), BadOrigin);
+		assert_noop!(XcAssetConfig::resume_asset(RuntimeOrigin::signed(1), asset_id), BadOrigin);
+		assert_noop!(
+			XcAssetConfig::pause_asset(RuntimeOrigin::root(), asset_id),
+			Error::<Test>::AssetDoesNotExist
+		);
+
+		assert_ok!(XcAssetConfig::register_asset_location(
+			RuntimeOrigin::root(),
+			Box::new(asset_location.into_versioned()),
+			asset_id
+		));
+		assert_noop!(
+			XcAssetConfig::resume_asset(RuntimeOrigin::root(), asset_id),
+			Error::<Test>::AssetNotPaused
+		);
+
+		assert_ok!(XcAssetConfig::pause_asset(RuntimeOrigin::root(), asset_id));
+		assert_noop!(
+			XcAssetConfig::pause_asset(RuntimeOrigin::root(), asset_id),
+			Error::<Test>::AssetAlreadyPaused
+		);
+	})
+}
@@ -52,6 +52,13 @@ pub trait WeightInfo {
 	fn change_existing_asset_location() -> Weight;
 	fn remove_payment_asset() -> Weight;
 	fn remove_asset() -> Weight;
+	fn set_asset_execution_rate() -> Weight;
+	fn set_dynamic_execution_rate() -> Weight;
+	fn remove_dynamic_execution_rate() -> Weight;
+	fn update_dynamic_rates(n: u32, ) -> Weight;
+	fn register_foreign_asset() -> Weight;
+	fn pause_asset() -> Weight;
+	fn resume_asset() -> Weight;
 }
 
 /// Weights for pallet_xc_asset_config using the Substrate node and recommended hardware.
@@ -72,50 +79,175 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	}
 	// Storage: XcAssetConfig AssetLocationToId (r:1 w:0)
 	// Proof Skipped: XcAssetConfig AssetLocationToId (max_values: None, max_size: None, mode: Measured)
-	// Storage: XcAssetConfig AssetLocationUnitsPerSecond (r:0 w:1)
-	// Proof Skipped: XcAssetConfig AssetLocationUnitsPerSecond (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig AssetLocationExecutionRate (r:1 w:1)
+	// Proof Skipped: XcAssetConfig AssetLocationExecutionRate (max_values: None, max_size: None, mode: Measured)
 	fn set_asset_units_per_second() -> Weight {
-		// Minimum execution time: 15_297 nanoseconds.
-		Weight::from_parts(15_551_000, 0)
-			.saturating_add(Weight::from_parts(0, 2661))
-			.saturating_add(T::DbWeight::get().reads(1_u64))
+		// Minimum execution time: 17_862 nanoseconds.
+		Weight::from_parts(18_240_000, 0)
+			.saturating_add(Weight::from_parts(0, 5322))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	// Storage: XcAssetConfig AssetIdToLocation (r:1 w:1)
 	// Proof Skipped: XcAssetConfig AssetIdToLocation (max_values: None, max_size: None, mode: Measured)
-	// Storage: XcAssetConfig AssetLocationUnitsPerSecond (r:1 w:2)
-	// Proof Skipped: XcAssetConfig AssetLocationUnitsPerSecond (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig AssetLocationExecutionRate (r:1 w:2)
+	// Proof Skipped: XcAssetConfig AssetLocationExecutionRate (max_values: None, max_size: None, mode: Measured)
 	// Storage: XcAssetConfig AssetLocationToId (r:0 w:2)
 	// Proof Skipped: XcAssetConfig AssetLocationToId (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig AssetLocationDynamicRate (r:1 w:2)
+	// Proof Skipped: XcAssetConfig AssetLocationDynamicRate (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig CounterForAssetLocationDynamicRate (r:1 w:1)
+	// Proof Skipped: XcAssetConfig CounterForAssetLocationDynamicRate (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig DerivedUnitsPerSecond (r:1 w:2)
+	// Proof Skipped: XcAssetConfig DerivedUnitsPerSecond (max_values: None, max_size: None, mode: Measured)
 	fn change_existing_asset_location() -> Weight {
-		// Minimum execution time: 22_357 nanoseconds.
-		Weight::from_parts(22_572_000, 0)
-			.saturating_add(Weight::from_parts(0, 5373))
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+		// Minimum execution time: 27_913 nanoseconds.
+		Weight::from_parts(28_406_000, 0)
+			.saturating_add(Weight::from_parts(0, 8112))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
 	}
-	// Storage: XcAssetConfig AssetLocationUnitsPerSecond (r:0 w:1)
-	// Proof Skipped: XcAssetConfig AssetLocationUnitsPerSecond (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig AssetLocationExecutionRate (r:0 w:1)
+	// Proof Skipped: XcAssetConfig AssetLocationExecutionRate (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig AssetLocationDynamicRate (r:1 w:1)
+	// Proof Skipped: XcAssetConfig AssetLocationDynamicRate (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig CounterForAssetLocationDynamicRate (r:1 w:1)
+	// Proof Skipped: XcAssetConfig CounterForAssetLocationDynamicRate (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig DerivedUnitsPerSecond (r:0 w:1)
+	// Proof Skipped: XcAssetConfig DerivedUnitsPerSecond (max_values: None, max_size: None, mode: Measured)
 	fn remove_payment_asset() -> Weight {
-		// Minimum execution time: 9_707 nanoseconds.
-		Weight::from_parts(10_005_000, 0)
-			.saturating_add(Weight::from_parts(0, 0))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		// Minimum execution time: 14_212 nanoseconds.
+		Weight::from_parts(14_580_000, 0)
+			.saturating_add(Weight::from_parts(0, 3010))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	// Storage: XcAssetConfig AssetIdToLocation (r:1 w:1)
 	// Proof Skipped: XcAssetConfig AssetIdToLocation (max_values: None, max_size: None, mode: Measured)
 	// Storage: EVM AccountCodes (r:0 w:1)
 	// Proof Skipped: EVM AccountCodes (max_values: None, max_size: None, mode: Measured)
-	// Storage: XcAssetConfig AssetLocationUnitsPerSecond (r:0 w:1)
-	// Proof Skipped: XcAssetConfig AssetLocationUnitsPerSecond (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig AssetLocationExecutionRate (r:0 w:1)
+	// Proof Skipped: XcAssetConfig AssetLocationExecutionRate (max_values: None, max_size: None, mode: Measured)
 	// Storage: XcAssetConfig AssetLocationToId (r:0 w:1)
 	// Proof Skipped: XcAssetConfig AssetLocationToId (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig AssetLocationDynamicRate (r:1 w:1)
+	// Proof Skipped: XcAssetConfig AssetLocationDynamicRate (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig CounterForAssetLocationDynamicRate (r:1 w:1)
+	// Proof Skipped: XcAssetConfig CounterForAssetLocationDynamicRate (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig DerivedUnitsPerSecond (r:0 w:1)
+	// Proof Skipped: XcAssetConfig DerivedUnitsPerSecond (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig PausedAssets (r:0 w:1)
+	// Proof Skipped: XcAssetConfig PausedAssets (max_values: None, max_size: None, mode: Measured)
 	fn remove_asset() -> Weight {
-		// Minimum execution time: 18_645 nanoseconds.
-		Weight::from_parts(18_878_000, 0)
-			.saturating_add(Weight::from_parts(0, 2987))
+		// Minimum execution time: 24_387 nanoseconds.
+		Weight::from_parts(24_815_000, 0)
+			.saturating_add(Weight::from_parts(0, 5997))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	// Storage: XcAssetConfig AssetLocationToId (r:1 w:0)
+	// Proof Skipped: XcAssetConfig AssetLocationToId (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig AssetLocationExecutionRate (r:0 w:1)
+	// Proof Skipped: XcAssetConfig AssetLocationExecutionRate (max_values: None, max_size: None, mode: Measured)
+	fn set_asset_execution_rate() -> Weight {
+		// Minimum execution time: 15_614 nanoseconds.
+		Weight::from_parts(15_903_000, 0)
+			.saturating_add(Weight::from_parts(0, 2661))
 			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: XcAssetConfig AssetLocationToId (r:2 w:0)
+	// Proof Skipped: XcAssetConfig AssetLocationToId (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig AssetLocationDynamicRate (r:1 w:1)
+	// Proof Skipped: XcAssetConfig AssetLocationDynamicRate (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig CounterForAssetLocationDynamicRate (r:1 w:1)
+	// Proof Skipped: XcAssetConfig CounterForAssetLocationDynamicRate (max_values: None, max_size: None, mode: Measured)
+	// Storage: ZenlinkProtocol PairStatuses (r:1 w:0)
+	// Proof Skipped: ZenlinkProtocol PairStatuses (max_values: None, max_size: None, mode: Measured)
+	// Storage: Assets Account (r:2 w:0)
+	// Proof Skipped: Assets Account (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig DerivedUnitsPerSecond (r:1 w:1)
+	// Proof Skipped: XcAssetConfig DerivedUnitsPerSecond (max_values: None, max_size: None, mode: Measured)
+	fn set_dynamic_execution_rate() -> Weight {
+		// Minimum execution time: 41_275 nanoseconds.
+		Weight::from_parts(42_019_000, 0)
+			.saturating_add(Weight::from_parts(0, 17846))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	// Storage: XcAssetConfig AssetLocationDynamicRate (r:1 w:1)
+	// Proof Skipped: XcAssetConfig AssetLocationDynamicRate (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig CounterForAssetLocationDynamicRate (r:1 w:1)
+	// Proof Skipped: XcAssetConfig CounterForAssetLocationDynamicRate (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig DerivedUnitsPerSecond (r:0 w:1)
+	// Proof Skipped: XcAssetConfig DerivedUnitsPerSecond (max_values: None, max_size: None, mode: Measured)
+	fn remove_dynamic_execution_rate() -> Weight {
+		// Minimum execution time: 13_468 nanoseconds.
+		Weight::from_parts(13_790_000, 0)
+			.saturating_add(Weight::from_parts(0, 3010))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	// Storage: XcAssetConfig AssetLocationDynamicRate (r:17 w:0)
+	// Proof Skipped: XcAssetConfig AssetLocationDynamicRate (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig AssetLocationToId (r:16 w:0)
+	// Proof Skipped: XcAssetConfig AssetLocationToId (max_values: None, max_size: None, mode: Measured)
+	// Storage: ZenlinkProtocol PairStatuses (r:16 w:0)
+	// Proof Skipped: ZenlinkProtocol PairStatuses (max_values: None, max_size: None, mode: Measured)
+	// Storage: Assets Account (r:32 w:0)
+	// Proof Skipped: Assets Account (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig DerivedUnitsPerSecond (r:16 w:16)
+	// Proof Skipped: XcAssetConfig DerivedUnitsPerSecond (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `n` is `[0, 16]`.
+	fn update_dynamic_rates(n: u32, ) -> Weight {
+		// Minimum execution time: 2_184 nanoseconds.
+		Weight::from_parts(2_532_118, 0)
+			.saturating_add(Weight::from_parts(0, 1483))
+			// Standard Error: 14_602
+			.saturating_add(Weight::from_parts(24_807_355, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((6_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 13041).saturating_mul(n.into()))
+	}
+	// Storage: XcAssetConfig AssetIdToLocation (r:1 w:1)
+	// Proof Skipped: XcAssetConfig AssetIdToLocation (max_values: None, max_size: None, mode: Measured)
+	// Storage: Assets Asset (r:1 w:1)
+	// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	// Storage: Assets Metadata (r:1 w:1)
+	// Proof: Assets Metadata (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	// Storage: EVM AccountCodes (r:0 w:1)
+	// Proof Skipped: EVM AccountCodes (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig AssetLocationToId (r:0 w:1)
+	// Proof Skipped: XcAssetConfig AssetLocationToId (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig AssetLocationExecutionRate (r:0 w:1)
+	// Proof Skipped: XcAssetConfig AssetLocationExecutionRate (max_values: None, max_size: None, mode: Measured)
+	fn register_foreign_asset() -> Weight {
+		// Minimum execution time: 38_419 nanoseconds.
+		Weight::from_parts(39_102_000, 0)
+			.saturating_add(Weight::from_parts(0, 8793))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	// Storage: XcAssetConfig AssetIdToLocation (r:1 w:0)
+	// Proof Skipped: XcAssetConfig AssetIdToLocation (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig PausedAssets (r:1 w:1)
+	// Proof Skipped: XcAssetConfig PausedAssets (max_values: None, max_size: None, mode: Measured)
+	fn pause_asset() -> Weight {
+		// Minimum execution time: 14_926 nanoseconds.
+		Weight::from_parts(15_311_000, 0)
+			.saturating_add(Weight::from_parts(0, 5024))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: XcAssetConfig PausedAssets (r:1 w:1)
+	// Proof Skipped: XcAssetConfig PausedAssets (max_values: None, max_size: None, mode: Measured)
+	fn resume_asset() -> Weight {
+		// Minimum execution time: 12_604 nanoseconds.
+		Weight::from_parts(12_987_000, 0)
+			.saturating_add(Weight::from_parts(0, 2499))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 }
 
@@ -136,49 +268,174 @@ impl WeightInfo for () {
 	}
 	// Storage: XcAssetConfig AssetLocationToId (r:1 w:0)
 	// Proof Skipped: XcAssetConfig AssetLocationToId (max_values: None, max_size: None, mode: Measured)
-	// Storage: XcAssetConfig AssetLocationUnitsPerSecond (r:0 w:1)
-	// Proof Skipped: XcAssetConfig AssetLocationUnitsPerSecond (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig AssetLocationExecutionRate (r:1 w:1)
+	// Proof Skipped: XcAssetConfig AssetLocationExecutionRate (max_values: None, max_size: None, mode: Measured)
 	fn set_asset_units_per_second() -> Weight {
-		// Minimum execution time: 15_297 nanoseconds.
-		Weight::from_parts(15_551_000, 0)
-			.saturating_add(Weight::from_parts(0, 2661))
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
+		// Minimum execution time: 17_862 nanoseconds.
+		Weight::from_parts(18_240_000, 0)
+			.saturating_add(Weight::from_parts(0, 5322))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	// Storage: XcAssetConfig AssetIdToLocation (r:1 w:1)
 	// Proof Skipped: XcAssetConfig AssetIdToLocation (max_values: None, max_size: None, mode: Measured)
-	// Storage: XcAssetConfig AssetLocationUnitsPerSecond (r:1 w:2)
-	// Proof Skipped: XcAssetConfig AssetLocationUnitsPerSecond (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig AssetLocationExecutionRate (r:1 w:2)
+	// Proof Skipped: XcAssetConfig AssetLocationExecutionRate (max_values: None, max_size: None, mode: Measured)
 	// Storage: XcAssetConfig AssetLocationToId (r:0 w:2)
 	// Proof Skipped: XcAssetConfig AssetLocationToId (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig AssetLocationDynamicRate (r:1 w:2)
+	// Proof Skipped: XcAssetConfig AssetLocationDynamicRate (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig CounterForAssetLocationDynamicRate (r:1 w:1)
+	// Proof Skipped: XcAssetConfig CounterForAssetLocationDynamicRate (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig DerivedUnitsPerSecond (r:1 w:2)
+	// Proof Skipped: XcAssetConfig DerivedUnitsPerSecond (max_values: None, max_size: None, mode: Measured)
 	fn change_existing_asset_location() -> Weight {
-		// Minimum execution time: 22_357 nanoseconds.
-		Weight::from_parts(22_572_000, 0)
-			.saturating_add(Weight::from_parts(0, 5373))
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+		// Minimum execution time: 27_913 nanoseconds.
+		Weight::from_parts(28_406_000, 0)
+			.saturating_add(Weight::from_parts(0, 8112))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
 	}
-	// Storage: XcAssetConfig AssetLocationUnitsPerSecond (r:0 w:1)
-	// Proof Skipped: XcAssetConfig AssetLocationUnitsPerSecond (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig AssetLocationExecutionRate (r:0 w:1)
+	// Proof Skipped: XcAssetConfig AssetLocationExecutionRate (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig AssetLocationDynamicRate (r:1 w:1)
+	// Proof Skipped: XcAssetConfig AssetLocationDynamicRate (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig CounterForAssetLocationDynamicRate (r:1 w:1)
+	// Proof Skipped: XcAssetConfig CounterForAssetLocationDynamicRate (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig DerivedUnitsPerSecond (r:0 w:1)
+	// Proof Skipped: XcAssetConfig DerivedUnitsPerSecond (max_values: None, max_size: None, mode: Measured)
 	fn remove_payment_asset() -> Weight {
-		// Minimum execution time: 9_707 nanoseconds.
-		Weight::from_parts(10_005_000, 0)
-			.saturating_add(Weight::from_parts(0, 0))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		// Minimum execution time: 14_212 nanoseconds.
+		Weight::from_parts(14_580_000, 0)
+			.saturating_add(Weight::from_parts(0, 3010))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	// Storage: XcAssetConfig AssetIdToLocation (r:1 w:1)
 	// Proof Skipped: XcAssetConfig AssetIdToLocation (max_values: None, max_size: None, mode: Measured)
 	// Storage: EVM AccountCodes (r:0 w:1)
 	// Proof Skipped: EVM AccountCodes (max_values: None, max_size: None, mode: Measured)
-	// Storage: XcAssetConfig AssetLocationUnitsPerSecond (r:0 w:1)
-	// Proof Skipped: XcAssetConfig AssetLocationUnitsPerSecond (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig AssetLocationExecutionRate (r:0 w:1)
+	// Proof Skipped: XcAssetConfig AssetLocationExecutionRate (max_values: None, max_size: None, mode: Measured)
 	// Storage: XcAssetConfig AssetLocationToId (r:0 w:1)
 	// Proof Skipped: XcAssetConfig AssetLocationToId (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig AssetLocationDynamicRate (r:1 w:1)
+	// Proof Skipped: XcAssetConfig AssetLocationDynamicRate (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig CounterForAssetLocationDynamicRate (r:1 w:1)
+	// Proof Skipped: XcAssetConfig CounterForAssetLocationDynamicRate (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig DerivedUnitsPerSecond (r:0 w:1)
+	// Proof Skipped: XcAssetConfig DerivedUnitsPerSecond (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig PausedAssets (r:0 w:1)
+	// Proof Skipped: XcAssetConfig PausedAssets (max_values: None, max_size: None, mode: Measured)
 	fn remove_asset() -> Weight {
-		// Minimum execution time: 18_645 nanoseconds.
-		Weight::from_parts(18_878_000, 0)
-			.saturating_add(Weight::from_parts(0, 2987))
+		// Minimum execution time: 24_387 nanoseconds.
+		Weight::from_parts(24_815_000, 0)
+			.saturating_add(Weight::from_parts(0, 5997))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	// Storage: XcAssetConfig AssetLocationToId (r:1 w:0)
+	// Proof Skipped: XcAssetConfig AssetLocationToId (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig AssetLocationExecutionRate (r:0 w:1)
+	// Proof Skipped: XcAssetConfig AssetLocationExecutionRate (max_values: None, max_size: None, mode: Measured)
+	fn set_asset_execution_rate() -> Weight {
+		// Minimum execution time: 15_614 nanoseconds.
+		Weight::from_parts(15_903_000, 0)
+			.saturating_add(Weight::from_parts(0, 2661))
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: XcAssetConfig AssetLocationToId (r:2 w:0)
+	// Proof Skipped: XcAssetConfig AssetLocationToId (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig AssetLocationDynamicRate (r:1 w:1)
+	// Proof Skipped: XcAssetConfig AssetLocationDynamicRate (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig CounterForAssetLocationDynamicRate (r:1 w:1)
+	// Proof Skipped: XcAssetConfig CounterForAssetLocationDynamicRate (max_values: None, max_size: None, mode: Measured)
+	// Storage: ZenlinkProtocol PairStatuses (r:1 w:0)
+	// Proof Skipped: ZenlinkProtocol PairStatuses (max_values: None, max_size: None, mode: Measured)
+	// Storage: Assets Account (r:2 w:0)
+	// Proof Skipped: Assets Account (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig DerivedUnitsPerSecond (r:1 w:1)
+	// Proof Skipped: XcAssetConfig DerivedUnitsPerSecond (max_values: None, max_size: None, mode: Measured)
+	fn set_dynamic_execution_rate() -> Weight {
+		// Minimum execution time: 41_275 nanoseconds.
+		Weight::from_parts(42_019_000, 0)
+			.saturating_add(Weight::from_parts(0, 17846))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	// Storage: XcAssetConfig AssetLocationDynamicRate (r:1 w:1)
+	// Proof Skipped: XcAssetConfig AssetLocationDynamicRate (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig CounterForAssetLocationDynamicRate (r:1 w:1)
+	// Proof Skipped: XcAssetConfig CounterForAssetLocationDynamicRate (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig DerivedUnitsPerSecond (r:0 w:1)
+	// Proof Skipped: XcAssetConfig DerivedUnitsPerSecond (max_values: None, max_size: None, mode: Measured)
+	fn remove_dynamic_execution_rate() -> Weight {
+		// Minimum execution time: 13_468 nanoseconds.
+		Weight::from_parts(13_790_000, 0)
+			.saturating_add(Weight::from_parts(0, 3010))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
-}
\ No newline at end of file
+	// Storage: XcAssetConfig AssetLocationDynamicRate (r:17 w:0)
+	// Proof Skipped: XcAssetConfig AssetLocationDynamicRate (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig AssetLocationToId (r:16 w:0)
+	// Proof Skipped: XcAssetConfig AssetLocationToId (max_values: None, max_size: None, mode: Measured)
+	// Storage: ZenlinkProtocol PairStatuses (r:16 w:0)
+	// Proof Skipped: ZenlinkProtocol PairStatuses (max_values: None, max_size: None, mode: Measured)
+	// Storage: Assets Account (r:32 w:0)
+	// Proof Skipped: Assets Account (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig DerivedUnitsPerSecond (r:16 w:16)
+	// Proof Skipped: XcAssetConfig DerivedUnitsPerSecond (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `n` is `[0, 16]`.
+	fn update_dynamic_rates(n: u32, ) -> Weight {
+		// Minimum execution time: 2_184 nanoseconds.
+		Weight::from_parts(2_532_118, 0)
+			.saturating_add(Weight::from_parts(0, 1483))
+			// Standard Error: 14_602
+			.saturating_add(Weight::from_parts(24_807_355, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((6_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 13041).saturating_mul(n.into()))
+	}
+	// Storage: XcAssetConfig AssetIdToLocation (r:1 w:1)
+	// Proof Skipped: XcAssetConfig AssetIdToLocation (max_values: None, max_size: None, mode: Measured)
+	// Storage: Assets Asset (r:1 w:1)
+	// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	// Storage: Assets Metadata (r:1 w:1)
+	// Proof: Assets Metadata (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	// Storage: EVM AccountCodes (r:0 w:1)
+	// Proof Skipped: EVM AccountCodes (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig AssetLocationToId (r:0 w:1)
+	// Proof Skipped: XcAssetConfig AssetLocationToId (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig AssetLocationExecutionRate (r:0 w:1)
+	// Proof Skipped: XcAssetConfig AssetLocationExecutionRate (max_values: None, max_size: None, mode: Measured)
+	fn register_foreign_asset() -> Weight {
+		// Minimum execution time: 38_419 nanoseconds.
+		Weight::from_parts(39_102_000, 0)
+			.saturating_add(Weight::from_parts(0, 8793))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	// Storage: XcAssetConfig AssetIdToLocation (r:1 w:0)
+	// Proof Skipped: XcAssetConfig AssetIdToLocation (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcAssetConfig PausedAssets (r:1 w:1)
+	// Proof Skipped: XcAssetConfig PausedAssets (max_values: None, max_size: None, mode: Measured)
+	fn pause_asset() -> Weight {
+		// Minimum execution time: 14_926 nanoseconds.
+		Weight::from_parts(15_311_000, 0)
+			.saturating_add(Weight::from_parts(0, 5024))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: XcAssetConfig PausedAssets (r:1 w:1)
+	// Proof Skipped: XcAssetConfig PausedAssets (max_values: None, max_size: None, mode: Measured)
+	fn resume_asset() -> Weight {
+		// Minimum execution time: 12_604 nanoseconds.
+		Weight::from_parts(12_987_000, 0)
+			.saturating_add(Weight::from_parts(0, 2499))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
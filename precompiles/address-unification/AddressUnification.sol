@@ -0,0 +1,54 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The AddressUnification contract's address.
+address constant ADDRESS_UNIFICATION_ADDRESS = 0x000000000000000000000000000000000000080A;
+
+/// @dev The AddressUnification contract's instance.
+AddressUnification constant ADDRESS_UNIFICATION_CONTRACT = AddressUnification(
+    ADDRESS_UNIFICATION_ADDRESS
+);
+
+/// @author The Peaq Team
+/// @title AddressUnification Interface
+/// The interface through which solidity contracts will interact with the address-unification pallet
+/// @custom:address 0x000000000000000000000000000000000000080A
+interface AddressUnification {
+    /// Get the Substrate account an EVM address is bound to, or its default account
+    /// if the address is not linked.
+    /// selector: 0xe0b490f7
+    function getAccountId(address evmAddress) external view returns (bytes32);
+
+    /// Get the EVM address a Substrate account is bound to, or its default EVM address
+    /// if the account is not linked.
+    /// selector: 0x0232027e
+    function getEvmAddress(bytes32 accountId) external view returns (address);
+
+    /// Whether an EVM address is linked to a Substrate account.
+    /// selector: 0xfb1afbd7
+    function isLinked(address evmAddress) external view returns (bool);
+
+    /// Bind an EVM address to the caller's Substrate account.
+    /// The signature is the same EIP-712 signature `claim_account` expects.
+    /// selector: 0xd9f01604
+    function claimAccount(address evmAddress, bytes memory signature)
+        external
+        returns (bool);
+
+    /// Bind an EVM address to the caller's Substrate account with a signature which
+    /// covers the claim nonce of the address and expires after block `deadline`.
+    /// The typed data to sign is returned by the `claim_typed_data` runtime API.
+    /// selector: 0x0c61f2ff
+    function claimAccountWithDeadline(
+        address evmAddress,
+        bytes memory signature,
+        uint256 deadline
+    ) external returns (bool);
+
+    /// Get the number of claims signed by an EVM address.
+    /// selector: 0x3ee22fcd
+    function claimNonce(address evmAddress) external view returns (uint256);
+
+    /// Emitted when an EVM address is bound to a Substrate account.
+    event ClaimAccount(address indexed evmAddress, bytes32 accountId);
+}
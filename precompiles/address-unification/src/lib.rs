@@ -0,0 +1,149 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use address_unification::{Accounts, ClaimNonces, EVMAddressMapping, Eip712Signature};
+use fp_evm::PrecompileHandle;
+use frame_support::{
+	dispatch::{Dispatchable, GetDispatchInfo, PostDispatchInfo},
+	traits::ConstU32,
+};
+use pallet_evm::AddressMapping;
+use precompile_utils::{keccak256, prelude::*, solidity, EvmResult};
+use sp_core::{H160, H256, U256};
+use sp_std::marker::PhantomData;
+
+type AccountIdOf<Runtime> = <Runtime as frame_system::Config>::AccountId;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub(crate) const SELECTOR_LOG_CLAIM_ACCOUNT: [u8; 32] = keccak256!("ClaimAccount(address,bytes32)");
+
+// Accounts/EvmAddresses: Twox64Concat(8) + EvmAddress(20) + AccountId(32)
+const MAPPING_ENTRY_SIZE: usize = 60;
+// ClaimNonces: Twox64Concat(8) + EvmAddress(20) + u64(8)
+const NONCE_ENTRY_SIZE: usize = 36;
+
+type GetSignatureLimit = ConstU32<65>;
+
+pub struct AddressUnificationPrecompile<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+impl<Runtime> AddressUnificationPrecompile<Runtime>
+where
+	Runtime: address_unification::Config + pallet_evm::Config,
+	Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+	<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+	Runtime::RuntimeCall: From<address_unification::Call<Runtime>>,
+	AccountIdOf<Runtime>: From<[u8; 32]>,
+	[u8; 32]: From<AccountIdOf<Runtime>>,
+{
+	#[precompile::public("getAccountId(address)")]
+	#[precompile::view]
+	fn get_account_id(handle: &mut impl PrecompileHandle, evm_address: Address) -> EvmResult<H256> {
+		handle.record_db_read::<Runtime>(MAPPING_ENTRY_SIZE)?;
+
+		let account_id =
+			address_unification::Pallet::<Runtime>::get_account_id_or_default(&evm_address.into());
+		Ok(H256::from(<AccountIdOf<Runtime> as Into<[u8; 32]>>::into(account_id)))
+	}
+
+	#[precompile::public("getEvmAddress(bytes32)")]
+	#[precompile::view]
+	fn get_evm_address(handle: &mut impl PrecompileHandle, account_id: H256) -> EvmResult<Address> {
+		handle.record_db_read::<Runtime>(MAPPING_ENTRY_SIZE)?;
+
+		let account_id = AccountIdOf::<Runtime>::from(account_id.to_fixed_bytes());
+		Ok(address_unification::Pallet::<Runtime>::get_evm_address_or_default(&account_id).into())
+	}
+
+	#[precompile::public("isLinked(address)")]
+	#[precompile::view]
+	fn is_linked(handle: &mut impl PrecompileHandle, evm_address: Address) -> EvmResult<bool> {
+		handle.record_db_read::<Runtime>(MAPPING_ENTRY_SIZE)?;
+
+		let evm_address: H160 = evm_address.into();
+		Ok(Accounts::<Runtime>::contains_key(evm_address))
+	}
+
+	#[precompile::public("claimAccount(address,bytes)")]
+	fn claim_account(
+		handle: &mut impl PrecompileHandle,
+		evm_address: Address,
+		signature: BoundedBytes<GetSignatureLimit>,
+	) -> EvmResult<bool> {
+		let eth_signature = Self::eth_signature(signature)?;
+		let evm_address: H160 = evm_address.into();
+
+		Self::dispatch_claim(
+			handle,
+			evm_address,
+			address_unification::Call::<Runtime>::claim_account { evm_address, eth_signature },
+		)
+	}
+
+	#[precompile::public("claimAccountWithDeadline(address,bytes,uint256)")]
+	fn claim_account_with_deadline(
+		handle: &mut impl PrecompileHandle,
+		evm_address: Address,
+		signature: BoundedBytes<GetSignatureLimit>,
+		deadline: U256,
+	) -> EvmResult<bool> {
+		let eth_signature = Self::eth_signature(signature)?;
+		let evm_address: H160 = evm_address.into();
+		let deadline: Runtime::BlockNumber = u64::try_from(deadline)
+			.ok()
+			.and_then(|deadline| deadline.try_into().ok())
+			.ok_or_else(|| RevertReason::value_is_too_large("block number").in_field("deadline"))?;
+
+		Self::dispatch_claim(
+			handle,
+			evm_address,
+			address_unification::Call::<Runtime>::claim_account_with_deadline {
+				evm_address,
+				eth_signature,
+				deadline,
+			},
+		)
+	}
+
+	#[precompile::public("claimNonce(address)")]
+	#[precompile::view]
+	fn claim_nonce(handle: &mut impl PrecompileHandle, evm_address: Address) -> EvmResult<U256> {
+		handle.record_db_read::<Runtime>(NONCE_ENTRY_SIZE)?;
+
+		let evm_address: H160 = evm_address.into();
+		Ok(ClaimNonces::<Runtime>::get(evm_address).into())
+	}
+
+	fn eth_signature(signature: BoundedBytes<GetSignatureLimit>) -> MayRevert<Eip712Signature> {
+		signature
+			.as_bytes()
+			.try_into()
+			.map_err(|_| RevertReason::custom("Signature must be 65 bytes").in_field("signature"))
+	}
+
+	fn dispatch_claim(
+		handle: &mut impl PrecompileHandle,
+		evm_address: H160,
+		call: address_unification::Call<Runtime>,
+	) -> EvmResult<bool> {
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin.clone()).into(), call, 0)?;
+
+		let event = log2(
+			handle.context().address,
+			SELECTOR_LOG_CLAIM_ACCOUNT,
+			evm_address,
+			solidity::encode_event_data(H256::from(
+				<AccountIdOf<Runtime> as Into<[u8; 32]>>::into(origin),
+			)),
+		);
+		event.record(handle)?;
+
+		Ok(true)
+	}
+}
@@ -0,0 +1,226 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use crate::{mock::*, *};
+use frame_support::assert_ok;
+use precompile_utils::testing::*;
+
+fn precompiles() -> Precompiles<Runtime> {
+	PrecompilesValue::get()
+}
+
+#[test]
+fn selectors() {
+	assert!(PCall::get_account_id_selectors().contains(&0xe0b490f7));
+	assert!(PCall::get_evm_address_selectors().contains(&0x0232027e));
+	assert!(PCall::is_linked_selectors().contains(&0xfb1afbd7));
+	assert!(PCall::claim_account_selectors().contains(&0xd9f01604));
+	assert!(PCall::claim_account_with_deadline_selectors().contains(&0x0c61f2ff));
+	assert!(PCall::claim_nonce_selectors().contains(&0x3ee22fcd));
+}
+
+#[test]
+fn no_selector_exists_but_length_is_right() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				MockPeaqAccount::Alice,
+				MockPeaqAccount::EVMu1Account,
+				vec![1u8, 2u8, 3u8, 4u8],
+			)
+			.execute_reverts(|output| output == b"Unknown selector");
+	});
+}
+
+#[test]
+fn claim_account_links_caller() {
+	ExtBuilder::default().build().execute_with(|| {
+		let evm_address = AddressUnification::evm_address(&evm_secret_key());
+		let signature = AddressUnification::eth_sign(&evm_secret_key(), &MockPeaqAccount::Alice);
+
+		precompiles()
+			.prepare_test(
+				MockPeaqAccount::Alice,
+				MockPeaqAccount::EVMu1Account,
+				PCall::is_linked { evm_address: Address(evm_address) },
+			)
+			.expect_no_logs()
+			.execute_returns(false);
+
+		precompiles()
+			.prepare_test(
+				MockPeaqAccount::Alice,
+				MockPeaqAccount::EVMu1Account,
+				PCall::claim_account {
+					evm_address: Address(evm_address),
+					signature: signature.to_vec().into(),
+				},
+			)
+			.expect_log(log2(
+				MockPeaqAccount::EVMu1Account,
+				SELECTOR_LOG_CLAIM_ACCOUNT,
+				evm_address,
+				solidity::encode_event_data(H256::from(<[u8; 32]>::from(MockPeaqAccount::Alice))),
+			))
+			.execute_returns(true);
+
+		assert_eq!(AddressUnification::accounts(evm_address), Some(MockPeaqAccount::Alice));
+
+		precompiles()
+			.prepare_test(
+				MockPeaqAccount::Alice,
+				MockPeaqAccount::EVMu1Account,
+				PCall::is_linked { evm_address: Address(evm_address) },
+			)
+			.expect_no_logs()
+			.execute_returns(true);
+
+		precompiles()
+			.prepare_test(
+				MockPeaqAccount::Alice,
+				MockPeaqAccount::EVMu1Account,
+				PCall::get_account_id { evm_address: Address(evm_address) },
+			)
+			.expect_no_logs()
+			.execute_returns(H256::from(<[u8; 32]>::from(MockPeaqAccount::Alice)));
+
+		precompiles()
+			.prepare_test(
+				MockPeaqAccount::Alice,
+				MockPeaqAccount::EVMu1Account,
+				PCall::get_evm_address {
+					account_id: H256::from(<[u8; 32]>::from(MockPeaqAccount::Alice)),
+				},
+			)
+			.expect_no_logs()
+			.execute_returns(Address(evm_address));
+	});
+}
+
+#[test]
+fn claim_account_with_wrong_signature_reverts() {
+	ExtBuilder::default().build().execute_with(|| {
+		let evm_address = AddressUnification::evm_address(&evm_secret_key());
+		// signed for another account than the caller
+		let signature = AddressUnification::eth_sign(&evm_secret_key(), &MockPeaqAccount::Bob);
+
+		precompiles()
+			.prepare_test(
+				MockPeaqAccount::Alice,
+				MockPeaqAccount::EVMu1Account,
+				PCall::claim_account {
+					evm_address: Address(evm_address),
+					signature: signature.to_vec().into(),
+				},
+			)
+			.execute_reverts(|output| {
+				sp_std::str::from_utf8(output).unwrap().contains("InvalidSignature")
+			});
+
+		precompiles()
+			.prepare_test(
+				MockPeaqAccount::Alice,
+				MockPeaqAccount::EVMu1Account,
+				PCall::claim_account {
+					evm_address: Address(evm_address),
+					signature: signature[..64].to_vec().into(),
+				},
+			)
+			.execute_reverts(|output| {
+				sp_std::str::from_utf8(output).unwrap().contains("Signature must be 65 bytes")
+			});
+	});
+}
+
+#[test]
+fn unlinked_addresses_resolve_to_default() {
+	ExtBuilder::default().build().execute_with(|| {
+		let evm_address: H160 = MockPeaqAccount::Bob.into();
+
+		precompiles()
+			.prepare_test(
+				MockPeaqAccount::Alice,
+				MockPeaqAccount::EVMu1Account,
+				PCall::get_account_id { evm_address: Address(evm_address) },
+			)
+			.expect_no_logs()
+			.execute_returns(H256::from(<[u8; 32]>::from(MockPeaqAccount::Bob)));
+
+		assert_ok!(AddressUnification::claim_default_account(RuntimeOrigin::signed(
+			MockPeaqAccount::Charlie
+		)));
+		let default_evm_address =
+			AddressUnification::get_detault_evm_address(&MockPeaqAccount::Charlie);
+		precompiles()
+			.prepare_test(
+				MockPeaqAccount::Alice,
+				MockPeaqAccount::EVMu1Account,
+				PCall::get_evm_address {
+					account_id: H256::from(<[u8; 32]>::from(MockPeaqAccount::Charlie)),
+				},
+			)
+			.expect_no_logs()
+			.execute_returns(Address(default_evm_address));
+	});
+}
+
+#[test]
+fn claim_account_with_deadline_uses_up_nonce() {
+	ExtBuilder::default().build().execute_with(|| {
+		let evm_address = AddressUnification::evm_address(&evm_secret_key());
+		let signature = AddressUnification::eth_sign_with_deadline(
+			&evm_secret_key(),
+			&MockPeaqAccount::Alice,
+			10,
+		);
+
+		precompiles()
+			.prepare_test(
+				MockPeaqAccount::Alice,
+				MockPeaqAccount::EVMu1Account,
+				PCall::claim_nonce { evm_address: Address(evm_address) },
+			)
+			.expect_no_logs()
+			.execute_returns(U256::zero());
+
+		precompiles()
+			.prepare_test(
+				MockPeaqAccount::Alice,
+				MockPeaqAccount::EVMu1Account,
+				PCall::claim_account_with_deadline {
+					evm_address: Address(evm_address),
+					signature: signature.to_vec().into(),
+					deadline: U256::MAX,
+				},
+			)
+			.execute_reverts(|output| sp_std::str::from_utf8(output).unwrap().contains("deadline"));
+
+		precompiles()
+			.prepare_test(
+				MockPeaqAccount::Alice,
+				MockPeaqAccount::EVMu1Account,
+				PCall::claim_account_with_deadline {
+					evm_address: Address(evm_address),
+					signature: signature.to_vec().into(),
+					deadline: 10.into(),
+				},
+			)
+			.expect_log(log2(
+				MockPeaqAccount::EVMu1Account,
+				SELECTOR_LOG_CLAIM_ACCOUNT,
+				evm_address,
+				solidity::encode_event_data(H256::from(<[u8; 32]>::from(MockPeaqAccount::Alice))),
+			))
+			.execute_returns(true);
+
+		assert_eq!(AddressUnification::accounts(evm_address), Some(MockPeaqAccount::Alice));
+
+		precompiles()
+			.prepare_test(
+				MockPeaqAccount::Alice,
+				MockPeaqAccount::EVMu1Account,
+				PCall::claim_nonce { evm_address: Address(evm_address) },
+			)
+			.expect_no_logs()
+			.execute_returns(U256::one());
+	});
+}
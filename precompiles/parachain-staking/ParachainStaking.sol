@@ -16,12 +16,26 @@ interface ParachainStaking {
     struct CollatorInfo {
         bytes32 owner;
         uint256 amount;
+        string displayName;
+        string url;
+        string description;
     }
 
-    /// Get all collator informations
+    struct PendingCommission {
+        uint32 commission;
+        uint32 effectiveAt;
+    }
+
+    /// Get all collator informations including the metadata set by the collators
     // selector: 0xaaacb283
     function getCollatorList() external view returns (CollatorInfo[] memory);
 
+    /// Get the commission change of a collator which is not effective yet.
+    /// The commission is given in parts per million, both values are zero if
+    /// no change is pending.
+    /// selector: 0x9773b574
+    function getPendingCommission(bytes32 collator) external view returns (PendingCommission memory);
+
     /// Join the set of delegators by delegating to a collator candidate
     /// selector: 0xd9f511cd
     function joinDelegators(bytes32 collator, uint256 stake) external;
@@ -53,4 +67,20 @@ interface ParachainStaking {
 		/// elapsed.
     /// selector: 0x0f615369
     function unlockUnstaked(address target) external;
+
+		/// Claim the rewards of the caller which have been recorded for the
+		/// given collator in past sessions but not yet paid out, inspecting the
+		/// `maxSessions` many sessions starting at `firstSession`.
+    /// selector: 0xf1ed5556
+    function claimRewards(bytes32 collator, uint32 firstSession, uint32 maxSessions) external;
+
+		/// Set the share of the rewards which is automatically restaked into
+		/// the caller's delegation for the given collator candidate, in percent.
+    /// selector: 0xff03bb11
+    function setAutoCompound(bytes32 collator, uint8 percent) external;
+
+		/// Move (part of) the stake delegated to one collator candidate to
+		/// another one without unstaking it.
+    /// selector: 0x929c8a8a
+    function moveDelegation(bytes32 fromCollator, bytes32 toCollator, uint256 stake) external;
 }
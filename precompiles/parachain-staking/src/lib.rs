@@ -30,9 +30,14 @@ use frame_support::{
 	traits::Currency,
 };
 use pallet_evm::AddressMapping;
+use parachain_staking::types::CandidateMetadataOf;
+use parity_scale_codec::MaxEncodedLen;
 use precompile_utils::prelude::*;
 use sp_core::{H256, U256};
-use sp_runtime::traits::{Dispatchable, StaticLookup};
+use sp_runtime::{
+	traits::{Dispatchable, StaticLookup},
+	PerThing, Percent,
+};
 use sp_std::{convert::TryInto, marker::PhantomData, vec::Vec};
 
 type AccountIdOf<Runtime> = <Runtime as frame_system::Config>::AccountId;
@@ -50,6 +55,15 @@ pub struct ParachainStakingPrecompile<Runtime>(PhantomData<Runtime>);
 pub struct CollatorInfo {
 	owner: H256,
 	amount: U256,
+	display_name: UnboundedString,
+	url: UnboundedString,
+	description: UnboundedString,
+}
+
+#[derive(Default, solidity::Codec)]
+pub struct PendingCommissionInfo {
+	commission: u32,
+	effective_at: u32,
 }
 
 #[precompile_utils::precompile]
@@ -73,15 +87,50 @@ where
 
 		handle.record_db_read::<Runtime>(7200)?;
 
-		Ok(parachain_staking::Pallet::<Runtime>::top_candidates()
+		let top_candidates = parachain_staking::Pallet::<Runtime>::top_candidates();
+		// MetadataOf: one entry per top candidate
+		handle.record_db_read::<Runtime>(
+			CandidateMetadataOf::<Runtime>::max_encoded_len().saturating_mul(top_candidates.len()),
+		)?;
+
+		Ok(top_candidates
 			.into_iter()
-			.map(|stake_info| CollatorInfo {
-				owner: H256::from(<AccountIdOf<Runtime> as Into<[u8; 32]>>::into(stake_info.owner)),
-				amount: stake_info.amount.into(),
+			.map(|stake_info| {
+				let metadata =
+					parachain_staking::Pallet::<Runtime>::candidate_metadata(&stake_info.owner)
+						.unwrap_or_default();
+				CollatorInfo {
+					owner: H256::from(<AccountIdOf<Runtime> as Into<[u8; 32]>>::into(
+						stake_info.owner,
+					)),
+					amount: stake_info.amount.into(),
+					display_name: metadata.display_name.into_inner().into(),
+					url: metadata.url.into_inner().into(),
+					description: metadata.description.into_inner().into(),
+				}
 			})
 			.collect::<Vec<CollatorInfo>>())
 	}
 
+	#[precompile::public("getPendingCommission(bytes32)")]
+	#[precompile::public("get_pending_commission(bytes32)")]
+	#[precompile::view]
+	fn get_pending_commission(
+		handle: &mut impl PrecompileHandle,
+		collator: H256,
+	) -> EvmResult<PendingCommissionInfo> {
+		// PendingCommissions: Twox64Concat(8) + AccountId(32) + Permill(4) + SessionIndex(4)
+		handle.record_db_read::<Runtime>(48)?;
+
+		let collator: Runtime::AccountId = AccountIdOf::<Runtime>::from(collator.to_fixed_bytes());
+		Ok(parachain_staking::Pallet::<Runtime>::pending_commission(collator)
+			.map(|pending| PendingCommissionInfo {
+				commission: pending.commission.deconstruct(),
+				effective_at: pending.effective_at,
+			})
+			.unwrap_or_default())
+	}
+
 	#[precompile::public("joinDelegators(bytes32,uint256)")]
 	#[precompile::public("join_delegators(bytes32,uint256)")]
 	fn join_delegators(
@@ -224,6 +273,90 @@ where
 		Ok(())
 	}
 
+	#[precompile::public("claimRewards(bytes32,uint32,uint32)")]
+	#[precompile::public("claim_rewards(bytes32,uint32,uint32)")]
+	fn claim_rewards(
+		handle: &mut impl PrecompileHandle,
+		collator: H256,
+		first_session: u32,
+		max_sessions: u32,
+	) -> EvmResult {
+		// Build call with origin.
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let collator: Runtime::AccountId = AccountIdOf::<Runtime>::from(collator.to_fixed_bytes());
+		let collator: <Runtime::Lookup as StaticLookup>::Source =
+			<Runtime::Lookup as StaticLookup>::unlookup(collator.clone());
+		let call = parachain_staking::Call::<Runtime>::claim_rewards {
+			collator,
+			first_session,
+			max_sessions,
+		};
+
+		// Dispatch call (if enough gas).
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call, 0)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("setAutoCompound(bytes32,uint8)")]
+	#[precompile::public("set_auto_compound(bytes32,uint8)")]
+	fn set_auto_compound(
+		handle: &mut impl PrecompileHandle,
+		collator: H256,
+		percent: u8,
+	) -> EvmResult {
+		if percent > 100 {
+			return Err(revert("Auto-compound percentage exceeds 100"))
+		}
+
+		// Build call with origin.
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let collator: Runtime::AccountId = AccountIdOf::<Runtime>::from(collator.to_fixed_bytes());
+		let collator: <Runtime::Lookup as StaticLookup>::Source =
+			<Runtime::Lookup as StaticLookup>::unlookup(collator.clone());
+		let call = parachain_staking::Call::<Runtime>::set_auto_compound {
+			candidate: collator,
+			value: Percent::from_percent(percent),
+		};
+
+		// Dispatch call (if enough gas).
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call, 0)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("moveDelegation(bytes32,bytes32,uint256)")]
+	#[precompile::public("move_delegation(bytes32,bytes32,uint256)")]
+	fn move_delegation(
+		handle: &mut impl PrecompileHandle,
+		from_collator: H256,
+		to_collator: H256,
+		stake: U256,
+	) -> EvmResult {
+		let stake = Self::u256_to_amount(stake).in_field("stake")?;
+
+		// Build call with origin.
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let from_collator: Runtime::AccountId =
+			AccountIdOf::<Runtime>::from(from_collator.to_fixed_bytes());
+		let from_collator: <Runtime::Lookup as StaticLookup>::Source =
+			<Runtime::Lookup as StaticLookup>::unlookup(from_collator.clone());
+		let to_collator: Runtime::AccountId =
+			AccountIdOf::<Runtime>::from(to_collator.to_fixed_bytes());
+		let to_collator: <Runtime::Lookup as StaticLookup>::Source =
+			<Runtime::Lookup as StaticLookup>::unlookup(to_collator.clone());
+		let call = parachain_staking::Call::<Runtime>::move_delegation {
+			from_collator,
+			to_collator,
+			amount: stake,
+		};
+
+		// Dispatch call (if enough gas).
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(origin).into(), call, 0)?;
+
+		Ok(())
+	}
+
 	fn u256_to_amount(value: U256) -> MayRevert<BalanceOf<Runtime>> {
 		value
 			.try_into()
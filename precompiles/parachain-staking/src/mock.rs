@@ -202,6 +202,14 @@ parameter_types! {
 	pub const MinDelegatorStake: Balance = 5;
 	pub const MinDelegation: Balance = 3;
 	pub const MaxUnstakeRequests: u32 = 6;
+	pub const MaxRewardPayoutsPerBlock: u32 = 64;
+	pub const MaxSessionsPerClaim: u32 = 8;
+	pub const SlashFraction: Perbill = Perbill::zero();
+	pub const SlashDelegators: bool = false;
+	pub const SlashDeferDuration: u32 = 0;
+	pub const MaxOffencesBeforeChill: u32 = 0;
+	pub const CommissionChangeDelay: u32 = 2;
+	pub const MaxMetadataLength: u32 = 32;
 }
 
 impl parachain_staking::Config for Test {
@@ -224,6 +232,16 @@ impl parachain_staking::Config for Test {
 	type MinDelegation = MinDelegation;
 	type MaxUnstakeRequests = MaxUnstakeRequests;
 	type PotId = PotId;
+	type MaxRewardPayoutsPerBlock = MaxRewardPayoutsPerBlock;
+	type MaxSessionsPerClaim = MaxSessionsPerClaim;
+	type SlashFraction = SlashFraction;
+	type SlashDelegators = SlashDelegators;
+	type SlashDeferDuration = SlashDeferDuration;
+	type MaxOffencesBeforeChill = MaxOffencesBeforeChill;
+	type Slash = ();
+	type CancelSlashOrigin = frame_system::EnsureRoot<AccountId>;
+	type CommissionChangeDelay = CommissionChangeDelay;
+	type MaxMetadataLength = MaxMetadataLength;
 	type WeightInfo = parachain_staking::weights::WeightInfo<Test>;
 }
 
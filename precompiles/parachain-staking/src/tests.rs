@@ -21,7 +21,7 @@ use crate::{
 		roll_to, Balances, BlockNumber, ExtBuilder, PCall, Precompiles, PrecompilesValue,
 		RuntimeOrigin, StakePallet, Test,
 	},
-	Address, BalanceOf, CollatorInfo, U256,
+	Address, BalanceOf, CollatorInfo, PendingCommissionInfo, U256,
 };
 use frame_support::{
 	assert_ok, storage::bounded_btree_map::BoundedBTreeMap, traits::LockIdentifier,
@@ -30,6 +30,7 @@ use pallet_balances::{BalanceLock, Reasons};
 use parachain_staking::types::TotalStake;
 use precompile_utils::testing::{MockPeaqAccount, PrecompileTesterExt, PrecompilesModifierTester};
 use sp_core::H256;
+use sp_runtime::{Percent, Permill};
 
 const STAKING_ID: LockIdentifier = *b"peaqstak";
 
@@ -48,6 +49,7 @@ fn convert_mock_account_by_u8_list(account: MockPeaqAccount) -> H256 {
 #[test]
 fn test_selector_enum() {
 	assert!(PCall::get_collator_list_selectors().contains(&0xaaacb283));
+	assert!(PCall::get_pending_commission_selectors().contains(&0x9773b574));
 	assert!(PCall::join_delegators_selectors().contains(&0xd9f511cd));
 	assert!(PCall::delegate_another_candidate_selectors().contains(&0x1916fdca));
 	assert!(PCall::leave_delegators_selectors().contains(&0x4b99dc38));
@@ -55,6 +57,9 @@ fn test_selector_enum() {
 	assert!(PCall::delegator_stake_more_selectors().contains(&0x1b3d3cdf));
 	assert!(PCall::delegator_stake_less_selectors().contains(&0xb7e8947f));
 	assert!(PCall::unlock_unstaked_selectors().contains(&0x0f615369));
+	assert!(PCall::claim_rewards_selectors().contains(&0xf1ed5556));
+	assert!(PCall::set_auto_compound_selectors().contains(&0xff03bb11));
+	assert!(PCall::move_delegation_selectors().contains(&0x929c8a8a));
 }
 
 #[test]
@@ -71,6 +76,7 @@ fn modifiers() {
 			);
 
 			tester.test_view_modifier(PCall::get_collator_list_selectors());
+			tester.test_view_modifier(PCall::get_pending_commission_selectors());
 		});
 }
 
@@ -91,6 +97,13 @@ fn collator_list_test() {
 		.with_delegators(vec![(MockPeaqAccount::Bob, MockPeaqAccount::Alice, 100)])
 		.build()
 		.execute_with(|| {
+			assert_ok!(StakePallet::set_candidate_metadata(
+				RuntimeOrigin::signed(MockPeaqAccount::Alice),
+				b"Alice".to_vec(),
+				b"https://alice.io".to_vec(),
+				vec![],
+			));
+
 			precompiles()
 				.prepare_test(
 					MockPeaqAccount::Bob,
@@ -102,10 +115,14 @@ fn collator_list_test() {
 					CollatorInfo {
 						owner: convert_mock_account_by_u8_list(MockPeaqAccount::Alice),
 						amount: U256::from(110),
+						display_name: "Alice".into(),
+						url: "https://alice.io".into(),
+						description: Default::default(),
 					},
 					CollatorInfo {
 						owner: convert_mock_account_by_u8_list(MockPeaqAccount::Charlie),
 						amount: U256::from(20),
+						..Default::default()
 					},
 				]);
 		});
@@ -341,3 +358,108 @@ fn should_update_total_stake() {
 			);
 		})
 }
+
+#[test]
+fn set_auto_compound() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 10), (MockPeaqAccount::Bob, 100)])
+		.with_collators(vec![(MockPeaqAccount::Alice, 10)])
+		.with_delegators(vec![(MockPeaqAccount::Bob, MockPeaqAccount::Alice, 100)])
+		.build()
+		.execute_with(|| {
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Bob,
+					MockPeaqAccount::EVMu1Account,
+					PCall::set_auto_compound {
+						collator: convert_mock_account_by_u8_list(MockPeaqAccount::Alice),
+						percent: 30,
+					},
+				)
+				.expect_no_logs()
+				.execute_returns(());
+			assert_eq!(
+				StakePallet::auto_compound(MockPeaqAccount::Bob, MockPeaqAccount::Alice),
+				Percent::from_percent(30)
+			);
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Bob,
+					MockPeaqAccount::EVMu1Account,
+					PCall::set_auto_compound {
+						collator: convert_mock_account_by_u8_list(MockPeaqAccount::Alice),
+						percent: 101,
+					},
+				)
+				.execute_reverts(|output| output == b"Auto-compound percentage exceeds 100");
+		});
+}
+
+#[test]
+fn move_delegation() {
+	ExtBuilder::default()
+		.with_balances(vec![
+			(MockPeaqAccount::Alice, 10),
+			(MockPeaqAccount::Charlie, 10),
+			(MockPeaqAccount::Bob, 100),
+		])
+		.with_collators(vec![(MockPeaqAccount::Alice, 10), (MockPeaqAccount::Charlie, 10)])
+		.with_delegators(vec![(MockPeaqAccount::Bob, MockPeaqAccount::Alice, 100)])
+		.build()
+		.execute_with(|| {
+			let old_stake = StakePallet::total_collator_stake();
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Bob,
+					MockPeaqAccount::EVMu1Account,
+					PCall::move_delegation {
+						from_collator: convert_mock_account_by_u8_list(MockPeaqAccount::Alice),
+						to_collator: convert_mock_account_by_u8_list(MockPeaqAccount::Charlie),
+						stake: 40.into(),
+					},
+				)
+				.expect_no_logs()
+				.execute_returns(());
+
+			assert_eq!(StakePallet::candidate_pool(MockPeaqAccount::Alice).unwrap().total, 70);
+			assert_eq!(StakePallet::candidate_pool(MockPeaqAccount::Charlie).unwrap().total, 50);
+			assert_eq!(StakePallet::delegator_state(MockPeaqAccount::Bob).unwrap().total, 100);
+			assert_eq!(StakePallet::total_collator_stake(), old_stake);
+		});
+}
+
+#[test]
+fn get_pending_commission() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 10), (MockPeaqAccount::Charlie, 10)])
+		.with_collators(vec![(MockPeaqAccount::Alice, 10), (MockPeaqAccount::Charlie, 10)])
+		.build()
+		.execute_with(|| {
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Bob,
+					MockPeaqAccount::EVMu1Account,
+					PCall::get_pending_commission {
+						collator: convert_mock_account_by_u8_list(MockPeaqAccount::Alice),
+					},
+				)
+				.expect_no_logs()
+				.execute_returns(PendingCommissionInfo::default());
+
+			assert_ok!(StakePallet::set_commission(
+				RuntimeOrigin::signed(MockPeaqAccount::Alice),
+				Permill::from_percent(10)
+			));
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Bob,
+					MockPeaqAccount::EVMu1Account,
+					PCall::get_pending_commission {
+						collator: convert_mock_account_by_u8_list(MockPeaqAccount::Alice),
+					},
+				)
+				.expect_no_logs()
+				.execute_returns(PendingCommissionInfo { commission: 100_000, effective_at: 2 });
+		});
+}
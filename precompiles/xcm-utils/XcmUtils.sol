@@ -34,6 +34,14 @@ interface XcmUtils {
         view
         returns (uint256 unitsPerSecond);
 
+    /// Get units per byte of proof size charged for a given multilocation
+    /// @custom:selector bdb38c41
+    /// @param multilocation scale encoded xcm mversioned xcm message
+    function getUnitsPerProofByte(Multilocation memory multilocation)
+        external
+        view
+        returns (uint256 unitsPerProofByte);
+
     /// Execute custom xcm message
     /// @dev This function CANNOT be called from a smart contract
     /// @custom:selector 34334a02
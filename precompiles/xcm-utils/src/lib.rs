@@ -90,37 +90,19 @@ where
 		handle: &mut impl PrecompileHandle,
 		multilocation: MultiLocation,
 	) -> EvmResult<U256> {
-		// storage item: AssetTypeUnitsPerSecond
-		// max encoded len: hash (16) + Multilocation + u128 (16)
-		handle.record_db_read::<Runtime>(32 + MultiLocation::max_encoded_len())?;
-
-		// We will construct an asset with the max amount, and check how much we
-		// get in return to substract
-		let multiasset: xcm::latest::MultiAsset = (multilocation, u128::MAX).into();
-		let weight_per_second = 1_000_000_000_000u64;
-
-		let mut trader = <XcmConfig as xcm_executor::Config>::Trader::new();
-
-		// buy_weight returns unused assets
-		let unused = trader
-			.buy_weight(
-				Weight::from_parts(weight_per_second, DEFAULT_PROOF_SIZE),
-				vec![multiasset.clone()].into(),
-			)
-			.map_err(|_| {
-				RevertReason::custom("Asset not supported as fee payment").in_field("multilocation")
-			})?;
+		// One second of execution time, without any proof size
+		Self::weight_price(handle, multilocation, Weight::from_parts(1_000_000_000_000u64, 0))
+	}
 
-		// we just need to substract from u128::MAX the unused assets
-		if let Some(amount) = unused
-			.fungible
-			.get(&multiasset.id)
-			.map(|&value| u128::MAX.saturating_sub(value))
-		{
-			Ok(amount.into())
-		} else {
-			Err(revert("Weight was too expensive to be bought with this asset"))
-		}
+	#[precompile::public("getUnitsPerProofByte((uint8,bytes[]))")]
+	#[precompile::public("get_units_per_proof_byte((uint8,bytes[]))")]
+	#[precompile::view]
+	fn get_units_per_proof_byte(
+		handle: &mut impl PrecompileHandle,
+		multilocation: MultiLocation,
+	) -> EvmResult<U256> {
+		// One byte of proof size, without any execution time
+		Self::weight_price(handle, multilocation, Weight::from_parts(0, 1))
 	}
 
 	#[precompile::public("weightMessage(bytes)")]
@@ -203,4 +185,36 @@ where
 
 		Ok(())
 	}
+
+	fn weight_price(
+		handle: &mut impl PrecompileHandle,
+		multilocation: MultiLocation,
+		weight: Weight,
+	) -> EvmResult<U256> {
+		// storage item: AssetLocationExecutionRate
+		// max encoded len: hash (16) + Multilocation + 2 * u128 (32)
+		handle.record_db_read::<Runtime>(48 + MultiLocation::max_encoded_len())?;
+
+		// We will construct an asset with the max amount, and check how much we
+		// get in return to substract
+		let multiasset: xcm::latest::MultiAsset = (multilocation, u128::MAX).into();
+
+		let mut trader = <XcmConfig as xcm_executor::Config>::Trader::new();
+
+		// buy_weight returns unused assets
+		let unused = trader.buy_weight(weight, vec![multiasset.clone()].into()).map_err(|_| {
+			RevertReason::custom("Asset not supported as fee payment").in_field("multilocation")
+		})?;
+
+		// we just need to substract from u128::MAX the unused assets
+		if let Some(amount) = unused
+			.fungible
+			.get(&multiasset.id)
+			.map(|&value| u128::MAX.saturating_sub(value))
+		{
+			Ok(amount.into())
+		} else {
+			Err(revert("Weight was too expensive to be bought with this asset"))
+		}
+	}
 }
@@ -353,7 +353,7 @@ impl WeightTrader for DummyWeightTrader {
 
 	fn buy_weight(&mut self, weight: Weight, payment: Assets) -> Result<Assets, XcmError> {
 		let asset_to_charge: MultiAsset =
-			(MultiLocation::parent(), weight.ref_time() as u128).into();
+			(MultiLocation::parent(), (weight.ref_time() + weight.proof_size()) as u128).into();
 		let unused = payment.checked_sub(asset_to_charge).map_err(|_| XcmError::TooExpensive)?;
 
 		Ok(unused)
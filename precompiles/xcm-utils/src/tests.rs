@@ -41,6 +41,7 @@ fn precompiles() -> Precompiles<Runtime> {
 fn test_selector_enum() {
 	assert!(PCall::weight_message_selectors().contains(&0x25d54154));
 	assert!(PCall::get_units_per_second_selectors().contains(&0x3f0f65db));
+	assert!(PCall::get_units_per_proof_byte_selectors().contains(&0xbdb38c41));
 }
 
 #[test]
@@ -54,6 +55,7 @@ fn modifiers() {
 
 		tester.test_view_modifier(PCall::weight_message_selectors());
 		tester.test_view_modifier(PCall::get_units_per_second_selectors());
+		tester.test_view_modifier(PCall::get_units_per_proof_byte_selectors());
 	});
 }
 
@@ -85,6 +87,19 @@ fn test_get_units_per_second() {
 	});
 }
 
+#[test]
+fn test_get_units_per_proof_byte() {
+	ExtBuilder::default().build().execute_with(|| {
+		let input = PCall::get_units_per_proof_byte { multilocation: MultiLocation::parent() };
+
+		precompiles()
+			.prepare_test(MockPeaqAccount::Alice, MockPeaqAccount::EVMu1Account, input)
+			.expect_cost(1)
+			.expect_no_logs()
+			.execute_returns(U256::from(1u128));
+	});
+}
+
 #[test]
 fn test_executor_clear_origin() {
 	ExtBuilder::default().build().execute_with(|| {
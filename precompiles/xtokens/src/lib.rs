@@ -21,11 +21,12 @@
 use fp_evm::PrecompileHandle;
 use frame_support::{
 	dispatch::{GetDispatchInfo, PostDispatchInfo},
+	pallet_prelude::MaxEncodedLen,
 	traits::Get,
 };
 use pallet_evm::AddressMapping;
 use pallet_evm_precompile_assets_erc20::AssetIdOf;
-use peaq_primitives_xcm::EVMAddressToAssetId;
+use peaq_primitives_xcm::{EVMAddressToAssetId, PausedXcAssets};
 use precompile_utils::prelude::*;
 use sp_core::{H160, U256};
 use sp_runtime::traits::Dispatchable;
@@ -77,7 +78,7 @@ where
 	Runtime::RuntimeCall: From<orml_xtokens::Call<Runtime>>,
 	<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
 	XBalanceOf<Runtime>: TryFrom<U256> + Into<U256> + solidity::Codec,
-	Runtime: EVMAddressToAssetId<AssetIdOf<Runtime>>,
+	Runtime: EVMAddressToAssetId<AssetIdOf<Runtime>> + PausedXcAssets<AssetIdOf<Runtime>>,
 	<Runtime as orml_xtokens::Config>::CurrencyId:
 		From<<Runtime as pallet_assets::Config>::AssetId>,
 {
@@ -94,6 +95,9 @@ where
 		let asset_id = Runtime::address_to_asset_id(to_address).ok_or(
 			RevertReason::custom("Cannot convert into currency id").in_field("currencyAddress"),
 		)?;
+		if Self::is_asset_paused(handle, asset_id.clone())? {
+			return Err(RevertReason::custom("Asset is paused").in_field("currencyAddress").into())
+		}
 
 		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
 		let amount = amount
@@ -133,6 +137,9 @@ where
 		let asset_id = Runtime::address_to_asset_id(to_address).ok_or(
 			RevertReason::custom("Cannot convert into currency id").in_field("currencyAddress"),
 		)?;
+		if Self::is_asset_paused(handle, asset_id.clone())? {
+			return Err(RevertReason::custom("Asset is paused").in_field("currencyAddress").into())
+		}
 
 		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
 
@@ -174,6 +181,10 @@ where
 		destination: MultiLocation,
 		weight: u64,
 	) -> EvmResult {
+		if Self::is_location_paused(handle, asset)? {
+			return Err(RevertReason::custom("Asset is paused").in_field("asset").into())
+		}
+
 		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
 		let to_balance = amount
 			.try_into()
@@ -213,6 +224,10 @@ where
 		destination: MultiLocation,
 		weight: u64,
 	) -> EvmResult {
+		if Self::is_location_paused(handle, asset)? {
+			return Err(RevertReason::custom("Asset is paused").in_field("asset").into())
+		}
+
 		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
 		let amount = amount
 			.try_into()
@@ -273,16 +288,19 @@ where
 						.in_field("currencies")
 				})?;
 
-				Ok((
-					Runtime::address_to_asset_id(address_as_h160)
-						.ok_or(
-							RevertReason::custom("Cannot convert into currency id")
-								.in_array(index)
-								.in_field("currencies"),
-						)?
-						.into(),
-					amount,
-				))
+				let asset_id = Runtime::address_to_asset_id(address_as_h160).ok_or(
+					RevertReason::custom("Cannot convert into currency id")
+						.in_array(index)
+						.in_field("currencies"),
+				)?;
+				if Self::is_asset_paused(handle, asset_id.clone())? {
+					return Err(RevertReason::custom("Asset is paused")
+						.in_array(index)
+						.in_field("currencies")
+						.into())
+				}
+
+				Ok((asset_id.into(), amount))
 			})
 			.collect::<EvmResult<_>>()?;
 
@@ -329,6 +347,12 @@ where
 						.in_array(index)
 						.in_field("assets")
 				})?;
+				if Self::is_location_paused(handle, evm_multiasset.location)? {
+					return Err(RevertReason::custom("Asset is paused")
+						.in_array(index)
+						.in_field("assets")
+						.into())
+				}
 				Ok((evm_multiasset.location, to_balance).into())
 			})
 			.collect();
@@ -358,6 +382,28 @@ where
 
 		Ok(())
 	}
+
+	fn is_asset_paused(
+		handle: &mut impl PrecompileHandle,
+		asset_id: AssetIdOf<Runtime>,
+	) -> EvmResult<bool> {
+		// PausedAssets: Twox64Concat(8) + AssetId
+		handle.record_db_read::<Runtime>(8 + AssetIdOf::<Runtime>::max_encoded_len())?;
+		Ok(Runtime::is_asset_paused(asset_id))
+	}
+
+	fn is_location_paused(
+		handle: &mut impl PrecompileHandle,
+		location: MultiLocation,
+	) -> EvmResult<bool> {
+		// AssetLocationToId: Twox64Concat(8) + VersionedMultiLocation(1 + MultiLocation) + AssetId
+		handle.record_db_read::<Runtime>(
+			8 + 1 + MultiLocation::max_encoded_len() + AssetIdOf::<Runtime>::max_encoded_len(),
+		)?;
+		// PausedAssets: Twox64Concat(8) + AssetId
+		handle.record_db_read::<Runtime>(8 + AssetIdOf::<Runtime>::max_encoded_len())?;
+		Ok(Runtime::is_location_paused(location))
+	}
 }
 
 // Currency
@@ -31,10 +31,11 @@ use pallet_evm::{EnsureAddressNever, EnsureAddressRoot};
 use precompile_utils::{precompile_set::*, testing::*};
 
 use sp_core::H256;
+use std::cell::RefCell;
 
 use sp_runtime::{
 	testing::Header,
-	traits::{BlakeTwo256, IdentityLookup},
+	traits::{BlakeTwo256, Convert, IdentityLookup},
 };
 use xcm::latest::{prelude::*, Error as XcmError};
 use xcm_builder::{AllowUnpaidExecutionFrom, FixedWeightBounds, IsConcrete};
@@ -82,6 +83,30 @@ impl EVMAddressToAssetId<AssetId> for Runtime {
 	}
 }
 
+thread_local! {
+	static PAUSED_ASSETS: RefCell<Vec<CurrencyId>> = RefCell::new(Vec::new());
+}
+
+/// Mark the given currency as paused for cross-chain transfers.
+pub(crate) fn pause_asset(currency: CurrencyId) {
+	PAUSED_ASSETS.with(|paused| paused.borrow_mut().push(currency));
+}
+
+impl PausedXcAssets<AssetId> for Runtime {
+	fn is_asset_paused(asset_id: AssetId) -> bool {
+		PAUSED_ASSETS.with(|paused| paused.borrow().contains(&asset_id.0))
+	}
+
+	fn is_location_paused(location: MultiLocation) -> bool {
+		PAUSED_ASSETS.with(|paused| {
+			paused
+				.borrow()
+				.iter()
+				.any(|currency| CurrencyIdToMultiLocation::convert(*currency) == Some(location))
+		})
+	}
+}
+
 parameter_types! {
 	pub const BlockHashCount: u64 = 250;
 	pub const SS58Prefix: u8 = 42;
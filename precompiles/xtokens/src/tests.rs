@@ -16,8 +16,8 @@
 
 use crate::{
 	mock::{
-		events, CurrencyIdToMultiLocation, ExtBuilder, PCall, Precompiles, PrecompilesValue,
-		Runtime,
+		events, pause_asset, CurrencyIdToMultiLocation, ExtBuilder, PCall, Precompiles,
+		PrecompilesValue, Runtime,
 	},
 	Currency, EvmMultiAsset,
 };
@@ -839,3 +839,58 @@ fn test_deprecated_solidity_selectors_are_supported() {
 		}
 	}
 }
+
+#[test]
+fn transfer_paused_currency_reverts() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 1000)])
+		.build()
+		.execute_with(|| {
+			let destination = MultiLocation::new(
+				1,
+				Junctions::X1(Junction::AccountId32 { network: None, id: [1u8; 32] }),
+			);
+			pause_asset(1u128);
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::transfer {
+						currency_address: Address(MockPeaqAccount::AssetId(1u128.into()).into()),
+						amount: 500.into(),
+						destination,
+						weight: 4_000_000,
+					},
+				)
+				.execute_reverts(|output| output == b"currencyAddress: Asset is paused");
+		});
+}
+
+#[test]
+fn transfer_paused_multi_asset_reverts() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 1000)])
+		.build()
+		.execute_with(|| {
+			let destination = MultiLocation::new(
+				1,
+				Junctions::X1(Junction::AccountId32 { network: None, id: [1u8; 32] }),
+			);
+			// Asset 1 is the relay asset
+			pause_asset(1u128);
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::transfer_multiasset {
+						asset: MultiLocation::parent(),
+						amount: 500.into(),
+						destination,
+						weight: 4_000_000,
+					},
+				)
+				.execute_reverts(|output| output == b"asset: Asset is paused");
+		});
+}
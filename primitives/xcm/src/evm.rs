@@ -3,6 +3,7 @@ use frame_support::ensure;
 use pallet_assets::AssetsCallback;
 use sp_core::{H160, U256};
 use sp_std::marker::PhantomData;
+use xcm::latest::MultiLocation;
 
 /// Evm Address.
 pub type EvmAddress = sp_core::H160;
@@ -25,6 +26,16 @@ pub trait EVMAddressToAssetId<AssetId> {
 	fn asset_id_to_address(asset_id: AssetId) -> Option<H160>;
 }
 
+/// This trait tells whether cross-chain transfers of an asset are paused
+/// We will require Runtime to have this trait implemented
+pub trait PausedXcAssets<AssetId> {
+	// Whether the asset is paused
+	fn is_asset_paused(asset_id: AssetId) -> bool;
+
+	// Whether the asset registered at the location is paused
+	fn is_location_paused(location: MultiLocation) -> bool;
+}
+
 pub struct EvmRevertCodeHandler<A, R>(PhantomData<(A, R)>);
 impl<A, R> AssetsCallback<R::AssetId, AccountId> for EvmRevertCodeHandler<A, R>
 where
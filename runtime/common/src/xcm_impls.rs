@@ -1,6 +1,15 @@
-use frame_support::weights::constants::WEIGHT_REF_TIME_PER_SECOND;
-use sp_std::marker::PhantomData;
-use xc_asset_config::ExecutionPaymentRate;
+use frame_support::{
+	dispatch::DispatchResult,
+	traits::{
+		fungibles::{self, metadata::Inspect as MetadataInspect, Inspect},
+		Get,
+	},
+	weights::constants::WEIGHT_REF_TIME_PER_SECOND,
+};
+use sp_std::{marker::PhantomData, vec::Vec};
+use xc_asset_config::{
+	ExecutionPaymentRate, ExecutionRate, ForeignAssetMetadata, ForeignAssetRegistrar,
+};
 use xcm::latest::{
 	prelude::{Fungibility, MultiAsset, MultiLocation, XcmError},
 	Weight,
@@ -8,28 +17,51 @@ use xcm::latest::{
 use xcm_builder::TakeRevenue;
 use xcm_executor::traits::WeightTrader;
 
+/// Price of `weight` at `rate`, charging for both the ref time and the proof size.
+fn weight_price(rate: &ExecutionRate, weight: Weight) -> u128 {
+	let ref_time_price = rate.units_per_second.saturating_mul(weight.ref_time() as u128) /
+		(WEIGHT_REF_TIME_PER_SECOND as u128);
+	let proof_size_price = rate.units_per_byte.saturating_mul(weight.proof_size() as u128);
+	ref_time_price.saturating_add(proof_size_price)
+}
+
+/// Weight bought with a single foreign asset and the amount of it consumed so far.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct ForeignAssetPayment {
+	/// Asset Id (as MultiLocation) used for payment
+	asset_location: MultiLocation,
+	/// Execution rate the weight was bought at
+	rate: ExecutionRate,
+	/// Weight bought with this asset and not yet refunded
+	weight: Weight,
+	/// Amount of this asset consumed and not yet refunded
+	consumed: u128,
+}
+
 /// Used as weight trader for foreign assets.
 ///
 /// In case foreigin asset is supported as payment asset, XCM execution time
 /// on-chain can be paid by the foreign asset, using the configured rate.
+///
+/// Every asset used in a `BuyExecution` gets its own entry in the ledger, so programs which pay
+/// with several assets are refunded in the asset the weight was bought with, and all consumed
+/// assets are handed over to `R` when the trader is dropped.
+///
+/// A refund can only return a single asset, so it is taken from the most recently purchased
+/// asset, up to the weight bought with it. Surplus weight beyond that is carried over and
+/// refunded in the next asset by the following refund. Whatever is never refunded stays
+/// consumed and is handed over to `R`.
 pub struct FixedRateOfForeignAsset<T: ExecutionPaymentRate, R: TakeRevenue> {
-	/// Total used weight
-	weight: Weight,
-	/// Total consumed assets
-	consumed: u128,
-	/// Asset Id (as MultiLocation) and units per second for payment
-	asset_location_and_units_per_second: Option<(MultiLocation, u128)>,
+	/// Weight bought and amounts consumed, per payment asset, in order of first purchase
+	payments: Vec<ForeignAssetPayment>,
+	/// Surplus weight which could not be refunded in the asset of the previous refund
+	unrefunded: Weight,
 	_pd: PhantomData<(T, R)>,
 }
 
 impl<T: ExecutionPaymentRate, R: TakeRevenue> WeightTrader for FixedRateOfForeignAsset<T, R> {
 	fn new() -> Self {
-		Self {
-			weight: Weight::zero(),
-			consumed: 0,
-			asset_location_and_units_per_second: None,
-			_pd: PhantomData,
-		}
+		Self { payments: Vec::new(), unrefunded: Weight::zero(), _pd: PhantomData }
 	}
 
 	fn buy_weight(
@@ -43,67 +75,74 @@ impl<T: ExecutionPaymentRate, R: TakeRevenue> WeightTrader for FixedRateOfForeig
 			weight, payment,
 		);
 
-		// Atm in pallet, we only support one asset so this should work
-		let payment_asset = payment.fungible_assets_iter().next().ok_or(XcmError::TooExpensive)?;
-
-		match payment_asset {
-			MultiAsset {
-				id: xcm::latest::AssetId::Concrete(asset_location),
-				fun: Fungibility::Fungible(_),
-			} => {
-				if let Some(units_per_second) = T::get_units_per_second(asset_location) {
-					let amount = units_per_second.saturating_mul(weight.ref_time() as u128) // TODO: change this to u64?
-                        / (WEIGHT_REF_TIME_PER_SECOND as u128);
-					if amount == 0 {
-						return Ok(payment)
-					}
-
-					let unused = payment
-						.checked_sub((asset_location, amount).into())
-						.map_err(|_| XcmError::TooExpensive)?;
-
-					self.weight = self.weight.saturating_add(weight);
-
-					// If there are multiple calls to `BuyExecution` but with different assets, we
-					// need to be able to handle that. Current primitive implementation will just
-					// keep total track of consumed asset for the FIRST consumed asset. Others will
-					// just be ignored when refund is concerned.
-					if let Some((old_asset_location, _)) = self.asset_location_and_units_per_second
-					{
-						if old_asset_location == asset_location {
-							self.consumed = self.consumed.saturating_add(amount);
-						}
-					} else {
-						self.consumed = self.consumed.saturating_add(amount);
-						self.asset_location_and_units_per_second =
-							Some((asset_location, units_per_second));
-					}
-
-					Ok(unused)
-				} else {
-					Err(XcmError::TooExpensive)
-				}
+		// Pay with the first fungible asset which is supported as payment asset
+		let (asset_location, rate) = payment
+			.fungible_assets_iter()
+			.find_map(|asset| match asset {
+				MultiAsset {
+					id: xcm::latest::AssetId::Concrete(asset_location),
+					fun: Fungibility::Fungible(_),
+				} => T::get_execution_rate(asset_location).map(|rate| (asset_location, rate)),
+				_ => None,
+			})
+			.ok_or(XcmError::TooExpensive)?;
+
+		let amount = weight_price(&rate, weight);
+		if amount == 0 {
+			return Ok(payment)
+		}
+
+		let unused = payment
+			.checked_sub((asset_location, amount).into())
+			.map_err(|_| XcmError::TooExpensive)?;
+
+		match self.payments.iter_mut().find(|p| p.asset_location == asset_location) {
+			Some(entry) => {
+				// Weight bought earlier keeps being refunded at the rate it was bought at
+				entry.weight = entry.weight.saturating_add(weight);
+				entry.consumed = entry.consumed.saturating_add(amount);
 			},
-			_ => Err(XcmError::TooExpensive),
+			None => self.payments.push(ForeignAssetPayment {
+				asset_location,
+				rate,
+				weight,
+				consumed: amount,
+			}),
 		}
+
+		Ok(unused)
 	}
 
 	fn refund_weight(&mut self, weight: Weight) -> Option<MultiAsset> {
 		log::trace!(target: "xcm::weight", "FixedRateOfForeignAsset::refund_weight weight: {:?}", weight);
 
-		if let Some((asset_location, units_per_second)) = self.asset_location_and_units_per_second {
-			let weight = weight.min(self.weight);
-			let amount = units_per_second.saturating_mul(weight.ref_time() as u128) /
-				(WEIGHT_REF_TIME_PER_SECOND as u128);
+		// Only a single asset can be returned, so the refund is taken from the most recently
+		// purchased asset, up to the weight bought with it. The rest is carried over.
+		let surplus = weight.saturating_add(self.unrefunded);
+		let entry = match self.payments.iter_mut().rev().find(|p| p.weight.any_gt(Weight::zero())) {
+			Some(entry) => entry,
+			None => {
+				self.unrefunded = Weight::zero();
+				return None
+			},
+		};
 
-			self.weight = self.weight.saturating_sub(weight);
-			self.consumed = self.consumed.saturating_sub(amount);
+		let weight = surplus.min(entry.weight);
+		let amount = weight_price(&entry.rate, weight).min(entry.consumed);
 
-			if amount > 0 {
-				Some((asset_location, amount).into())
-			} else {
-				None
-			}
+		entry.weight = entry.weight.saturating_sub(weight);
+		entry.consumed = entry.consumed.saturating_sub(amount);
+		self.unrefunded = surplus.saturating_sub(weight);
+		if self.unrefunded.any_gt(Weight::zero()) {
+			log::debug!(
+				target: "xcm::weight",
+				"FixedRateOfForeignAsset::refund_weight carried over: {:?}",
+				self.unrefunded,
+			);
+		}
+
+		if amount > 0 {
+			Some((entry.asset_location, amount).into())
 		} else {
 			None
 		}
@@ -112,10 +151,291 @@ impl<T: ExecutionPaymentRate, R: TakeRevenue> WeightTrader for FixedRateOfForeig
 
 impl<T: ExecutionPaymentRate, R: TakeRevenue> Drop for FixedRateOfForeignAsset<T, R> {
 	fn drop(&mut self) {
-		if let Some((asset_location, _)) = self.asset_location_and_units_per_second {
-			if self.consumed > 0 {
-				R::take_revenue((asset_location, self.consumed).into());
+		for payment in self.payments.iter().filter(|p| p.consumed > 0) {
+			R::take_revenue((payment.asset_location, payment.consumed).into());
+		}
+	}
+}
+
+/// Creates the local `pallet_assets` asset of a foreign asset, owned by `Owner`.
+///
+/// No deposits are taken, metadata is set as if by the force origin.
+pub struct AssetsForeignAssetRegistrar<T, Owner>(PhantomData<(T, Owner)>);
+
+type AssetsOf<T> = pallet_assets::Pallet<T>;
+
+impl<T, Owner> ForeignAssetRegistrar<T::AssetId, T::Balance>
+	for AssetsForeignAssetRegistrar<T, Owner>
+where
+	T: pallet_assets::Config,
+	Owner: Get<T::AccountId>,
+{
+	fn create_foreign_asset(
+		asset_id: T::AssetId,
+		is_sufficient: bool,
+		metadata: ForeignAssetMetadata<T::Balance>,
+	) -> DispatchResult {
+		<AssetsOf<T> as fungibles::Create<T::AccountId>>::create(
+			asset_id.clone(),
+			Owner::get(),
+			is_sufficient,
+			metadata.min_balance,
+		)?;
+		AssetsOf::<T>::force_set_metadata(
+			frame_system::RawOrigin::Root.into(),
+			asset_id.into(),
+			metadata.name,
+			metadata.symbol,
+			metadata.decimals,
+			false,
+		)
+	}
+
+	fn foreign_asset_metadata(asset_id: T::AssetId) -> Option<ForeignAssetMetadata<T::Balance>> {
+		if !AssetsOf::<T>::asset_exists(asset_id.clone()) {
+			return None
+		}
+		Some(ForeignAssetMetadata {
+			name: AssetsOf::<T>::name(asset_id.clone()),
+			symbol: AssetsOf::<T>::symbol(asset_id.clone()),
+			decimals: AssetsOf::<T>::decimals(asset_id.clone()),
+			min_balance: AssetsOf::<T>::minimum_balance(asset_id),
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_std::cell::RefCell;
+	use xcm::latest::prelude::{AssetId, Junction, Junctions};
+
+	const RELAY_ASSET: MultiLocation = MultiLocation::parent();
+	const SIBLING_ASSET: MultiLocation =
+		MultiLocation { parents: 1, interior: Junctions::X1(Junction::Parachain(1000)) };
+	const UNSUPPORTED_ASSET: MultiLocation =
+		MultiLocation { parents: 1, interior: Junctions::X1(Junction::Parachain(2000)) };
+
+	thread_local! {
+		static REVENUE: RefCell<Vec<MultiAsset>> = RefCell::new(Vec::new());
+	}
+
+	struct MockExecutionRates;
+	impl ExecutionPaymentRate for MockExecutionRates {
+		fn get_execution_rate(asset_location: MultiLocation) -> Option<ExecutionRate> {
+			match asset_location {
+				// 1 unit per picosecond of ref time
+				RELAY_ASSET => Some(ExecutionRate {
+					units_per_second: WEIGHT_REF_TIME_PER_SECOND as u128,
+					units_per_byte: 0,
+				}),
+				// 2 units per picosecond of ref time and 10 units per byte of proof size
+				SIBLING_ASSET => Some(ExecutionRate {
+					units_per_second: 2 * WEIGHT_REF_TIME_PER_SECOND as u128,
+					units_per_byte: 10,
+				}),
+				_ => None,
 			}
 		}
 	}
+
+	struct MockRevenue;
+	impl TakeRevenue for MockRevenue {
+		fn take_revenue(revenue: MultiAsset) {
+			REVENUE.with(|r| r.borrow_mut().push(revenue));
+		}
+	}
+
+	type Trader = FixedRateOfForeignAsset<MockExecutionRates, MockRevenue>;
+
+	fn assets(list: &[(MultiLocation, u128)]) -> xcm_executor::Assets {
+		let mut assets = xcm_executor::Assets::new();
+		for (location, amount) in list {
+			assets.subsume((*location, *amount).into());
+		}
+		assets
+	}
+
+	fn amount_of(assets: &xcm_executor::Assets, location: MultiLocation) -> u128 {
+		assets.fungible.get(&AssetId::Concrete(location)).copied().unwrap_or_default()
+	}
+
+	fn take_revenue() -> Vec<MultiAsset> {
+		REVENUE.with(|r| r.take())
+	}
+
+	#[test]
+	fn single_asset_buy_refund_and_revenue() {
+		take_revenue();
+		{
+			let mut trader = Trader::new();
+
+			let unused = trader
+				.buy_weight(Weight::from_parts(1_000, 10), assets(&[(SIBLING_ASSET, 5_000)]))
+				.unwrap();
+			// 2 * 1_000 + 10 * 10
+			assert_eq!(amount_of(&unused, SIBLING_ASSET), 5_000 - 2_100);
+
+			assert_eq!(
+				trader.refund_weight(Weight::from_parts(400, 5)),
+				Some((SIBLING_ASSET, 850).into())
+			);
+		}
+		assert_eq!(take_revenue(), vec![(SIBLING_ASSET, 1_250).into()]);
+	}
+
+	#[test]
+	fn mixed_assets_are_tracked_separately() {
+		take_revenue();
+		{
+			let mut trader = Trader::new();
+
+			let unused = trader
+				.buy_weight(Weight::from_parts(1_000, 0), assets(&[(RELAY_ASSET, 1_500)]))
+				.unwrap();
+			assert_eq!(amount_of(&unused, RELAY_ASSET), 500);
+
+			let unused = trader
+				.buy_weight(Weight::from_parts(500, 10), assets(&[(SIBLING_ASSET, 2_000)]))
+				.unwrap();
+			assert_eq!(amount_of(&unused, SIBLING_ASSET), 900);
+
+			// Refunds come from the most recently purchased asset, at its own rate
+			assert_eq!(
+				trader.refund_weight(Weight::from_parts(100, 2)),
+				Some((SIBLING_ASSET, 220).into())
+			);
+		}
+
+		// Both assets are deposited on drop, not just the first one
+		assert_eq!(take_revenue(), vec![(RELAY_ASSET, 1_000).into(), (SIBLING_ASSET, 880).into()]);
+	}
+
+	#[test]
+	fn refund_is_capped_per_asset() {
+		take_revenue();
+		{
+			let mut trader = Trader::new();
+
+			trader
+				.buy_weight(Weight::from_parts(1_000, 0), assets(&[(RELAY_ASSET, 1_000)]))
+				.unwrap();
+			trader
+				.buy_weight(Weight::from_parts(100, 0), assets(&[(SIBLING_ASSET, 200)]))
+				.unwrap();
+
+			// Only the weight bought with the sibling asset can be refunded in it
+			assert_eq!(
+				trader.refund_weight(Weight::from_parts(600, 0)),
+				Some((SIBLING_ASSET, 200).into())
+			);
+			// Further refunds fall back to the relay asset, including the carried over 500
+			assert_eq!(
+				trader.refund_weight(Weight::from_parts(600, 0)),
+				Some((RELAY_ASSET, 1_000).into())
+			);
+			assert_eq!(trader.refund_weight(Weight::from_parts(600, 0)), None);
+			assert_eq!(trader.refund_weight(Weight::from_parts(600, 0)), None);
+		}
+		assert!(take_revenue().is_empty());
+	}
+
+	#[test]
+	fn surplus_beyond_last_asset_is_carried_over() {
+		take_revenue();
+		{
+			let mut trader = Trader::new();
+
+			trader
+				.buy_weight(Weight::from_parts(1_000, 0), assets(&[(RELAY_ASSET, 1_000)]))
+				.unwrap();
+			trader
+				.buy_weight(Weight::from_parts(500, 0), assets(&[(SIBLING_ASSET, 1_000)]))
+				.unwrap();
+
+			// A single refund returns one asset only, the weight bought with the sibling asset
+			assert_eq!(
+				trader.refund_weight(Weight::from_parts(1_200, 0)),
+				Some((SIBLING_ASSET, 1_000).into())
+			);
+			// The remaining 700 are refunded in the relay asset by the next refund
+			assert_eq!(
+				trader.refund_weight(Weight::from_parts(100, 0)),
+				Some((RELAY_ASSET, 800).into())
+			);
+		}
+		assert_eq!(take_revenue(), vec![(RELAY_ASSET, 200).into()]);
+	}
+
+	#[test]
+	fn surplus_never_refunded_is_taken_as_revenue() {
+		take_revenue();
+		{
+			let mut trader = Trader::new();
+
+			trader
+				.buy_weight(Weight::from_parts(1_000, 0), assets(&[(RELAY_ASSET, 1_000)]))
+				.unwrap();
+			trader
+				.buy_weight(Weight::from_parts(500, 0), assets(&[(SIBLING_ASSET, 1_000)]))
+				.unwrap();
+
+			assert_eq!(
+				trader.refund_weight(Weight::from_parts(1_200, 0)),
+				Some((SIBLING_ASSET, 1_000).into())
+			);
+		}
+		// Without another refund the carried over surplus stays consumed
+		assert_eq!(take_revenue(), vec![(RELAY_ASSET, 1_000).into()]);
+	}
+
+	#[test]
+	fn repeated_purchases_with_same_asset_accumulate() {
+		take_revenue();
+		{
+			let mut trader = Trader::new();
+
+			trader
+				.buy_weight(Weight::from_parts(300, 0), assets(&[(RELAY_ASSET, 300)]))
+				.unwrap();
+			trader
+				.buy_weight(Weight::from_parts(200, 0), assets(&[(RELAY_ASSET, 300)]))
+				.unwrap();
+
+			assert_eq!(
+				trader.refund_weight(Weight::from_parts(450, 0)),
+				Some((RELAY_ASSET, 450).into())
+			);
+		}
+		assert_eq!(take_revenue(), vec![(RELAY_ASSET, 50).into()]);
+	}
+
+	#[test]
+	fn unsupported_or_insufficient_payment_fails() {
+		take_revenue();
+		{
+			let mut trader = Trader::new();
+
+			assert_eq!(
+				trader
+					.buy_weight(Weight::from_parts(100, 0), assets(&[(UNSUPPORTED_ASSET, 1_000)])),
+				Err(XcmError::TooExpensive)
+			);
+			assert_eq!(
+				trader.buy_weight(Weight::from_parts(100, 0), assets(&[(RELAY_ASSET, 99)])),
+				Err(XcmError::TooExpensive)
+			);
+
+			// A supported asset is picked even if it's not the first one in holding
+			let unused = trader
+				.buy_weight(
+					Weight::from_parts(100, 0),
+					assets(&[(UNSUPPORTED_ASSET, 1_000), (RELAY_ASSET, 100)]),
+				)
+				.unwrap();
+			assert_eq!(amount_of(&unused, UNSUPPORTED_ASSET), 1_000);
+			assert_eq!(amount_of(&unused, RELAY_ASSET), 0);
+		}
+		assert_eq!(take_revenue(), vec![(RELAY_ASSET, 100).into()]);
+	}
 }
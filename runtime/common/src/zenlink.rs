@@ -6,6 +6,7 @@ use sp_std::marker::PhantomData;
 use zenlink_protocol::GenerateLpAssetId;
 
 use peaq_primitives_xcm::{AssetId as PeaqAssetId, AssetIdToZenlinkId, StorageAssetId};
+use xc_asset_config::NativePriceOracle;
 use zenlink_protocol::{AssetId as ZenlinkAssetId, Config as ZenProtConfig};
 
 /// This is the Peaq's default GenerateLpAssetId implementation.
@@ -61,3 +62,25 @@ where
 		}
 	}
 }
+
+/// Reads the reserves of the Zenlink pool of an asset paired with the native token.
+pub struct ZenlinkNativePriceOracle<T, NativeAssetId>(PhantomData<(T, NativeAssetId)>);
+
+impl<T, NativeAssetId> NativePriceOracle<StorageAssetId>
+	for ZenlinkNativePriceOracle<T, NativeAssetId>
+where
+	T: ZenProtConfig<AssetId = ZenlinkAssetId>,
+	NativeAssetId: Get<StorageAssetId>,
+{
+	fn native_reserves(asset_id: StorageAssetId) -> Option<(u128, u128)> {
+		let asset = AssetIdToZenlinkId::<T::SelfParaId>::convert(asset_id)?;
+		let native = AssetIdToZenlinkId::<T::SelfParaId>::convert(NativeAssetId::get())?;
+
+		let pair = zenlink_protocol::Pallet::<T>::get_pair_by_asset_id(asset, native)?;
+		if pair.asset_0 == asset {
+			Some((pair.reserve_0, pair.reserve_1))
+		} else {
+			Some((pair.reserve_1, pair.reserve_0))
+		}
+	}
+}
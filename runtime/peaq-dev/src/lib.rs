@@ -68,8 +68,8 @@ pub use frame_support::{
 	parameter_types,
 	traits::{
 		AsEnsureOriginWithArg, ConstBool, ConstU128, ConstU32, Contains, Currency, EitherOfDiverse,
-		EnsureOrigin, ExistenceRequirement, FindAuthor, Imbalance, KeyOwnerProofSystem, Nothing,
-		OnFinalize, OnUnbalanced, Randomness, StorageInfo, WithdrawReasons,
+		EnsureOrigin, ExistenceRequirement, FindAuthor, Get, Imbalance, KeyOwnerProofSystem,
+		Nothing, OnFinalize, OnUnbalanced, Randomness, StorageInfo, WithdrawReasons,
 	},
 	weights::{
 		constants::{
@@ -106,7 +106,7 @@ pub use peaq_pallet_storage;
 pub use peaq_pallet_transaction;
 
 // For Zenlink-DEX-Module
-use peaq_primitives_xcm::EVMAddressToAssetId;
+use peaq_primitives_xcm::{EVMAddressToAssetId, PausedXcAssets};
 
 pub use precompiles::EVMAssetPrefix;
 
@@ -793,6 +793,22 @@ pub mod staking {
 			pub const MaxCollatorCandidates: u32 = 16;
 			/// Maximum number of concurrent requests to unlock unstaked balance
 			pub const MaxUnstakeRequests: u32 = 10;
+			/// Maximum number of recorded rewards paid out per block, each of which might be restaked
+			pub const MaxRewardPayoutsPerBlock: u32 = 16;
+			/// Maximum number of sessions inspected when claiming rewards
+			pub const MaxSessionsPerClaim: u32 = 64;
+			/// Collators which do not author any block in a session are slashed by 1%
+			pub const SlashFraction: Perbill = Perbill::from_percent(1);
+			/// Only the collator's own stake is slashed
+			pub const SlashDelegators: bool = false;
+			/// Slashes are applied 12 sessions after the offence unless cancelled
+			pub const SlashDeferDuration: u32 = 12;
+			/// Collators are chilled after 3 sessions in a row without blocks
+			pub const MaxOffencesBeforeChill: u32 = 3;
+			/// Commission changes become effective after 6 rounds/sessions
+			pub const CommissionChangeDelay: u32 = 6;
+			/// Collator display name, website and description are limited to 128 bytes each
+			pub const MaxCollatorMetadataLength: u32 = 128;
 	}
 }
 
@@ -817,6 +833,19 @@ impl parachain_staking::Config for Runtime {
 	type MinDelegation = staking::MinDelegatorStake;
 	type MinDelegatorStake = staking::MinDelegatorStake;
 	type MaxUnstakeRequests = staking::MaxUnstakeRequests;
+	type MaxRewardPayoutsPerBlock = staking::MaxRewardPayoutsPerBlock;
+	type MaxSessionsPerClaim = staking::MaxSessionsPerClaim;
+	type SlashFraction = staking::SlashFraction;
+	type SlashDelegators = staking::SlashDelegators;
+	type SlashDeferDuration = staking::SlashDeferDuration;
+	type MaxOffencesBeforeChill = staking::MaxOffencesBeforeChill;
+	type Slash = ToTreasuryPot;
+	type CancelSlashOrigin = EitherOfDiverse<
+		EnsureRoot<AccountId>,
+		pallet_collective::EnsureProportionMoreThan<AccountId, CouncilCollective, 1, 2>,
+	>;
+	type CommissionChangeDelay = staking::CommissionChangeDelay;
+	type MaxMetadataLength = staking::MaxCollatorMetadataLength;
 
 	type WeightInfo = parachain_staking::weights::WeightInfo<Runtime>;
 }
@@ -857,6 +886,7 @@ impl pallet_block_reward::Config for Runtime {
 	type Currency = Balances;
 	type BeneficiaryPayout = BeneficiaryPayout;
 	type RuntimeEvent = RuntimeEvent;
+	type MaxBeneficiaries = ConstU32<16>;
 	type WeightInfo = pallet_block_reward::weights::WeightInfo<Runtime>;
 }
 
@@ -995,6 +1025,19 @@ parameter_types! {
 	};
 	pub const InitializeInflationAt: BlockNumber = 0;
 	pub const BlockRewardBeforeInitialize: Balance = 0;
+	pub const InflationYearLength: Moment = inflation_manager::MILLISECS_PER_YEAR;
+	pub const MinInflationRate: Perbill = Perbill::from_percent(1);
+	pub const MaxInflationRate: Perbill = Perbill::from_percent(7);
+	pub const TargetStakingRatio: Perbill = Perbill::from_percent(50);
+}
+
+/// Total stake of the collators and their delegators, used to derive the staking ratio
+pub struct TotalStakeProvider;
+impl Get<Balance> for TotalStakeProvider {
+	fn get() -> Balance {
+		let total_stake = ParachainStaking::total_collator_stake();
+		total_stake.collators.saturating_add(total_stake.delegators)
+	}
 }
 
 impl inflation_manager::Config for Runtime {
@@ -1007,6 +1050,18 @@ impl inflation_manager::Config for Runtime {
 	type WeightInfo = inflation_manager::weights::WeightInfo<Runtime>;
 	type DoInitializeAt = InitializeInflationAt;
 	type BlockRewardBeforeInitialize = BlockRewardBeforeInitialize;
+	type YearLength = InflationYearLength;
+	type ConfigurationOrigin = EitherOfDiverse<
+		EnsureRoot<AccountId>,
+		pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 3, 5>,
+	>;
+	type InflationAdjustment = inflation_manager::StakingRatioAdjustment<
+		Runtime,
+		TotalStakeProvider,
+		MinInflationRate,
+		MaxInflationRate,
+		TargetStakingRatio,
+	>;
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -1096,9 +1151,12 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
-	(),
+	Migrations,
 >;
 
+/// Runtime storage migrations executed on upgrade.
+type Migrations = (xc_asset_config::migrations::MigrationExecutionRates<Runtime>,);
+
 #[cfg(feature = "runtime-benchmarks")]
 #[macro_use]
 extern crate frame_benchmarking;
@@ -1886,6 +1944,64 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl parachain_staking_runtime_api::ParachainStakingApi<Block, AccountId, Balance, BlockNumber> for Runtime {
+		fn candidates() -> Vec<parachain_staking_runtime_api::CandidateInfo<AccountId, Balance>> {
+			ParachainStaking::candidates()
+		}
+
+		fn delegations(
+			delegator: AccountId
+		) -> Vec<parachain_staking_runtime_api::DelegationInfo<AccountId, Balance>> {
+			ParachainStaking::delegations(&delegator)
+		}
+
+		fn unstaking(
+			account: AccountId
+		) -> Vec<parachain_staking_runtime_api::UnstakingInfo<BlockNumber, Balance>> {
+			ParachainStaking::unstaking_chunks(&account)
+		}
+
+		fn estimated_reward_per_session(account: AccountId) -> Balance {
+			ParachainStaking::estimated_reward_per_session(&account)
+		}
+	}
+
+	impl pallet_block_reward_runtime_api::BlockRewardApi<Block, AccountId, Balance, Moment> for Runtime {
+		fn block_reward_info() -> pallet_block_reward_runtime_api::BlockRewardInfo<AccountId, Balance, Moment> {
+			BlockReward::block_reward_info()
+		}
+
+		fn issuance_schedule(years: u32) -> Vec<pallet_block_reward_runtime_api::YearlyIssuance<Balance>> {
+			BlockReward::issuance_schedule(years)
+		}
+	}
+
+	impl inflation_manager_runtime_api::InflationManagerApi<Block, AccountId, Balance, BlockNumber> for Runtime {
+		fn pot_ledger(start: u32, limit: u32) -> Vec<inflation_manager_runtime_api::PotLedgerEntry<AccountId, Balance, BlockNumber>> {
+			InflationManager::pot_ledger_entries(start, limit)
+		}
+
+		fn pot_reconciliation() -> inflation_manager_runtime_api::PotReconciliation<Balance> {
+			InflationManager::pot_reconciliation()
+		}
+	}
+
+	impl address_unification_runtime_api::AddressUnificationApi<Block, AccountId, BlockNumber> for Runtime {
+		fn claim_typed_data(
+			account_id: AccountId,
+			evm_address: H160,
+			deadline: BlockNumber,
+		) -> address_unification_runtime_api::ClaimTypedData<BlockNumber> {
+			AddressUnification::claim_typed_data(&account_id, evm_address, deadline)
+		}
+	}
+
+	impl xc_asset_config_runtime_api::XcAssetConfigApi<Block, StorageAssetId, Balance> for Runtime {
+		fn foreign_assets() -> Vec<xc_asset_config_runtime_api::ForeignAssetInfo<StorageAssetId, Balance>> {
+			XcAssetConfig::foreign_assets()
+		}
+	}
+
 	impl zenlink_protocol_runtime_api::ZenlinkProtocolApi<Block, AccountId, ZenlinkAssetId> for Runtime {
 		fn get_balance(asset_id: ZenlinkAssetId, owner: AccountId) -> AssetBalance {
 			<Runtime as zenlink_protocol::Config>::MultiAssetsHandler::balance_of(asset_id, &owner)
@@ -2088,11 +2204,23 @@ impl pallet_assets::Config for Runtime {
 	type BenchmarkHelper = ();
 }
 
+parameter_types! {
+	pub const EvmRelinkDelay: BlockNumber = DAYS;
+	pub EvmClaimVerifyingContract: H160 = H160::from_low_u64_be(2058);
+	pub const EvmMaxSweptAssets: u32 = 16;
+}
+
 impl address_unification::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
 	type OriginAddressMapping = HashedAddressMapping<BlakeTwo256>;
 	type ChainId = EvmChainId;
+	type VerifyingContract = EvmClaimVerifyingContract;
+	type RelinkDelay = EvmRelinkDelay;
+	type AssetsSweeper = address_unification::FungiblesSweeper<Assets>;
+	type MaxSweptAssets = EvmMaxSweptAssets;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = address_unification::FungiblesSweeper<Assets>;
 	type WeightInfo = address_unification::weights::WeightInfo<Runtime>;
 }
 
@@ -2109,3 +2237,14 @@ impl EVMAddressToAssetId<StorageAssetId> for Runtime {
 		Some(AssetIdToEVMAddress::<EVMAssetPrefix>::convert(asset_id))
 	}
 }
+
+impl PausedXcAssets<StorageAssetId> for Runtime {
+	fn is_asset_paused(asset_id: StorageAssetId) -> bool {
+		XcAssetConfig::is_asset_paused(asset_id)
+	}
+
+	fn is_location_paused(location: xcm::latest::MultiLocation) -> bool {
+		<XcAssetConfig as xc_asset_config::XcAssetLocation<StorageAssetId>>::get_asset_id(location)
+			.map_or(false, XcAssetConfig::is_asset_paused)
+	}
+}
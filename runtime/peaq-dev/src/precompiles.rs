@@ -1,5 +1,6 @@
 use crate::xcm_config::XcmConfig;
 use frame_support::parameter_types;
+use pallet_evm_precompile_address_unification::AddressUnificationPrecompile;
 use pallet_evm_precompile_assets_erc20::Erc20AssetsPrecompileSet;
 use pallet_evm_precompile_assets_factory::AssetsFactoryPrecompile;
 use pallet_evm_precompile_balances_erc20::{Erc20BalancesPrecompile, Erc20Metadata};
@@ -140,6 +141,11 @@ pub type PeaqPrecompiles<R> = PrecompileSetBuilder<
 					Erc20BalancesPrecompile<R, NativeErc20Metadata>,
 					(AcceptDelegateCall, CallableByContract),
 				>,
+				PrecompileAt<
+					AddressU64<2058>,
+					AddressUnificationPrecompile<R>,
+					(AcceptDelegateCall, CallableByContract),
+				>,
 			),
 		>,
 		PrecompileSetStartingWith<
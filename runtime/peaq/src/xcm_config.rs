@@ -1,19 +1,24 @@
 use super::{
-	AccountId, AllPalletsWithSystem, Assets, Balance, Balances, BlockReward, GetNativeAssetId,
-	ParachainInfo, ParachainSystem, PeaqPotAccount, PolkadotXcm, Runtime, RuntimeCall,
-	RuntimeEvent, RuntimeOrigin, StorageAssetId, WeightToFee, XcAssetConfig, XcmpQueue,
+	AccountId, AllPalletsWithSystem, Assets, AssetsStringLimit, Balance, Balances, BlockReward,
+	GetNativeAssetId, ParachainInfo, ParachainSystem, PeaqAssetAdm, PeaqPotAccount, PolkadotXcm,
+	Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, StorageAssetId, WeightToFee, XcAssetConfig,
+	XcmpQueue,
 };
 use frame_support::{
 	dispatch::Weight,
 	match_types, parameter_types,
 	traits::{fungibles, ContainsPair, Everything, Nothing},
+	weights::{constants::WEIGHT_REF_TIME_PER_SECOND, WeightToFee as WeightToFeeT},
 };
 use frame_system::EnsureRoot;
 use orml_traits::location::{RelativeReserveProvider, Reserve};
 use orml_xcm_support::DisabledParachainFee;
 use pallet_xcm::XcmPassthrough;
 use polkadot_parachain::primitives::Sibling;
-use runtime_common::{AccountIdToMultiLocation, FixedRateOfForeignAsset};
+use runtime_common::{
+	AccountIdToMultiLocation, AssetsForeignAssetRegistrar, FixedRateOfForeignAsset,
+	ZenlinkNativePriceOracle,
+};
 use sp_runtime::traits::ConstU32;
 use xc_asset_config::MultiLocationToAssetId;
 use xcm::latest::{prelude::*, MultiAsset};
@@ -393,11 +398,24 @@ impl orml_xtokens::Config for Runtime {
 	type UniversalLocation = UniversalLocation;
 }
 
+parameter_types! {
+	/// Native tokens charged per second of XCM execution, used to price dynamic execution rates.
+	pub NativeUnitsPerSecond: u128 =
+		WeightToFee::weight_to_fee(&Weight::from_parts(WEIGHT_REF_TIME_PER_SECOND, 0));
+	pub const MaxDynamicRateAssets: u32 = 16;
+}
+
 impl xc_asset_config::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type AssetId = StorageAssetId;
 	type NativeAssetId = GetNativeAssetId;
 	type NativeAssetLocation = SelfReserveLocation;
 	type ManagerOrigin = EnsureRoot<AccountId>;
+	type Balance = Balance;
+	type AssetRegistrar = AssetsForeignAssetRegistrar<Runtime, PeaqAssetAdm>;
+	type StringLimit = AssetsStringLimit;
+	type PriceOracle = ZenlinkNativePriceOracle<Runtime, GetNativeAssetId>;
+	type NativeUnitsPerSecond = NativeUnitsPerSecond;
+	type MaxDynamicRateAssets = MaxDynamicRateAssets;
 	type WeightInfo = xc_asset_config::weights::SubstrateWeight<Self>;
 }